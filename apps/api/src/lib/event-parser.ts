@@ -56,6 +56,63 @@ function readHash32(buf: Buffer, offset: number): [string, number] {
   return [Buffer.from(buf.subarray(offset, offset + 32)).toString('hex'), offset + 32]
 }
 
+/** Borsh Vec<Pubkey> (u32 length prefix), as a comma-separated list */
+function readPubkeyVec(buf: Buffer, offset: number): [string, number] {
+  const len = buf.readUInt32LE(offset)
+  let o = offset + 4
+  const keys: string[] = []
+  for (let i = 0; i < len; i++) {
+    const [key, next] = readPubkey(buf, o)
+    keys.push(key)
+    o = next
+  }
+  return [keys.join(','), o]
+}
+
+/** Borsh `PlatformConfig` (field order as in state.rs), keys prefixed with `prefix` */
+function readPlatformConfig(
+  buf: Buffer,
+  offset: number,
+  prefix: string
+): [Record<string, string>, number] {
+  const fields: [string, (buf: Buffer, offset: number) => [string, number]][] = [
+    ['fee_bps', readU16],
+    ['min_bounty_lamports', readU64],
+    ['dispute_voting_period', readI64],
+    ['dispute_min_votes', readU8],
+    ['min_voter_reputation', readI64],
+    ['claim_grace_period', readI64],
+    ['review_period', readI64],
+    ['dispute_fallback_period', readI64],
+    ['arbitrator_stake_lamports', readU64],
+    ['arbitrator_reward_bps', readU16],
+    ['slash_bps', readU16],
+    ['appeal_window', readI64],
+    ['appeal_bond_lamports', readU64],
+    ['max_appeal_rounds', readU8],
+    ['vote_weight_mode', readU8],
+    ['vote_weight_cap', readU64],
+    ['dispute_min_weight', readU64],
+    ['jury_size', readU8],
+    ['min_arbitrator_stake_lamports', readU64],
+    ['arbitrator_approval_required', readU8],
+    ['arbitrator_rep_reward', readI64],
+    ['arbitrator_rep_penalty', readI64],
+    ['dispute_bond_lamports', readU64],
+    ['config_timelock', readI64],
+    ['emergency_withdraw_delay', readI64],
+    ['emergency_agent_share_bps', readU16],
+  ]
+  const config: Record<string, string> = {}
+  let o = offset
+  for (const [name, read] of fields) {
+    const [value, next] = read(buf, o)
+    config[`${prefix}${name}`] = value
+    o = next
+  }
+  return [config, o]
+}
+
 const EVENT_DECODERS: Record<string, EventDecoder> = {
   PlatformInitialized: (buf) => {
     let o = 0
@@ -68,6 +125,141 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     return { authority, fee_bps, treasury }
   },
 
+  AuthorityTransferProposed: (buf) => {
+    let o = 0
+    const [authority, o1] = readPubkey(buf, o)
+    o = o1
+    const [pending_authority, o2] = readPubkey(buf, o)
+    o = o2
+    return { authority, pending_authority }
+  },
+
+  AuthorityTransferred: (buf) => {
+    let o = 0
+    const [previous_authority, o1] = readPubkey(buf, o)
+    o = o1
+    const [new_authority, o2] = readPubkey(buf, o)
+    o = o2
+    return { previous_authority, new_authority }
+  },
+
+  AuthorityTransferCancelled: (buf) => {
+    let o = 0
+    const [authority, o1] = readPubkey(buf, o)
+    o = o1
+    const [pending_authority, o2] = readPubkey(buf, o)
+    o = o2
+    return { authority, pending_authority }
+  },
+
+  PlatformConfigQueued: (buf) => {
+    let o = 0
+    const [oldConfig, o1] = readPlatformConfig(buf, o, 'old_')
+    o = o1
+    const [newConfig, o2] = readPlatformConfig(buf, o, 'new_')
+    o = o2
+    const [old_treasury, o3] = readPubkey(buf, o)
+    o = o3
+    const [new_treasury, o4] = readPubkey(buf, o)
+    o = o4
+    const [effective_at, o5] = readI64(buf, o)
+    o = o5
+    return { ...oldConfig, ...newConfig, old_treasury, new_treasury, effective_at }
+  },
+
+  PlatformUpdated: (buf) => {
+    let o = 0
+    const [oldConfig, o1] = readPlatformConfig(buf, o, 'old_')
+    o = o1
+    const [newConfig, o2] = readPlatformConfig(buf, o, 'new_')
+    o = o2
+    const [old_treasury, o3] = readPubkey(buf, o)
+    o = o3
+    const [new_treasury, o4] = readPubkey(buf, o)
+    o = o4
+    return { ...oldConfig, ...newConfig, old_treasury, new_treasury }
+  },
+
+  PlatformPaused: (buf) => {
+    let o = 0
+    const [paused_by, o1] = readPubkey(buf, o)
+    o = o1
+    const [flags, o2] = readU8(buf, o)
+    o = o2
+    const [pause_flags, o3] = readU8(buf, o)
+    o = o3
+    return { paused_by, flags, pause_flags }
+  },
+
+  PlatformResumed: (buf) => {
+    let o = 0
+    const [resumed_by, o1] = readPubkey(buf, o)
+    o = o1
+    const [flags, o2] = readU8(buf, o)
+    o = o2
+    const [pause_flags, o3] = readU8(buf, o)
+    o = o3
+    return { resumed_by, flags, pause_flags }
+  },
+
+  GovernanceInitialized: (buf) => {
+    let o = 0
+    const [signers, o1] = readPubkeyVec(buf, o)
+    o = o1
+    const [threshold, o2] = readU8(buf, o)
+    o = o2
+    return { signers, threshold }
+  },
+
+  GovernanceSignersUpdated: (buf) => {
+    let o = 0
+    const [signers, o1] = readPubkeyVec(buf, o)
+    o = o1
+    const [threshold, o2] = readU8(buf, o)
+    o = o2
+    return { signers, threshold }
+  },
+
+  ProposalCreated: (buf) => {
+    let o = 0
+    const [proposal, o1] = readPubkey(buf, o)
+    o = o1
+    const [proposal_index, o2] = readU64(buf, o)
+    o = o2
+    const [proposer, o3] = readPubkey(buf, o)
+    o = o3
+    return { proposal, proposal_index, proposer }
+  },
+
+  ProposalApproved: (buf) => {
+    let o = 0
+    const [proposal, o1] = readPubkey(buf, o)
+    o = o1
+    const [signer, o2] = readPubkey(buf, o)
+    o = o2
+    const [approvals, o3] = readU8(buf, o)
+    o = o3
+    return { proposal, signer, approvals }
+  },
+
+  ProposalExecuted: (buf) => {
+    let o = 0
+    const [proposal, o1] = readPubkey(buf, o)
+    o = o1
+    const [executor, o2] = readPubkey(buf, o)
+    o = o2
+    return { proposal, executor }
+  },
+
+  ProposalCancelled: (buf) => {
+    let o = 0
+    const [proposal, o1] = readPubkey(buf, o)
+    o = o1
+    const [cancelled_by, o2] = readPubkey(buf, o)
+    o = o2
+    return { proposal, cancelled_by }
+  },
+
   TaskCreated: (buf) => {
     let o = 0
     const [task, o1] = readPubkey(buf, o)
@@ -78,9 +270,13 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     o = o3
     const [bounty_lamports, o4] = readU64(buf, o)
     o = o4
-    const [deadline, o5] = readI64(buf, o)
+    const [bounty_mint, o5] = readPubkey(buf, o)
     o = o5
-    return { task, creator, task_index, bounty_lamports, deadline }
+    const [deadline, o6] = readI64(buf, o)
+    o = o6
+    const [milestone_count, o7] = readU8(buf, o)
+    o = o7
+    return { task, creator, task_index, bounty_lamports, bounty_mint, deadline, milestone_count }
   },
 
   TaskClaimed: (buf) => {
@@ -100,9 +296,11 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     o = o1
     const [agent, o2] = readPubkey(buf, o)
     o = o2
-    const [deliverable_hash, o3] = readHash32(buf, o)
+    const [milestone_index, o3] = readU8(buf, o)
     o = o3
-    return { task, agent, deliverable_hash }
+    const [deliverable_hash, o4] = readHash32(buf, o)
+    o = o4
+    return { task, agent, milestone_index, deliverable_hash }
   },
 
   TaskSettled: (buf) => {
@@ -111,11 +309,28 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     o = o1
     const [agent, o2] = readPubkey(buf, o)
     o = o2
-    const [payout_lamports, o3] = readU64(buf, o)
+    const [bounty_mint, o3] = readPubkey(buf, o)
+    o = o3
+    const [payout_lamports, o4] = readU64(buf, o)
+    o = o4
+    const [fee_lamports, o5] = readU64(buf, o)
+    o = o5
+    return { task, agent, bounty_mint, payout_lamports, fee_lamports }
+  },
+
+  MilestoneSettled: (buf) => {
+    let o = 0
+    const [task, o1] = readPubkey(buf, o)
+    o = o1
+    const [agent, o2] = readPubkey(buf, o)
+    o = o2
+    const [milestone_index, o3] = readU8(buf, o)
     o = o3
-    const [fee_lamports, o4] = readU64(buf, o)
+    const [payout_lamports, o4] = readU64(buf, o)
     o = o4
-    return { task, agent, payout_lamports, fee_lamports }
+    const [fee_lamports, o5] = readU64(buf, o)
+    o = o5
+    return { task, agent, milestone_index, payout_lamports, fee_lamports }
   },
 
   SubmissionRejected: (buf) => {
@@ -124,9 +339,11 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     o = o1
     const [agent, o2] = readPubkey(buf, o)
     o = o2
-    const [reason_hash, o3] = readHash32(buf, o)
+    const [milestone_index, o3] = readU8(buf, o)
     o = o3
-    return { task, agent, reason_hash }
+    const [reason_hash, o4] = readHash32(buf, o)
+    o = o4
+    return { task, agent, milestone_index, reason_hash }
   },
 
   TaskCancelled: (buf) => {
@@ -140,6 +357,17 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     return { task, creator, refunded_lamports }
   },
 
+  LateSubmissionCancelled: (buf) => {
+    let o = 0
+    const [task, o1] = readPubkey(buf, o)
+    o = o1
+    const [creator, o2] = readPubkey(buf, o)
+    o = o2
+    const [agent, o3] = readPubkey(buf, o)
+    o = o3
+    return { task, creator, agent }
+  },
+
   TaskExpired: (buf) => {
     let o = 0
     const [task, o1] = readPubkey(buf, o)
@@ -151,6 +379,23 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     return { task, creator, refunded_lamports }
   },
 
+  EmergencyWithdrawal: (buf) => {
+    let o = 0
+    const [task, o1] = readPubkey(buf, o)
+    o = o1
+    const [creator, o2] = readPubkey(buf, o)
+    o = o2
+    const [agent, o3] = readPubkey(buf, o)
+    o = o3
+    const [caller, o4] = readPubkey(buf, o)
+    o = o4
+    const [refunded_lamports, o5] = readU64(buf, o)
+    o = o5
+    const [payout_lamports, o6] = readU64(buf, o)
+    o = o6
+    return { task, creator, agent, caller, refunded_lamports, payout_lamports }
+  },
+
   TemplateCreated: (buf) => {
     let o = 0
     const [template, o1] = readPubkey(buf, o)
@@ -174,10 +419,73 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     o = o3
     const [reason, o4] = readU8(buf, o)
     o = o4
-    return { dispute, task, initiator, reason }
+    const [bond_lamports, o5] = readU64(buf, o)
+    o = o5
+    return { dispute, task, initiator, reason, bond_lamports }
+  },
+
+  JuryDrawn: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [round, o2] = readU8(buf, o)
+    o = o2
+    const [jurors, o3] = readPubkeyVec(buf, o)
+    o = o3
+    return { dispute, round, jurors }
+  },
+
+  JuryDrawDeferred: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [round, o2] = readU8(buf, o)
+    o = o2
+    const [draw_slot, o3] = readU64(buf, o)
+    o = o3
+    return { dispute, round, draw_slot }
+  },
+
+  EvidenceSubmitted: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [task, o2] = readPubkey(buf, o)
+    o = o2
+    const [submitter, o3] = readPubkey(buf, o)
+    o = o3
+    const [evidence_hash, o4] = readHash32(buf, o)
+    o = o4
+    const [submitted_at, o5] = readI64(buf, o)
+    o = o5
+    return { dispute, task, submitter, evidence_hash, submitted_at }
   },
 
   VoteCast: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [voter, o2] = readPubkey(buf, o)
+    o = o2
+    const [commitment, o3] = readHash32(buf, o)
+    o = o3
+    return { dispute, voter, commitment }
+  },
+
+  VoteChanged: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [voter, o2] = readPubkey(buf, o)
+    o = o2
+    const [commitment, o3] = readHash32(buf, o)
+    o = o3
+    const [withdrawn, o4] = readU8(buf, o)
+    o = o4
+    return { dispute, voter, commitment, withdrawn }
+  },
+
+  VoteRevealed: (buf) => {
     let o = 0
     const [dispute, o1] = readPubkey(buf, o)
     o = o1
@@ -185,7 +493,28 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     o = o2
     const [ruling, o3] = readU8(buf, o)
     o = o3
-    return { dispute, voter, ruling }
+    const [split_pct, o4] = readU8(buf, o)
+    o = o4
+    const [weight, o5] = readU64(buf, o)
+    o = o5
+    return { dispute, voter, ruling, split_pct, weight }
+  },
+
+  DisputeAppealed: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [task, o2] = readPubkey(buf, o)
+    o = o2
+    const [appellant, o3] = readPubkey(buf, o)
+    o = o3
+    const [appealed_ruling, o4] = readU8(buf, o)
+    o = o4
+    const [round, o5] = readU8(buf, o)
+    o = o5
+    const [bond_lamports, o6] = readU64(buf, o)
+    o = o6
+    return { dispute, task, appellant, appealed_ruling, round, bond_lamports }
   },
 
   DisputeResolved: (buf) => {
@@ -196,9 +525,81 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     o = o2
     const [ruling, o3] = readU8(buf, o)
     o = o3
-    const [total_votes, o4] = readU16(buf, o)
+    const [resolution, o4] = readU8(buf, o)
+    o = o4
+    const [total_votes, o5] = readU16(buf, o)
+    o = o5
+    const [agent_share_pct, o6] = readU8(buf, o)
+    o = o6
+    const [bond_outcome, o7] = readU8(buf, o)
+    o = o7
+    return { dispute, task, ruling, resolution, total_votes, agent_share_pct, bond_outcome }
+  },
+
+  VoteSettled: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [voter, o2] = readPubkey(buf, o)
+    o = o2
+    const [majority, o3] = readU8(buf, o)
+    o = o3
+    const [reward_lamports, o4] = readU64(buf, o)
+    o = o4
+    const [reward_tokens, o5] = readU64(buf, o)
+    o = o5
+    const [slashed_lamports, o6] = readU64(buf, o)
+    o = o6
+    const [reputation_delta, o7] = readI64(buf, o)
+    o = o7
+    return {
+      dispute,
+      voter,
+      majority,
+      reward_lamports,
+      reward_tokens,
+      slashed_lamports,
+      reputation_delta,
+    }
+  },
+
+  VoteClosed: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [voter, o2] = readPubkey(buf, o)
+    o = o2
+    const [refund_lamports, o3] = readU64(buf, o)
+    o = o3
+    return { dispute, voter, refund_lamports }
+  },
+
+  DisputeFlagged: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [task, o2] = readPubkey(buf, o)
+    o = o2
+    const [flagged_by, o3] = readPubkey(buf, o)
+    o = o3
+    return { dispute, task, flagged_by }
+  },
+
+  DisputeAdminResolved: (buf) => {
+    let o = 0
+    const [dispute, o1] = readPubkey(buf, o)
+    o = o1
+    const [task, o2] = readPubkey(buf, o)
+    o = o2
+    const [authority, o3] = readPubkey(buf, o)
+    o = o3
+    const [ruling, o4] = readU8(buf, o)
     o = o4
-    return { dispute, task, ruling, total_votes }
+    const [agent_share_pct, o5] = readU8(buf, o)
+    o = o5
+    const [bond_outcome, o6] = readU8(buf, o)
+    o = o6
+    return { dispute, task, authority, ruling, agent_share_pct, bond_outcome }
   },
 
   AgentRegistered: (buf) => {
@@ -220,6 +621,41 @@ const EVENT_DECODERS: Record<string, EventDecoder> = {
     o = o3
     return { agent, reputation_score, tasks_completed }
   },
+
+  ArbitratorRegistered: (buf) => {
+    let o = 0
+    const [arbitrator, o1] = readPubkey(buf, o)
+    o = o1
+    const [categories, o2] = readU8(buf, o)
+    o = o2
+    const [stake_lamports, o3] = readU64(buf, o)
+    o = o3
+    const [is_approved, o4] = readU8(buf, o)
+    o = o4
+    return { arbitrator, categories, stake_lamports, is_approved }
+  },
+
+  ArbitratorUpdated: (buf) => {
+    let o = 0
+    const [arbitrator, o1] = readPubkey(buf, o)
+    o = o1
+    const [categories, o2] = readU8(buf, o)
+    o = o2
+    const [is_available, o3] = readU8(buf, o)
+    o = o3
+    const [is_approved, o4] = readU8(buf, o)
+    o = o4
+    return { arbitrator, categories, is_available, is_approved }
+  },
+
+  ArbitratorDeregistered: (buf) => {
+    let o = 0
+    const [arbitrator, o1] = readPubkey(buf, o)
+    o = o1
+    const [stake_lamports, o2] = readU64(buf, o)
+    o = o2
+    return { arbitrator, stake_lamports }
+  },
 }
 
 // Build discriminator → event name map
@@ -149,7 +149,13 @@ export async function loadStore(): Promise<void> {
 // Write path
 // ────────────────────────────────────────────────────────────
 
-const TERMINAL_EVENTS = new Set(['TaskSettled', 'TaskCancelled', 'TaskExpired', 'DisputeResolved'])
+const TERMINAL_EVENTS = new Set([
+  'TaskSettled',
+  'TaskCancelled',
+  'TaskExpired',
+  'DisputeResolved',
+  'EmergencyWithdrawal',
+])
 
 /** Events that should auto-publicize deliverables (task completed successfully) */
 const PUBLICIZE_EVENTS = new Set(['TaskSettled', 'DisputeResolved'])
@@ -429,6 +435,7 @@ function mapTerminalStatus(eventName: string): HistoricalTask['finalStatus'] {
     case 'TaskSettled':
       return 'Approved'
     case 'TaskCancelled':
+    case 'EmergencyWithdrawal':
       return 'Cancelled'
     case 'TaskExpired':
       return 'Expired'
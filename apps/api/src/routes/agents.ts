@@ -28,6 +28,11 @@ function decodeAgentProfile(pubkey: PublicKey, data: Buffer) {
   offset += 8
   const skillTags = data.readUInt8(offset)
   offset += 1
+  offset += 1 // bump; the arbitration stats were appended after it
+  const disputesJudged = data.readBigUInt64LE(offset)
+  offset += 8
+  const majorityVotes = data.readBigUInt64LE(offset)
+  offset += 8
 
   const skills = [
     'DataLabeling',
@@ -56,6 +61,12 @@ function decodeAgentProfile(pubkey: PublicKey, data: Buffer) {
       Number(tasksDisputed) > 0
         ? `${((Number(disputesWon) / Number(tasksDisputed)) * 100).toFixed(1)}%`
         : 'N/A',
+    disputesJudged: disputesJudged.toString(),
+    majorityVotes: majorityVotes.toString(),
+    majorityRate:
+      Number(disputesJudged) > 0
+        ? `${((Number(majorityVotes) / Number(disputesJudged)) * 100).toFixed(1)}%`
+        : 'N/A',
   }
 }
 
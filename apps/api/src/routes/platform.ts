@@ -1,5 +1,6 @@
 import { createRoute, OpenAPIHono } from '@hono/zod-openapi'
-import { decodePlatform, getPlatformPda } from '@verbitto/program'
+import { PublicKey } from '@solana/web3.js'
+import { decodePlatform, getPlatformPda, pausedClasses } from '@verbitto/program'
 import { getConnection } from '../lib/solana.js'
 import { ErrorSchema } from '../schemas/common.js'
 import { PlatformResponseSchema } from '../schemas/platform.js'
@@ -67,10 +68,45 @@ app.openapi(getPlatformRoute, async (c) => {
       totalSettledLamports: platform.totalSettledLamports.toString(),
       totalSettledSol: Number(platform.totalSettledLamports) / 1e9,
       isPaused: platform.isPaused,
+      pauseFlags: platform.pauseFlags,
+      pausedClasses: pausedClasses(platform.pauseFlags),
       disputeVotingPeriod: platform.disputeVotingPeriod.toString(),
       disputeMinVotes: platform.disputeMinVotes,
       minVoterReputation: platform.minVoterReputation.toString(),
       claimGracePeriod: platform.claimGracePeriod.toString(),
+      reviewPeriod: platform.reviewPeriod.toString(),
+      disputeFallbackPeriod: platform.disputeFallbackPeriod.toString(),
+      arbitratorStakeLamports: platform.arbitratorStakeLamports.toString(),
+      arbitratorRewardBps: platform.arbitratorRewardBps,
+      slashBps: platform.slashBps,
+      appealWindow: platform.appealWindow.toString(),
+      appealBondLamports: platform.appealBondLamports.toString(),
+      maxAppealRounds: platform.maxAppealRounds,
+      voteWeightMode: platform.voteWeightMode,
+      voteWeightCap: platform.voteWeightCap.toString(),
+      disputeMinWeight: platform.disputeMinWeight.toString(),
+      jurySize: platform.jurySize,
+      minArbitratorStakeLamports: platform.minArbitratorStakeLamports.toString(),
+      arbitratorApprovalRequired: platform.arbitratorApprovalRequired,
+      arbitratorRepReward: platform.arbitratorRepReward.toString(),
+      arbitratorRepPenalty: platform.arbitratorRepPenalty.toString(),
+      disputeBondLamports: platform.disputeBondLamports.toString(),
+      pendingAuthority: platform.pendingAuthority.equals(PublicKey.default)
+        ? null
+        : platform.pendingAuthority.toBase58(),
+      configTimelock: platform.configTimelock.toString(),
+      emergencyWithdrawDelay: platform.emergencyWithdrawDelay.toString(),
+      emergencyAgentShareBps: platform.emergencyAgentShareBps,
+      settlementPausedAt:
+        platform.settlementPausedAt === 0n ? null : platform.settlementPausedAt.toString(),
+      pendingConfig:
+        platform.configEffectiveAt === 0n
+          ? null
+          : {
+              feeBps: platform.pendingFeeBps,
+              treasury: platform.pendingTreasury.toBase58(),
+              effectiveAt: platform.configEffectiveAt.toString(),
+            },
     })
   } catch (error) {
     return c.json({ error: error instanceof Error ? error.message : 'Internal error' }, 500)
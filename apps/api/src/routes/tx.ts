@@ -1,22 +1,32 @@
 import { AnchorProvider, Program, Wallet } from '@coral-xyz/anchor'
 import { createRoute, OpenAPIHono } from '@hono/zod-openapi'
+import { getAssociatedTokenAddressSync } from '@solana/spl-token'
 import {
   Connection,
   Keypair,
   PublicKey,
+  SYSVAR_SLOT_HASHES_PUBKEY,
   SystemProgram,
   Transaction,
   type TransactionInstruction,
 } from '@solana/web3.js'
 import {
   decodePlatform,
+  decodeTask,
   getAgentProfilePda,
+  getArbitratorPda,
+  getArbitratorPoolPda,
   getCreatorCounterPda,
   getDisputePda,
   getPlatformPda,
+  getRewardVaultPda,
   getTaskPda,
   getTemplatePda,
+  getVaultPda,
   getVotePda,
+  MAX_REJECTIONS,
+  RULING,
+  voteCommitment,
 } from '@verbitto/program'
 import BN from 'bn.js'
 import { loadIdl } from '../lib/idl.js'
@@ -56,6 +66,92 @@ async function getProgram() {
   return { program: cachedProgram, connection: cachedConnection }
 }
 
+/**
+ * Token accounts for a task's bounty: the mint, the task vault, the token
+ * program and each given party's associated token account. All null for a
+ * SOL bounty (`mint` is the default key); instructions ignore the accounts
+ * they don't take.
+ */
+async function bountyTokenAccounts(
+  connection: Connection,
+  task: PublicKey,
+  mint: PublicKey,
+  parties: { creator?: PublicKey; agent?: PublicKey; treasury?: PublicKey }
+) {
+  if (mint.equals(PublicKey.default)) {
+    return {
+      bountyMint: null,
+      vault: null,
+      creatorTokenAccount: null,
+      agentTokenAccount: null,
+      treasuryTokenAccount: null,
+      tokenProgram: null,
+    }
+  }
+
+  // SPL Token or Token-2022, whichever owns the mint
+  const mintInfo = await connection.getAccountInfo(mint)
+  if (!mintInfo) {
+    throw new Error(`Bounty mint ${mint.toBase58()} not found`)
+  }
+  const tokenProgram = mintInfo.owner
+  const ata = (owner?: PublicKey) =>
+    owner && !owner.equals(PublicKey.default)
+      ? getAssociatedTokenAddressSync(mint, owner, true, tokenProgram)
+      : null
+
+  return {
+    bountyMint: mint,
+    vault: getVaultPda(task),
+    creatorTokenAccount: ata(parties.creator),
+    agentTokenAccount: ata(parties.agent),
+    treasuryTokenAccount: ata(parties.treasury),
+    tokenProgram,
+  }
+}
+
+/**
+ * Token accounts for a resolved dispute's token reward pool: the mint, the
+ * reward vault, the token program and the treasury's associated token
+ * account, plus `ata` for each arbitrator's. `null` when the dispute holds
+ * no token rewards.
+ */
+async function rewardTokenAccounts(
+  connection: Connection,
+  dispute: PublicKey,
+  rewardPoolTokens: BN,
+  treasury: PublicKey
+) {
+  if (rewardPoolTokens.isZero()) {
+    return null
+  }
+  const rewardVault = getRewardVaultPda(dispute)
+  const vaultInfo = await connection.getAccountInfo(rewardVault)
+  if (!vaultInfo) {
+    throw new Error(`Reward vault ${rewardVault.toBase58()} not found`)
+  }
+  // A token account starts with its mint
+  const mint = new PublicKey(vaultInfo.data.subarray(0, 32))
+  const tokenProgram = vaultInfo.owner
+  const ata = (owner: PublicKey) => getAssociatedTokenAddressSync(mint, owner, true, tokenProgram)
+
+  return {
+    accounts: {
+      bountyMint: mint,
+      rewardVault,
+      treasuryTokenAccount: ata(treasury),
+      tokenProgram,
+    },
+    ata,
+  }
+}
+
+/** The platform treasury, or `fallback` before the platform exists. */
+async function getTreasury(connection: Connection, fallback: PublicKey) {
+  const platInfo = await connection.getAccountInfo(getPlatformPda())
+  return platInfo ? decodePlatform(Buffer.from(platInfo.data)).treasury : fallback
+}
+
 const buildTransactionRoute = createRoute({
   method: 'post',
   path: '/build',
@@ -136,6 +232,7 @@ app.openapi(buildTransactionRoute, async (c) => {
             .registerAgent(skillTags)
             .accounts({
               agentProfile: getAgentProfilePda(signerKey),
+              platform: getPlatformPda(),
               authority: signerKey,
               systemProgram: SystemProgram.programId,
             })
@@ -185,10 +282,15 @@ app.openapi(buildTransactionRoute, async (c) => {
           break
         }
 
-        case 'createTask': {
+        case 'createTask':
+        case 'createMilestoneTask': {
           if (!params?.title || !params?.bountyLamports || !params?.deadline) {
             return c.json({ error: 'Missing required params: title, bountyLamports, deadline' }, 400)
           }
+          const milestoneTask = instruction === 'createMilestoneTask'
+          if (milestoneTask && (!Array.isArray(params.milestones) || !params.milestones.length)) {
+            return c.json({ error: 'Missing params.milestones' }, 400)
+          }
 
           const counterPda = getCreatorCounterPda(signerKey)
           let taskIndex = 0n
@@ -206,20 +308,41 @@ app.openapi(buildTransactionRoute, async (c) => {
             ? Array.from(Buffer.from(params.descriptionHash as string, 'hex'))
             : Array.from(Buffer.alloc(32))
 
-          ix = await program.methods
-            .createTask(
-              params.title as string,
-              descHash,
-              new BN(params.bountyLamports as string | number),
-              new BN(taskIndex.toString()),
-              new BN(params.deadline as string | number),
-              new BN((params.reputationReward as number) ?? 50)
-            )
+          // Token bounties are funded from the creator's associated token account
+          const tokenAccounts = await bountyTokenAccounts(
+            connection,
+            taskPda,
+            params.bountyMint ? new PublicKey(params.bountyMint as string) : PublicKey.default,
+            { creator: signerKey }
+          )
+          const args = [
+            params.title as string,
+            descHash,
+            new BN(params.bountyLamports as string | number),
+            new BN(taskIndex.toString()),
+            new BN(params.deadline as string | number),
+            new BN((params.reputationReward as number) ?? 50),
+          ] as const
+          const method = milestoneTask
+            ? program.methods.createMilestoneTask(
+                ...args,
+                (params.milestones as Record<string, unknown>[]).map((m) => ({
+                  titleHash: m.titleHash
+                    ? Array.from(Buffer.from(m.titleHash as string, 'hex'))
+                    : Array.from(Buffer.alloc(32)),
+                  amount: new BN(m.amount as string | number),
+                  deadline: new BN((m.deadline as string | number) ?? 0),
+                }))
+              )
+            : program.methods.createTask(...args)
+
+          ix = await method
             .accounts({
               task: taskPda,
               platform: getPlatformPda(),
               creatorCounter: counterPda,
               creator: signerKey,
+              ...tokenAccounts,
               systemProgram: SystemProgram.programId,
             })
             .instruction()
@@ -249,22 +372,114 @@ app.openapi(buildTransactionRoute, async (c) => {
           break
         }
 
-        case 'castVote': {
-          if (!params?.task || !params?.ruling) {
-            return c.json({ error: 'Missing params.task or params.ruling' }, 400)
+        case 'drawJury': {
+          if (!params?.task) {
+            return c.json({ error: 'Missing params.task' }, 400)
+          }
+          const taskPda = new PublicKey(params.task as string)
+
+          ix = await program.methods
+            .drawJury()
+            .accounts({
+              task: taskPda,
+              dispute: getDisputePda(taskPda),
+              platform: getPlatformPda(),
+              arbitratorPool: getArbitratorPoolPda(),
+              slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+            })
+            .instruction()
+          break
+        }
+
+        case 'submitEvidence': {
+          if (!params?.task || !params?.evidenceHash) {
+            return c.json({ error: 'Missing params.task or params.evidenceHash' }, 400)
+          }
+          const taskPda = new PublicKey(params.task as string)
+
+          ix = await program.methods
+            .submitEvidence(Array.from(Buffer.from(params.evidenceHash as string, 'hex')))
+            .accounts({
+              task: taskPda,
+              dispute: getDisputePda(taskPda),
+              platform: getPlatformPda(),
+              submitter: signerKey,
+            })
+            .instruction()
+          break
+        }
+
+        case 'flagDispute': {
+          if (!params?.task) {
+            return c.json({ error: 'Missing params.task' }, 400)
+          }
+          const taskPda = new PublicKey(params.task as string)
+
+          ix = await program.methods
+            .flagDispute()
+            .accounts({
+              task: taskPda,
+              dispute: getDisputePda(taskPda),
+              platform: getPlatformPda(),
+              party: signerKey,
+            })
+            .instruction()
+          break
+        }
+
+        case 'castVote':
+        case 'changeVote': {
+          // Sealed vote: pass a precomputed `commitment` (hex), or `ruling` + `salt`
+          // (hex) and, for a split, `splitPct` to have it computed here.
+          // Keep the salt — revealVote needs it. changeVote replaces the
+          // commitment during the commit phase.
+          if (!params?.task || !(params?.commitment || (params?.ruling && params?.salt))) {
+            return c.json(
+              { error: 'Missing params.task, or params.commitment / params.ruling + params.salt' },
+              400
+            )
           }
           const taskPda = new PublicKey(params.task)
           const disputePda = getDisputePda(taskPda)
           const votePda = getVotePda(disputePda, signerKey)
 
+          let commitment: number[]
+          if (params.commitment) {
+            commitment = Array.from(Buffer.from(params.commitment as string, 'hex'))
+          } else {
+            const variant = Object.keys(params.ruling)[0] ?? ''
+            const ruling = RULING.find((r) => r.toLowerCase() === variant.toLowerCase())
+            if (!ruling || ruling === 'Pending') {
+              return c.json({ error: 'Invalid params.ruling' }, 400)
+            }
+            const salt = Buffer.from(params.salt as string, 'hex')
+            const splitPct = Number(params.splitPct ?? 0)
+            commitment = Array.from(await voteCommitment(ruling, splitPct, salt, signerKey))
+          }
+
+          if (instruction === 'changeVote') {
+            ix = await program.methods
+              .changeVote(commitment)
+              .accounts({
+                task: taskPda,
+                dispute: disputePda,
+                platform: getPlatformPda(),
+                vote: votePda,
+                voter: signerKey,
+              })
+              .instruction()
+            break
+          }
+
           ix = await program.methods
-            .castVote(params.ruling)
+            .castVote(commitment)
             .accounts({
               task: taskPda,
               dispute: disputePda,
               platform: getPlatformPda(),
               vote: votePda,
               voterProfile: getAgentProfilePda(signerKey),
+              arbitrator: getArbitratorPda(signerKey),
               voter: signerKey,
               systemProgram: SystemProgram.programId,
             })
@@ -272,12 +487,105 @@ app.openapi(buildTransactionRoute, async (c) => {
           break
         }
 
+        case 'withdrawVote': {
+          if (!params?.task) {
+            return c.json({ error: 'Missing params.task' }, 400)
+          }
+          const taskPda = new PublicKey(params.task)
+          const disputePda = getDisputePda(taskPda)
+
+          ix = await program.methods
+            .withdrawVote()
+            .accounts({
+              task: taskPda,
+              dispute: disputePda,
+              platform: getPlatformPda(),
+              vote: getVotePda(disputePda, signerKey),
+              voter: signerKey,
+            })
+            .instruction()
+          break
+        }
+
+        case 'revealVote': {
+          if (!params?.task || !params?.ruling || !params?.salt) {
+            return c.json({ error: 'Missing params.task, params.ruling or params.salt' }, 400)
+          }
+          const taskPda = new PublicKey(params.task)
+          const disputePda = getDisputePda(taskPda)
+          const salt = Array.from(Buffer.from(params.salt as string, 'hex'))
+          const splitPct = Number(params.splitPct ?? 0)
+
+          ix = await program.methods
+            .revealVote(params.ruling, splitPct, salt)
+            .accounts({
+              task: taskPda,
+              dispute: disputePda,
+              platform: getPlatformPda(),
+              vote: getVotePda(disputePda, signerKey),
+              voter: signerKey,
+            })
+            .instruction()
+          break
+        }
+
         case 'updateAgentSkills': {
           const skillTags = params?.skillTags ?? 0
           ix = await program.methods
             .updateAgentSkills(skillTags)
             .accounts({
               agentProfile: getAgentProfilePda(signerKey),
+              platform: getPlatformPda(),
+              authority: signerKey,
+            })
+            .instruction()
+          break
+        }
+
+        case 'registerArbitrator': {
+          // `categories` is a TaskCategory bitmap (bit = category index)
+          if (!params?.categories) {
+            return c.json({ error: 'Missing params.categories' }, 400)
+          }
+          ix = await program.methods
+            .registerArbitrator(
+              params.categories as number,
+              new BN((params.stakeLamports as string | number) ?? 0)
+            )
+            .accounts({
+              arbitrator: getArbitratorPda(signerKey),
+              arbitratorPool: getArbitratorPoolPda(),
+              platform: getPlatformPda(),
+              agentProfile: getAgentProfilePda(signerKey),
+              authority: signerKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .instruction()
+          break
+        }
+
+        case 'updateArbitrator': {
+          if (!params?.categories) {
+            return c.json({ error: 'Missing params.categories' }, 400)
+          }
+          ix = await program.methods
+            .updateArbitrator(params.categories as number, params.isAvailable !== false)
+            .accounts({
+              arbitrator: getArbitratorPda(signerKey),
+              arbitratorPool: getArbitratorPoolPda(),
+              platform: getPlatformPda(),
+              authority: signerKey,
+            })
+            .instruction()
+          break
+        }
+
+        case 'deregisterArbitrator': {
+          ix = await program.methods
+            .deregisterArbitrator()
+            .accounts({
+              arbitrator: getArbitratorPda(signerKey),
+              arbitratorPool: getArbitratorPoolPda(),
               authority: signerKey,
             })
             .instruction()
@@ -292,13 +600,12 @@ app.openapi(buildTransactionRoute, async (c) => {
           const agentKey = new PublicKey(params.agent)
           const platform = getPlatformPda()
 
-          // Fetch treasury from platform account
-          const platformInfo = await connection.getAccountInfo(platform)
-          let treasury = signerKey
-          if (platformInfo) {
-            const platformData = decodePlatform(Buffer.from(platformInfo.data))
-            treasury = platformData.treasury
+          const taskInfo = await connection.getAccountInfo(taskPda)
+          if (!taskInfo) {
+            return c.json({ error: 'Task account not found' }, 400)
           }
+          const { bountyMint } = decodeTask(taskPda, Buffer.from(taskInfo.data))
+          const treasury = await getTreasury(connection, signerKey)
 
           ix = await program.methods
             .approveAndSettle()
@@ -309,6 +616,39 @@ app.openapi(buildTransactionRoute, async (c) => {
               agent: agentKey,
               agentProfile: getAgentProfilePda(agentKey),
               treasury,
+              ...(await bountyTokenAccounts(connection, taskPda, bountyMint, {
+                agent: agentKey,
+                treasury,
+              })),
+            })
+            .instruction()
+          break
+        }
+
+        case 'autoApproveTask': {
+          if (!params?.task) {
+            return c.json({ error: 'Missing params.task' }, 400)
+          }
+          const taskPda = new PublicKey(params.task as string)
+
+          const taskInfo = await connection.getAccountInfo(taskPda)
+          if (!taskInfo) {
+            return c.json({ error: 'Task account not found' }, 400)
+          }
+          const { creator, agent, bountyMint } = decodeTask(taskPda, Buffer.from(taskInfo.data))
+          const treasury = await getTreasury(connection, signerKey)
+
+          ix = await program.methods
+            .autoApproveTask()
+            .accounts({
+              task: taskPda,
+              platform: getPlatformPda(),
+              creator,
+              agent,
+              agentProfile: getAgentProfilePda(agent),
+              treasury,
+              ...(await bountyTokenAccounts(connection, taskPda, bountyMint, { agent, treasury })),
+              caller: signerKey,
             })
             .instruction()
           break
@@ -323,11 +663,19 @@ app.openapi(buildTransactionRoute, async (c) => {
             ? Array.from(Buffer.from(params.reasonHash as string, 'hex'))
             : Array.from(Buffer.alloc(32))
 
+          // The rejection that hits MAX_REJECTIONS opens the dispute PDA
+          const taskInfo = await connection.getAccountInfo(taskPda)
+          const escalates =
+            taskInfo !== null &&
+            decodeTask(taskPda, Buffer.from(taskInfo.data)).rejectionCount + 1 >= MAX_REJECTIONS
+
           ix = await program.methods
             .rejectSubmission(reasonHash)
             .accounts({
               task: taskPda,
+              dispute: escalates ? getDisputePda(taskPda) : null,
               creator: signerKey,
+              systemProgram: SystemProgram.programId,
             })
             .instruction()
           break
@@ -339,11 +687,20 @@ app.openapi(buildTransactionRoute, async (c) => {
           }
           const taskPda = new PublicKey(params.task)
 
+          const taskInfo = await connection.getAccountInfo(taskPda)
+          if (!taskInfo) {
+            return c.json({ error: 'Task account not found' }, 400)
+          }
+          const { bountyMint } = decodeTask(taskPda, Buffer.from(taskInfo.data))
+
           ix = await program.methods
             .cancelTask()
             .accounts({
               task: taskPda,
               creator: signerKey,
+              ...(await bountyTokenAccounts(connection, taskPda, bountyMint, {
+                creator: signerKey,
+              })),
             })
             .instruction()
           break
@@ -360,8 +717,10 @@ app.openapi(buildTransactionRoute, async (c) => {
           if (!taskInfo) {
             return c.json({ error: 'Task account not found' }, 400)
           }
-          // creator pubkey is at offset 8 (after discriminator)
-          const taskCreator = new PublicKey(taskInfo.data.subarray(8, 40))
+          const { creator: taskCreator, bountyMint } = decodeTask(
+            taskPda,
+            Buffer.from(taskInfo.data)
+          )
 
           ix = await program.methods
             .expireTask()
@@ -370,6 +729,65 @@ app.openapi(buildTransactionRoute, async (c) => {
               creator: taskCreator,
               platform: getPlatformPda(),
               caller: signerKey,
+              ...(await bountyTokenAccounts(connection, taskPda, bountyMint, {
+                creator: taskCreator,
+              })),
+            })
+            .instruction()
+          break
+        }
+
+        case 'emergencyWithdraw': {
+          if (!params?.task) {
+            return c.json({ error: 'Missing params.task' }, 400)
+          }
+          const taskPda = new PublicKey(params.task as string)
+
+          const taskInfo = await connection.getAccountInfo(taskPda)
+          if (!taskInfo) {
+            return c.json({ error: 'Task account not found' }, 400)
+          }
+          const { creator, agent, bountyMint } = decodeTask(taskPda, Buffer.from(taskInfo.data))
+
+          ix = await program.methods
+            .emergencyWithdraw()
+            .accounts({
+              task: taskPda,
+              platform: getPlatformPda(),
+              creator,
+              agent,
+              caller: signerKey,
+              ...(await bountyTokenAccounts(connection, taskPda, bountyMint, { creator, agent })),
+            })
+            .instruction()
+          break
+        }
+
+        case 'appealDispute': {
+          if (!params?.task) {
+            return c.json({ error: 'Missing params.task' }, 400)
+          }
+          const taskPda = new PublicKey(params.task as string)
+
+          const taskAcct = await connection.getAccountInfo(taskPda)
+          if (!taskAcct) {
+            return c.json({ error: 'Task account not found' }, 400)
+          }
+          const { creator: taskCreatorKey, agent: taskAgentKey } = decodeTask(
+            taskPda,
+            Buffer.from(taskAcct.data)
+          )
+          // The party not appealing (refunded any earlier bond it won)
+          const counterparty = signerKey.equals(taskCreatorKey) ? taskAgentKey : taskCreatorKey
+
+          ix = await program.methods
+            .appealDispute()
+            .accounts({
+              task: taskPda,
+              dispute: getDisputePda(taskPda),
+              platform: getPlatformPda(),
+              appellant: signerKey,
+              counterparty,
             })
             .instruction()
           break
@@ -388,17 +806,15 @@ app.openapi(buildTransactionRoute, async (c) => {
           if (!taskAcct) {
             return c.json({ error: 'Task account not found' }, 400)
           }
-          const taskCreatorKey = new PublicKey(taskAcct.data.subarray(8, 40))
-          // agent is at offset 57 (disc:8 + creator:32 + taskIndex:8 + bounty:8 + status:1)
-          const taskAgentKey = new PublicKey(taskAcct.data.subarray(57, 89))
+          const {
+            creator: taskCreatorKey,
+            agent: taskAgentKey,
+            bountyMint,
+          } = decodeTask(taskPda, Buffer.from(taskAcct.data))
+          const treasuryKey = await getTreasury(connection, signerKey)
 
-          // Fetch treasury from platform
-          const platInfo = await connection.getAccountInfo(platform)
-          let treasuryKey = signerKey
-          if (platInfo) {
-            const platData = decodePlatform(Buffer.from(platInfo.data))
-            treasuryKey = platData.treasury
-          }
+          // eslint-disable-next-line @typescript-eslint/no-explicit-any
+          const dispute = await (program.account as any).dispute.fetch(disputePda)
 
           ix = await program.methods
             .resolveDispute()
@@ -410,12 +826,122 @@ app.openapi(buildTransactionRoute, async (c) => {
               agent: taskAgentKey,
               agentProfile: getAgentProfilePda(taskAgentKey),
               treasury: treasuryKey,
+              initiator: dispute.initiator,
               caller: signerKey,
+              ...(await bountyTokenAccounts(connection, taskPda, bountyMint, {
+                creator: taskCreatorKey,
+                agent: taskAgentKey,
+                treasury: treasuryKey,
+              })),
+              // Holds the voters' share of a token bounty's fee
+              rewardVault: bountyMint.equals(PublicKey.default)
+                ? null
+                : getRewardVaultPda(disputePda),
             })
             .instruction()
           break
         }
 
+        case 'settleVote': {
+          if (!params?.task || !params?.voter) {
+            return c.json({ error: 'Missing params.task or params.voter' }, 400)
+          }
+          // The task is closed once the dispute resolves, so the dispute PDA is
+          // derived from the task address rather than fetched through it.
+          const disputePda = getDisputePda(new PublicKey(params.task as string))
+          const voterKey = new PublicKey(params.voter as string)
+
+          // eslint-disable-next-line @typescript-eslint/no-explicit-any
+          const dispute = await (program.account as any).dispute.fetchNullable(disputePda)
+          if (!dispute) {
+            return c.json({ error: 'Dispute account not found' }, 400)
+          }
+
+          const platform = getPlatformPda()
+          const treasuryKey = await getTreasury(connection, signerKey)
+          const rewards = await rewardTokenAccounts(
+            connection,
+            disputePda,
+            dispute.rewardPoolTokens,
+            treasuryKey
+          )
+
+          ix = await program.methods
+            .settleVote()
+            .accounts({
+              dispute: disputePda,
+              vote: getVotePda(disputePda, voterKey),
+              arbitrator: voterKey,
+              arbitratorProfile: getAgentProfilePda(voterKey),
+              arbitratorRegistration: getArbitratorPda(voterKey),
+              initiator: dispute.initiator,
+              platform,
+              treasury: treasuryKey,
+              caller: signerKey,
+              bountyMint: rewards?.accounts.bountyMint ?? null,
+              rewardVault: rewards?.accounts.rewardVault ?? null,
+              arbitratorTokenAccount: rewards?.ata(voterKey) ?? null,
+              treasuryTokenAccount: rewards?.accounts.treasuryTokenAccount ?? null,
+              tokenProgram: rewards?.accounts.tokenProgram ?? null,
+            })
+            .instruction()
+          break
+        }
+
+        case 'closeVotes': {
+          if (!params?.task || !Array.isArray(params?.voters) || params.voters.length === 0) {
+            return c.json({ error: 'Missing params.task or params.voters' }, 400)
+          }
+          const disputePda = getDisputePda(new PublicKey(params.task as string))
+          const voterKeys = (params.voters as string[]).map((v) => new PublicKey(v))
+
+          // While the dispute exists its votes are settled and the initiator
+          // gets the rent back after the last one; once it is gone, the
+          // initiator account is unused and the signer stands in.
+          // eslint-disable-next-line @typescript-eslint/no-explicit-any
+          const dispute = await (program.account as any).dispute.fetchNullable(disputePda)
+          const initiator: PublicKey = dispute ? dispute.initiator : signerKey
+
+          const platform = getPlatformPda()
+          const treasuryKey = await getTreasury(connection, signerKey)
+          // A token reward pool adds each arbitrator's token account to its group
+          const rewards = dispute
+            ? await rewardTokenAccounts(
+                connection,
+                disputePda,
+                dispute.rewardPoolTokens,
+                treasuryKey
+              )
+            : null
+
+          ix = await program.methods
+            .closeVotes()
+            .accounts({
+              dispute: disputePda,
+              initiator,
+              platform,
+              treasury: treasuryKey,
+              caller: signerKey,
+              bountyMint: rewards?.accounts.bountyMint ?? null,
+              rewardVault: rewards?.accounts.rewardVault ?? null,
+              treasuryTokenAccount: rewards?.accounts.treasuryTokenAccount ?? null,
+              tokenProgram: rewards?.accounts.tokenProgram ?? null,
+            })
+            .remainingAccounts(
+              voterKeys.flatMap((voter) => [
+                { pubkey: getVotePda(disputePda, voter), isWritable: true, isSigner: false },
+                { pubkey: voter, isWritable: true, isSigner: false },
+                { pubkey: getAgentProfilePda(voter), isWritable: true, isSigner: false },
+                { pubkey: getArbitratorPda(voter), isWritable: true, isSigner: false },
+                ...(rewards
+                  ? [{ pubkey: rewards.ata(voter), isWritable: true, isSigner: false }]
+                  : []),
+              ])
+            )
+            .instruction()
+          break
+        }
+
         case 'createTemplate': {
           if (!params?.title || !params?.category) {
             return c.json({ error: 'Missing required params: title, category' }, 400)
@@ -503,13 +1029,17 @@ app.openapi(buildTransactionRoute, async (c) => {
 
           const taskPda = getTaskPda(signerKey, taskIndex)
           const templatePda = new PublicKey(params.template as string)
+          const descHash = params.descriptionHash
+            ? Array.from(Buffer.from(params.descriptionHash as string, 'hex'))
+            : Array.from(Buffer.alloc(32))
 
           ix = await program.methods
             .createTaskFromTemplate(
               new BN(params.bountyLamports as string | number),
               new BN(params.deadline as string | number),
               new BN((params.reputationReward as number) ?? 50),
-              new BN(taskIndex.toString())
+              new BN(taskIndex.toString()),
+              descHash
             )
             .accounts({
               task: taskPda,
@@ -517,6 +1047,12 @@ app.openapi(buildTransactionRoute, async (c) => {
               template: templatePda,
               platform: getPlatformPda(),
               creator: signerKey,
+              ...(await bountyTokenAccounts(
+                connection,
+                taskPda,
+                params.bountyMint ? new PublicKey(params.bountyMint as string) : PublicKey.default,
+                { creator: signerKey }
+              )),
               systemProgram: SystemProgram.programId,
             })
             .instruction()
@@ -13,6 +13,9 @@ export const AgentResponseSchema = z
     skillTags: z.number().openapi({ example: 5 }),
     skills: z.array(z.string()).openapi({ example: ['DataLabeling', 'Translation'] }),
     winRate: z.string().openapi({ example: '50.0%' }),
+    disputesJudged: z.string().openapi({ example: '8' }),
+    majorityVotes: z.string().openapi({ example: '7' }),
+    majorityRate: z.string().openapi({ example: '87.5%' }),
     walletAddress: z
       .string()
       .optional()
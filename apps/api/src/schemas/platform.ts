@@ -13,10 +13,48 @@ export const PlatformResponseSchema = z
     templateCount: z.string().openapi({ example: '5' }),
     totalSettledLamports: z.string().openapi({ example: '5000000000' }),
     totalSettledSol: z.number().openapi({ example: 5.0 }),
-    isPaused: z.boolean().openapi({ example: false }),
+    isPaused: z.boolean().openapi({ example: false, description: 'Any instruction class paused' }),
+    pauseFlags: z.number().openapi({ example: 0 }),
+    pausedClasses: z.array(z.string()).openapi({ example: [] }),
     disputeVotingPeriod: z.string().openapi({ example: '604800' }),
     disputeMinVotes: z.number().openapi({ example: 3 }),
     minVoterReputation: z.string().openapi({ example: '100' }),
     claimGracePeriod: z.string().openapi({ example: '86400' }),
+    reviewPeriod: z.string().openapi({ example: '259200' }),
+    disputeFallbackPeriod: z.string().openapi({ example: '604800' }),
+    arbitratorStakeLamports: z.string().openapi({ example: '50000000' }),
+    arbitratorRewardBps: z.number().openapi({ example: 4000 }),
+    slashBps: z.number().openapi({ example: 5000 }),
+    appealWindow: z.string().openapi({ example: '172800' }),
+    appealBondLamports: z.string().openapi({ example: '100000000' }),
+    maxAppealRounds: z.number().openapi({ example: 1 }),
+    voteWeightMode: z.enum(['Flat', 'Linear', 'Sqrt', 'Capped']).openapi({ example: 'Sqrt' }),
+    voteWeightCap: z.string().openapi({ example: '0' }),
+    disputeMinWeight: z.string().openapi({ example: '30' }),
+    jurySize: z.number().openapi({ example: 5 }),
+    minArbitratorStakeLamports: z.string().openapi({ example: '100000000' }),
+    arbitratorApprovalRequired: z.boolean().openapi({ example: false }),
+    arbitratorRepReward: z.string().openapi({ example: '5' }),
+    arbitratorRepPenalty: z.string().openapi({ example: '10' }),
+    disputeBondLamports: z.string().openapi({ example: '20000000' }),
+    pendingAuthority: z.string().nullable().openapi({
+      example: null,
+      description: 'Proposed next authority, awaiting accept_authority',
+    }),
+    configTimelock: z.string().openapi({ example: '172800' }),
+    emergencyWithdrawDelay: z.string().openapi({ example: '1209600' }),
+    emergencyAgentShareBps: z.number().openapi({ example: 5000 }),
+    settlementPausedAt: z.string().nullable().openapi({
+      example: null,
+      description: 'When settlement was paused; emergency withdrawals open after the delay',
+    }),
+    pendingConfig: z
+      .object({
+        feeBps: z.number().openapi({ example: 300 }),
+        treasury: z.string().openapi({ example: 'DRpbCBMxVnDK7maPM5tGv6MvB3v1sRMC86PZ8okm21hy' }),
+        effectiveAt: z.string().openapi({ example: '1767225600' }),
+      })
+      .nullable()
+      .openapi({ description: 'Queued config change, applied by apply_platform_config' }),
   })
   .openapi('PlatformResponse')
@@ -8,15 +8,30 @@ export const BuildTransactionRequestSchema = z
         'claimTask',
         'submitDeliverable',
         'createTask',
+        'createMilestoneTask',
         'createTaskFromTemplate',
         'openDispute',
+        'drawJury',
+        'submitEvidence',
+        'flagDispute',
         'castVote',
+        'changeVote',
+        'withdrawVote',
+        'revealVote',
         'updateAgentSkills',
+        'registerArbitrator',
+        'updateArbitrator',
+        'deregisterArbitrator',
         'approveAndSettle',
+        'autoApproveTask',
         'rejectSubmission',
         'cancelTask',
         'expireTask',
+        'emergencyWithdraw',
+        'appealDispute',
         'resolveDispute',
+        'settleVote',
+        'closeVotes',
         'createTemplate',
         'deactivateTemplate',
         'reactivateTemplate',
@@ -4,6 +4,7 @@ import Link from 'next/link'
 import { Icons } from '@/components/icons'
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card'
 import { lamportsToSol, useIndexerStats, usePlatform, useTasks } from '@/hooks/use-program'
+import { pausedClasses } from '@/lib/program'
 
 export function ExplorerStats() {
   const { platform, loading: pLoading } = usePlatform()
@@ -155,7 +156,45 @@ export function ExplorerStats() {
                     ['Min Votes to Resolve', String(platform.disputeMinVotes)],
                     ['Min Voter Reputation', String(platform.minVoterReputation)],
                     ['Claim Grace Period', `${platform.claimGracePeriod}s`],
-                    ['Paused', platform.isPaused ? 'Yes' : 'No'],
+                    ['Review Period', `${platform.reviewPeriod}s`],
+                    ['Dispute Fallback Period', `${platform.disputeFallbackPeriod}s`],
+                    ['Arbitrator Stake', `${lamportsToSol(platform.arbitratorStakeLamports)} SOL`],
+                    ['Arbitrator Reward', `${platform.arbitratorRewardBps / 100}% of dispute fee`],
+                    ['Slash Rate', `${platform.slashBps / 100}% of minority stake`],
+                    ['Appeal Window', `${platform.appealWindow}s`],
+                    ['Appeal Bond', `${lamportsToSol(platform.appealBondLamports)} SOL`],
+                    ['Max Appeal Rounds', String(platform.maxAppealRounds)],
+                    [
+                      'Vote Weighting',
+                      platform.voteWeightMode === 'Capped'
+                        ? `Capped at ${platform.voteWeightCap}`
+                        : platform.voteWeightMode,
+                    ],
+                    ['Min Dispute Weight', String(platform.disputeMinWeight)],
+                    ['Dispute Bond', `${lamportsToSol(platform.disputeBondLamports)} SOL`],
+                    ['Jury Size', platform.jurySize ? String(platform.jurySize) : 'Open voting'],
+                    [
+                      'Arbitrator Registration Stake',
+                      `${lamportsToSol(platform.minArbitratorStakeLamports)} SOL`,
+                    ],
+                    [
+                      'Arbitrator Approval',
+                      platform.arbitratorApprovalRequired ? 'Required' : 'Automatic',
+                    ],
+                    [
+                      'Arbitrator Reputation',
+                      `+${platform.arbitratorRepReward} / -${platform.arbitratorRepPenalty} per vote`,
+                    ],
+                    ['Config Timelock', `${platform.configTimelock}s`],
+                    [
+                      'Emergency Withdrawal',
+                      `${platform.emergencyWithdrawDelay}s into a settlement pause, ` +
+                        `${platform.emergencyAgentShareBps / 100}% to agents`,
+                    ],
+                    [
+                      'Paused',
+                      platform.isPaused ? pausedClasses(platform.pauseFlags).join(', ') : 'No',
+                    ],
                   ].map(([label, value]) => (
                     <tr key={label}>
                       <td className="py-2 text-muted-foreground">{label}</td>
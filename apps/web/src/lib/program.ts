@@ -20,6 +20,7 @@ export {
   getTemplatePda,
   getVotePda,
   PROGRAM_ID,
+  pausedClasses,
   STATUS_VARIANTS,
   TASK_CATEGORY,
   TASK_STATUS,
@@ -58,6 +58,44 @@ export function getVotePda(dispute: PublicKey, voter: PublicKey): PublicKey {
   return pda
 }
 
+export function getArbitratorPoolPda(): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync([Buffer.from('arbitrator_pool')], PROGRAM_ID)
+  return pda
+}
+
+export function getArbitratorPda(authority: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('arbitrator'), authority.toBuffer()],
+    PROGRAM_ID
+  )
+  return pda
+}
+
+export function getGovernancePda(): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync([Buffer.from('governance')], PROGRAM_ID)
+  return pda
+}
+
+export function getProposalPda(proposalIndex: bigint): PublicKey {
+  const buf = Buffer.alloc(8)
+  buf.writeBigUInt64LE(proposalIndex)
+  const [pda] = PublicKey.findProgramAddressSync([Buffer.from('proposal'), buf], PROGRAM_ID)
+  return pda
+}
+
+export function getVaultPda(task: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync([Buffer.from('vault'), task.toBuffer()], PROGRAM_ID)
+  return pda
+}
+
+export function getRewardVaultPda(dispute: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('reward_vault'), dispute.toBuffer()],
+    PROGRAM_ID
+  )
+  return pda
+}
+
 export function getTemplatePda(creator: PublicKey, templateIndex: bigint): PublicKey {
   const buf = Buffer.alloc(8)
   buf.writeBigUInt64LE(templateIndex)
@@ -99,6 +137,60 @@ export const TASK_STATUS = [
 
 export type TaskStatus = (typeof TASK_STATUS)[number]
 
+/** Rejections after which a task is escalated to arbitration (mirrors Rust) */
+export const MAX_REJECTIONS = 3
+
+// ============================================================
+// Dispute ruling enum (mirrors Rust) & vote commitments
+// ============================================================
+
+export const RULING = ['Pending', 'CreatorWins', 'AgentWins', 'Split'] as const
+
+export type Ruling = (typeof RULING)[number]
+
+/** How arbitrator reputation maps to vote weight (mirrors Rust `VoteWeightMode`) */
+export const VOTE_WEIGHT_MODE = ['Flat', 'Linear', 'Sqrt', 'Capped'] as const
+
+export type VoteWeightMode = (typeof VOTE_WEIGHT_MODE)[number]
+
+/** `Platform.pause_flags` bits, one per instruction class (mirrors Rust `PAUSE_*`) */
+export const PAUSE_FLAGS = {
+  TaskCreation: 1 << 0,
+  Claiming: 1 << 1,
+  Settlement: 1 << 2,
+  Disputes: 1 << 3,
+  Templates: 1 << 4,
+  AgentRegistration: 1 << 5,
+} as const
+
+export type PauseClass = keyof typeof PAUSE_FLAGS
+
+/** Instruction classes paused by `pauseFlags` */
+export function pausedClasses(pauseFlags: number): PauseClass[] {
+  return (Object.keys(PAUSE_FLAGS) as PauseClass[]).filter(
+    (name) => (pauseFlags & PAUSE_FLAGS[name]) !== 0
+  )
+}
+
+/**
+ * Commit-reveal vote commitment: sha256(ruling_u8 || split_pct_u8 || salt || voter).
+ * `splitPct` is the agent's share for a Split vote (multiple of 10, up to 100), else 0.
+ * Keep `salt` (32 random bytes) — it is needed again to reveal the vote.
+ */
+export async function voteCommitment(
+  ruling: Ruling,
+  splitPct: number,
+  salt: Uint8Array,
+  voter: PublicKey
+): Promise<Uint8Array> {
+  const preimage = new Uint8Array(1 + 1 + 32 + 32)
+  preimage[0] = RULING.indexOf(ruling)
+  preimage[1] = splitPct
+  preimage.set(salt, 2)
+  preimage.set(voter.toBytes(), 34)
+  return new Uint8Array(await globalThis.crypto.subtle.digest('SHA-256', preimage))
+}
+
 export const STATUS_VARIANTS: Record<
   TaskStatus,
   'outline' | 'secondary' | 'default' | 'destructive'
@@ -129,7 +221,47 @@ export interface PlatformAccount {
   disputeMinVotes: number
   minVoterReputation: bigint
   claimGracePeriod: bigint
+  reviewPeriod: bigint
+  disputeFallbackPeriod: bigint
+  arbitratorStakeLamports: bigint
+  arbitratorRewardBps: number
+  slashBps: number
+  appealWindow: bigint
+  appealBondLamports: bigint
+  maxAppealRounds: number
+  voteWeightMode: VoteWeightMode
+  voteWeightCap: bigint
+  disputeMinWeight: bigint
+  /** Jurors drawn per dispute (0 = open voting) */
+  jurySize: number
+  minArbitratorStakeLamports: bigint
+  /** Whether new arbitrators need the authority's approval before voting */
+  arbitratorApprovalRequired: boolean
+  /** Reputation gained per vote with the final ruling */
+  arbitratorRepReward: bigint
+  /** Reputation lost per vote against the final ruling (or unrevealed) */
+  arbitratorRepPenalty: bigint
+  /** Bond the initiator posts when opening a dispute */
+  disputeBondLamports: bigint
+  /** Seconds a queued config change waits before it can be applied */
+  configTimelock: bigint
+  /** Seconds settlement must stay paused before emergency withdrawals open */
+  emergencyWithdrawDelay: bigint
+  /** Agent's share (bps) of a Submitted milestone on emergency withdrawal */
+  emergencyAgentShareBps: number
+  /** Proposed next authority; the default (all-zero) key if none */
+  pendingAuthority: PublicKey
+  /** Fee of the queued config change (meaningful only if configEffectiveAt != 0) */
+  pendingFeeBps: number
+  pendingTreasury: PublicKey
+  /** When the queued config change can be applied; 0 if none is queued */
+  configEffectiveAt: bigint
+  /** Paused instruction classes, as `PAUSE_FLAGS` bits */
+  pauseFlags: number
+  /** Whether any instruction class is paused */
   isPaused: boolean
+  /** When settlement was last paused; 0 while it is running */
+  settlementPausedAt: bigint
   bump: number
 }
 
@@ -158,11 +290,20 @@ export interface TaskTemplateAccount {
   bump: number
 }
 
+export interface Milestone {
+  titleHash: Uint8Array
+  amount: bigint
+  /** 0 = task deadline applies */
+  deadline: bigint
+}
+
 export interface TaskAccount {
   publicKey: PublicKey
   creator: PublicKey
   taskIndex: bigint
   bountyLamports: bigint
+  /** SPL / Token-2022 bounty mint; the default (all-zero) key for SOL bounties */
+  bountyMint: PublicKey
   status: TaskStatus
   agent: PublicKey
   deadline: bigint
@@ -172,8 +313,18 @@ export interface TaskAccount {
   title: string
   descriptionHash: Uint8Array
   deliverableHash: Uint8Array
+  /** Unix timestamp of the latest submission (0 if none) */
+  submittedAt: bigint
   templateIndex: bigint
+  /** From the template; Other for tasks created directly */
+  category: TaskCategory
   rejectionCount: number
+  /** Empty for single-delivery tasks */
+  milestones: Milestone[]
+  currentMilestone: number
+  releasedLamports: bigint
+  /** Platform fee (bps) snapshotted at creation; settlement uses this */
+  feeBps: number
   bump: number
 }
 
@@ -181,6 +332,9 @@ export interface TaskAccount {
 // Manual deserialization (no IDL dependency)
 // ============================================================
 
+/** Borsh size of `PlatformConfig`, embedded in Platform as `pending_config` */
+const PLATFORM_CONFIG_SIZE = 149
+
 export function decodePlatform(data: Buffer): PlatformAccount {
   let offset = 8 // skip discriminator
 
@@ -206,9 +360,61 @@ export function decodePlatform(data: Buffer): PlatformAccount {
   offset += 8
   const claimGracePeriod = data.readBigInt64LE(offset)
   offset += 8
-  const isPaused = data.readUInt8(offset) === 1
+  const pauseFlags = data.readUInt8(offset)
   offset += 1
   const bump = data.readUInt8(offset)
+  offset += 1
+  // Fields appended after the first deployment
+  const reviewPeriod = data.readBigInt64LE(offset)
+  offset += 8
+  const disputeFallbackPeriod = data.readBigInt64LE(offset)
+  offset += 8
+  const arbitratorStakeLamports = data.readBigUInt64LE(offset)
+  offset += 8
+  const arbitratorRewardBps = data.readUInt16LE(offset)
+  offset += 2
+  const slashBps = data.readUInt16LE(offset)
+  offset += 2
+  const appealWindow = data.readBigInt64LE(offset)
+  offset += 8
+  const appealBondLamports = data.readBigUInt64LE(offset)
+  offset += 8
+  const maxAppealRounds = data.readUInt8(offset)
+  offset += 1
+  const voteWeightMode = VOTE_WEIGHT_MODE[data.readUInt8(offset)] ?? 'Flat'
+  offset += 1
+  const voteWeightCap = data.readBigUInt64LE(offset)
+  offset += 8
+  const disputeMinWeight = data.readBigUInt64LE(offset)
+  offset += 8
+  const jurySize = data.readUInt8(offset)
+  offset += 1
+  const minArbitratorStakeLamports = data.readBigUInt64LE(offset)
+  offset += 8
+  const arbitratorApprovalRequired = data.readUInt8(offset) === 1
+  offset += 1
+  const arbitratorRepReward = data.readBigInt64LE(offset)
+  offset += 8
+  const arbitratorRepPenalty = data.readBigInt64LE(offset)
+  offset += 8
+  const disputeBondLamports = data.readBigUInt64LE(offset)
+  offset += 8
+  const configTimelock = data.readBigInt64LE(offset)
+  offset += 8
+  const emergencyWithdrawDelay = data.readBigInt64LE(offset)
+  offset += 8
+  const emergencyAgentShareBps = data.readUInt16LE(offset)
+  offset += 2
+  const pendingAuthority = new PublicKey(data.subarray(offset, offset + 32))
+  offset += 32
+  // PlatformConfig: fee_bps leads; skip the rest of the 149-byte struct
+  const pendingFeeBps = data.readUInt16LE(offset)
+  offset += PLATFORM_CONFIG_SIZE
+  const pendingTreasury = new PublicKey(data.subarray(offset, offset + 32))
+  offset += 32
+  const configEffectiveAt = data.readBigInt64LE(offset)
+  offset += 8
+  const settlementPausedAt = data.readBigInt64LE(offset)
 
   return {
     authority,
@@ -222,7 +428,33 @@ export function decodePlatform(data: Buffer): PlatformAccount {
     disputeMinVotes,
     minVoterReputation,
     claimGracePeriod,
-    isPaused,
+    reviewPeriod,
+    disputeFallbackPeriod,
+    arbitratorStakeLamports,
+    arbitratorRewardBps,
+    slashBps,
+    appealWindow,
+    appealBondLamports,
+    maxAppealRounds,
+    voteWeightMode,
+    voteWeightCap,
+    disputeMinWeight,
+    jurySize,
+    minArbitratorStakeLamports,
+    arbitratorApprovalRequired,
+    arbitratorRepReward,
+    arbitratorRepPenalty,
+    disputeBondLamports,
+    configTimelock,
+    emergencyWithdrawDelay,
+    emergencyAgentShareBps,
+    pendingAuthority,
+    pendingFeeBps,
+    pendingTreasury,
+    configEffectiveAt,
+    pauseFlags,
+    isPaused: pauseFlags !== 0,
+    settlementPausedAt,
     bump,
   }
 }
@@ -306,12 +538,41 @@ export function decodeTask(pubkey: PublicKey, data: Buffer): TaskAccount {
   const rejectionCount = data.readUInt8(offset)
   offset += 1
   const bump = data.readUInt8(offset)
+  offset += 1
+  // Fields appended after the first deployment
+  const bountyMint = new PublicKey(data.subarray(offset, offset + 32))
+  offset += 32
+  const submittedAt = data.readBigInt64LE(offset)
+  offset += 8
+  const category = TASK_CATEGORY[data.readUInt8(offset)] ?? 'Other'
+  offset += 1
+
+  // Vec<Milestone>: 4-byte length prefix + 48-byte entries
+  const milestoneCount = data.readUInt32LE(offset)
+  offset += 4
+  const milestones: Milestone[] = []
+  for (let i = 0; i < milestoneCount; i++) {
+    const titleHash = new Uint8Array(data.subarray(offset, offset + 32))
+    offset += 32
+    const amount = data.readBigUInt64LE(offset)
+    offset += 8
+    const milestoneDeadline = data.readBigInt64LE(offset)
+    offset += 8
+    milestones.push({ titleHash, amount, deadline: milestoneDeadline })
+  }
+
+  const currentMilestone = data.readUInt8(offset)
+  offset += 1
+  const releasedLamports = data.readBigUInt64LE(offset)
+  offset += 8
+  const feeBps = data.readUInt16LE(offset)
 
   return {
     publicKey: pubkey,
     creator,
     taskIndex,
     bountyLamports,
+    bountyMint,
     status,
     agent,
     deadline,
@@ -321,8 +582,14 @@ export function decodeTask(pubkey: PublicKey, data: Buffer): TaskAccount {
     title,
     descriptionHash,
     deliverableHash,
+    submittedAt,
     templateIndex,
+    category,
     rejectionCount,
+    milestones,
+    currentMilestone,
+    releasedLamports,
+    feeBps,
     bump,
   }
 }
@@ -83,6 +83,9 @@ interface BuildTxResponse {
   transaction: string
 }
 
+/** Actions that create a task and may carry a description to store off-chain */
+const TASK_CREATION_ACTIONS = ['createTask', 'createMilestoneTask', 'createTaskFromTemplate']
+
 /**
  * POST /verbitto/execute
  * Execute a Verbitto action (build + sign + send transaction)
@@ -97,7 +100,7 @@ app.post('/verbitto/execute', async (req, res) => {
 
   // Auto-compute descriptionHash from description text if not provided
   if (
-    (action === 'createTask' || action === 'createTaskFromTemplate') &&
+    TASK_CREATION_ACTIONS.includes(action) &&
     typeof params.description === 'string' &&
     params.description.length > 0 &&
     !params.descriptionHash
@@ -159,9 +162,9 @@ app.post('/verbitto/execute', async (req, res) => {
 
     const result = (await sendRes.json()) as { signature: string }
 
-    // Step 5: Store description off-chain for createTask / createTaskFromTemplate
+    // Step 5: Store description off-chain for the task creation actions
     if (
-      (action === 'createTask' || action === 'createTaskFromTemplate') &&
+      TASK_CREATION_ACTIONS.includes(action) &&
       typeof params.description === 'string' &&
       params.description.length > 0
     ) {
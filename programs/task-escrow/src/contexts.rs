@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::VerbittoError;
 use crate::state::*;
@@ -40,134 +41,1303 @@ pub struct PlatformAdmin<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.pending_authority == new_authority.key() @ VerbittoError::NotPendingAuthority,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyPlatformConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Anyone can crank a due configuration change.
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePlatform<'info> {
+    /// CHECK: Platform in its first-deployment layout, which `Account<Platform>`
+    /// can't deserialize. Checked in `migrate_platform`.
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+    )]
+    pub platform: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateTask<'info> {
+    /// CHECK: Task in its first-deployment layout. Checked in `migrate_task`.
+    #[account(mut)]
+    pub task: AccountInfo<'info>,
+
+    /// Platform config (needed for the fee snapshotted on the task).
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Anyone can migrate a task, paying its extra rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAgentProfile<'info> {
+    /// CHECK: Agent profile in its first-deployment layout. Checked in
+    /// `migrate_agent_profile`.
+    #[account(mut)]
+    pub agent_profile: AccountInfo<'info>,
+
+    /// Anyone can migrate a profile, paying its extra rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateDispute<'info> {
+    /// CHECK: Dispute in its first-deployment layout. Checked in
+    /// `migrate_dispute`.
+    #[account(mut)]
+    pub dispute: AccountInfo<'info>,
+
+    /// Platform config (voting period and quorum for the carried-over round).
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Anyone can migrate a dispute, paying its extra rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Governance::INIT_SPACE,
+        seeds = [b"governance"],
+        bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Its authority is handed to the governance PDA.
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = authority @ VerbittoError::NotPlatformAuthority,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + GovernanceProposal::INIT_SPACE,
+        seeds = [b"proposal", &governance.proposal_count.to_le_bytes()],
+        bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    /// Read to check configuration patches against.
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Governance signer proposing the change (checked in the handler).
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReviewProposal<'info> {
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal.proposal_index.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    /// Governance signer approving, or the proposer cancelling.
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal.proposal_index.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Any governance signer (checked in the handler).
+    pub executor: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct RegisterAgent<'info> {
     #[account(
         init,
-        payer = authority,
-        space = 8 + AgentProfile::INIT_SPACE,
-        seeds = [b"agent", authority.key().as_ref()],
+        payer = authority,
+        space = 8 + AgentProfile::INIT_SPACE,
+        seeds = [b"agent", authority.key().as_ref()],
+        bump,
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAgentSkills<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent", authority.key().as_ref()],
+        bump = agent_profile.bump,
+        has_one = authority @ VerbittoError::NotProfileOwner,
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeArbitratorPool<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ArbitratorPool::INIT_SPACE,
+        seeds = [b"arbitrator_pool"],
+        bump,
+    )]
+    pub arbitrator_pool: Account<'info, ArbitratorPool>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Anyone can create the pool, paying its rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterArbitrator<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Arbitrator::INIT_SPACE,
+        seeds = [b"arbitrator", authority.key().as_ref()],
+        bump,
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+
+    #[account(
+        mut,
+        seeds = [b"arbitrator_pool"],
+        bump = arbitrator_pool.bump,
+    )]
+    pub arbitrator_pool: Account<'info, ArbitratorPool>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Arbitrators must hold a registered agent profile.
+    #[account(
+        seeds = [b"agent", authority.key().as_ref()],
+        bump = agent_profile.bump,
+        has_one = authority @ VerbittoError::NotProfileOwner,
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateArbitrator<'info> {
+    #[account(
+        mut,
+        seeds = [b"arbitrator", authority.key().as_ref()],
+        bump = arbitrator.bump,
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+
+    #[account(
+        mut,
+        seeds = [b"arbitrator_pool"],
+        bump = arbitrator_pool.bump,
+    )]
+    pub arbitrator_pool: Account<'info, ArbitratorPool>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetArbitratorApproval<'info> {
+    #[account(
+        mut,
+        seeds = [b"arbitrator", arbitrator.authority.as_ref()],
+        bump = arbitrator.bump,
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+
+    #[account(
+        mut,
+        seeds = [b"arbitrator_pool"],
+        bump = arbitrator_pool.bump,
+    )]
+    pub arbitrator_pool: Account<'info, ArbitratorPool>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Platform authority, or a governance signer executing `proposal`.
+    pub authority: Signer<'info>,
+
+    /// Governance holding the platform authority (governed platforms only).
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump,
+    )]
+    pub governance: Option<Account<'info, Governance>>,
+
+    /// Approved `SetArbitratorApproval` proposal (governed platforms only).
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal.proposal_index.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Option<Account<'info, GovernanceProposal>>,
+}
+
+#[derive(Accounts)]
+pub struct DeregisterArbitrator<'info> {
+    #[account(
+        mut,
+        seeds = [b"arbitrator", authority.key().as_ref()],
+        bump = arbitrator.bump,
+        close = authority,
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+
+    #[account(
+        mut,
+        seeds = [b"arbitrator_pool"],
+        bump = arbitrator_pool.bump,
+    )]
+    pub arbitrator_pool: Account<'info, ArbitratorPool>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, description_hash: [u8; 32], bounty_lamports: u64, task_index: u64)]
+pub struct CreateTask<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Task::INIT_SPACE,
+        seeds = [
+            b"task",
+            creator.key().as_ref(),
+            &task_index.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorCounter::INIT_SPACE,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_counter: Account<'info, CreatorCounter>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// SPL / Token-2022 bounty mint. Omit for a SOL bounty.
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Task-owned vault holding a token bounty. Omit for a SOL bounty.
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"vault", task.key().as_ref()],
+        bump,
+        token::mint = bounty_mint,
+        token::authority = task,
+        token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's token account funding the bounty. Omit for a SOL bounty.
+    #[account(
+        mut,
+        token::mint = bounty_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(bounty_lamports: u64, deadline: i64, reputation_reward: i64, task_index: u64)]
+pub struct CreateTaskFromTemplate<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Task::INIT_SPACE,
+        seeds = [
+            b"task",
+            creator.key().as_ref(),
+            &task_index.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorCounter::INIT_SPACE,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_counter: Account<'info, CreatorCounter>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"template",
+            template.creator.as_ref(),
+            &template.template_index.to_le_bytes(),
+        ],
+        bump = template.bump,
+        constraint = template.is_active @ VerbittoError::TemplateInactive,
+    )]
+    pub template: Account<'info, TaskTemplate>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// SPL / Token-2022 bounty mint. Omit for a SOL bounty.
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Task-owned vault holding a token bounty. Omit for a SOL bounty.
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"vault", task.key().as_ref()],
+        bump,
+        token::mint = bounty_mint,
+        token::authority = task,
+        token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's token account funding the bounty. Omit for a SOL bounty.
+    #[account(
+        mut,
+        token::mint = bounty_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimTask<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Agent must have a registered profile to claim tasks.
+    #[account(
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_profile.bump,
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitDeliverable<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveAndSettle<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Agent account to receive payout. Verified against task.agent.
+    #[account(
+        mut,
+        constraint = agent.key() == task.agent @ VerbittoError::NotAssignedAgent,
+    )]
+    pub agent: AccountInfo<'info>,
+
+    /// Agent's on-chain profile. Updated with completion stats.
+    #[account(
+        mut,
+        seeds = [b"agent", task.agent.as_ref()],
+        bump = agent_profile.bump,
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    /// CHECK: Platform treasury to receive fee. Verified against platform.treasury.
+    #[account(
+        mut,
+        constraint = treasury.key() == platform.treasury @ VerbittoError::InvalidTreasury,
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    /// Bounty mint (token bounties only).
+    #[account(
+        mut,
+        address = task.bounty_mint @ VerbittoError::BountyMintMismatch,
+    )]
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Task vault holding the token bounty (token bounties only).
+    #[account(
+        mut,
+        seeds = [b"vault", task.key().as_ref()],
+        bump,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Agent's token account for the payout (token bounties only).
+    #[account(
+        mut,
+        constraint = agent_token_account.mint == task.bounty_mint
+            && agent_token_account.owner == task.agent @ VerbittoError::InvalidTokenAccount,
+    )]
+    pub agent_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury token account for the fee (token bounties only).
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == task.bounty_mint
+            && treasury_token_account.owner == platform.treasury @ VerbittoError::InvalidTokenAccount,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct AutoApproveTask<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// CHECK: Task creator, receives rent once the task closes. Verified against task.creator.
+    #[account(
+        mut,
+        constraint = creator.key() == task.creator @ VerbittoError::NotTaskCreator,
+    )]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Agent account to receive payout. Verified against task.agent.
+    #[account(
+        mut,
+        constraint = agent.key() == task.agent @ VerbittoError::NotAssignedAgent,
+    )]
+    pub agent: AccountInfo<'info>,
+
+    /// Agent's on-chain profile. Updated with completion stats.
+    #[account(
+        mut,
+        seeds = [b"agent", task.agent.as_ref()],
+        bump = agent_profile.bump,
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    /// CHECK: Platform treasury to receive fee. Verified against platform.treasury.
+    #[account(
+        mut,
+        constraint = treasury.key() == platform.treasury @ VerbittoError::InvalidTreasury,
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    /// Bounty mint (token bounties only).
+    #[account(
+        mut,
+        address = task.bounty_mint @ VerbittoError::BountyMintMismatch,
+    )]
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Task vault holding the token bounty (token bounties only).
+    #[account(
+        mut,
+        seeds = [b"vault", task.key().as_ref()],
+        bump,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Agent's token account for the payout (token bounties only).
+    #[account(
+        mut,
+        constraint = agent_token_account.mint == task.bounty_mint
+            && agent_token_account.owner == task.agent @ VerbittoError::InvalidTokenAccount,
+    )]
+    pub agent_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury token account for the fee (token bounties only).
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == task.bounty_mint
+            && treasury_token_account.owner == platform.treasury @ VerbittoError::InvalidTokenAccount,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Anyone can crank auto-approval once the review window has passed.
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RejectSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Dispute opened on the rejection that reaches MAX_REJECTIONS.
+    /// Must be supplied on that rejection only.
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump,
+    )]
+    pub dispute: Option<Account<'info, Dispute>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelTask<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    /// Platform config (claim grace period).
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Bounty mint (token bounties only).
+    #[account(
+        mut,
+        address = task.bounty_mint @ VerbittoError::BountyMintMismatch,
+    )]
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Task vault holding the token bounty (token bounties only).
+    #[account(
+        mut,
+        seeds = [b"vault", task.key().as_ref()],
+        bump,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's token account for the refund (token bounties only).
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == task.bounty_mint
+            && creator_token_account.owner == task.creator @ VerbittoError::InvalidTokenAccount,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireTask<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+        close = creator,
+    )]
+    pub task: Account<'info, Task>,
+
+    /// CHECK: Task creator to receive refund. Verified against task.creator.
+    #[account(
+        mut,
+        constraint = creator.key() == task.creator @ VerbittoError::NotTaskCreator,
+    )]
+    pub creator: AccountInfo<'info>,
+
+    /// Platform config (needed for claim_grace_period).
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Anyone can trigger expiration.
+    pub caller: Signer<'info>,
+
+    /// Bounty mint (token bounties only).
+    #[account(
+        mut,
+        address = task.bounty_mint @ VerbittoError::BountyMintMismatch,
+    )]
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Task vault holding the token bounty (token bounties only).
+    #[account(
+        mut,
+        seeds = [b"vault", task.key().as_ref()],
+        bump,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's token account for the refund (token bounties only).
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == task.bounty_mint
+            && creator_token_account.owner == task.creator @ VerbittoError::InvalidTokenAccount,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+        close = creator,
+    )]
+    pub task: Account<'info, Task>,
+
+    /// Platform config (needed for the pause state and emergency settings).
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// CHECK: Task creator to receive the refund. Verified against task.creator.
+    #[account(
+        mut,
+        constraint = creator.key() == task.creator @ VerbittoError::NotTaskCreator,
+    )]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Agent to receive its share of submitted work. Verified against task.agent.
+    #[account(
+        mut,
+        constraint = agent.key() == task.agent @ VerbittoError::NotAssignedAgent,
+    )]
+    pub agent: AccountInfo<'info>,
+
+    /// The creator, or the agent of a Submitted task.
+    pub caller: Signer<'info>,
+
+    /// Bounty mint (token bounties only).
+    #[account(
+        mut,
+        address = task.bounty_mint @ VerbittoError::BountyMintMismatch,
+    )]
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Task vault holding the token bounty (token bounties only).
+    #[account(
+        mut,
+        seeds = [b"vault", task.key().as_ref()],
+        bump,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's token account for the refund (token bounties only).
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == task.bounty_mint
+            && creator_token_account.owner == task.creator @ VerbittoError::InvalidTokenAccount,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Agent's token account for its share (token bounties only).
+    #[account(
+        mut,
+        constraint = agent_token_account.mint == task.bounty_mint
+            && agent_token_account.owner == task.agent @ VerbittoError::InvalidTokenAccount,
+    )]
+    pub agent_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, description_hash: [u8; 32], default_bounty_lamports: u64, category: TaskCategory)]
+pub struct CreateTemplate<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + TaskTemplate::INIT_SPACE,
+        seeds = [
+            b"template",
+            creator.key().as_ref(),
+            &platform.template_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub template: Account<'info, TaskTemplate>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DeactivateTemplate<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"template",
+            template.creator.as_ref(),
+            &template.template_index.to_le_bytes(),
+        ],
+        bump = template.bump,
+        has_one = creator @ VerbittoError::NotTaskCreator,
+    )]
+    pub template: Account<'info, TaskTemplate>,
+
+    /// Platform config (needed for pause check).
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        init,
+        payer = initiator,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [b"dispute", task.key().as_ref()],
         bump,
     )]
-    pub agent_profile: Account<'info, AgentProfile>,
+    pub dispute: Account<'info, Dispute>,
 
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub initiator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateAgentSkills<'info> {
+pub struct DrawJury<'info> {
+    #[account(
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+        constraint = dispute.task == task.key() @ VerbittoError::DisputeTaskMismatch,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
     #[account(
         mut,
-        seeds = [b"agent", authority.key().as_ref()],
-        bump = agent_profile.bump,
-        has_one = authority @ VerbittoError::NotProfileOwner,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
     )]
-    pub agent_profile: Account<'info, AgentProfile>,
+    pub dispute: Account<'info, Dispute>,
 
-    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"arbitrator_pool"],
+        bump = arbitrator_pool.bump,
+    )]
+    pub arbitrator_pool: Account<'info, ArbitratorPool>,
+
+    /// CHECK: SlotHashes sysvar, read raw for the committed draw slot's hash.
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(title: String, description_hash: [u8; 32], bounty_lamports: u64, task_index: u64)]
-pub struct CreateTask<'info> {
+pub struct SubmitEvidence<'info> {
     #[account(
-        init,
-        payer = creator,
-        space = 8 + Task::INIT_SPACE,
         seeds = [
             b"task",
-            creator.key().as_ref(),
-            &task_index.to_le_bytes(),
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
         ],
-        bump,
+        bump = task.bump,
+        constraint = dispute.task == task.key() @ VerbittoError::DisputeTaskMismatch,
     )]
     pub task: Account<'info, Task>,
 
     #[account(
-        init_if_needed,
-        payer = creator,
-        space = 8 + CreatorCounter::INIT_SPACE,
-        seeds = [b"creator", creator.key().as_ref()],
-        bump,
+        seeds = [b"platform"],
+        bump = platform.bump,
     )]
-    pub creator_counter: Account<'info, CreatorCounter>,
+    pub platform: Account<'info, Platform>,
 
     #[account(
         mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Creator or agent adding evidence.
+    pub submitter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FlagDispute<'info> {
+    #[account(
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+        constraint = dispute.task == task.key() @ VerbittoError::DisputeTaskMismatch,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
         seeds = [b"platform"],
         bump = platform.bump,
     )]
     pub platform: Account<'info, Platform>,
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
 
-    pub system_program: Program<'info, System>,
+    /// Creator or agent asking for admin resolution.
+    pub party: Signer<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(bounty_lamports: u64, deadline: i64, reputation_reward: i64, task_index: u64)]
-pub struct CreateTaskFromTemplate<'info> {
+pub struct CastVote<'info> {
+    /// Task account referenced by the dispute. Used to verify voter is not a party.
     #[account(
-        init,
-        payer = creator,
-        space = 8 + Task::INIT_SPACE,
         seeds = [
             b"task",
-            creator.key().as_ref(),
-            &task_index.to_le_bytes(),
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
         ],
-        bump,
+        bump = task.bump,
+        constraint = dispute.task == task.key() @ VerbittoError::DisputeTaskMismatch,
     )]
     pub task: Account<'info, Task>,
 
     #[account(
-        init_if_needed,
-        payer = creator,
-        space = 8 + CreatorCounter::INIT_SPACE,
-        seeds = [b"creator", creator.key().as_ref()],
+        mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + ArbitratorVote::INIT_SPACE,
+        seeds = [
+            b"vote",
+            dispute.key().as_ref(),
+            voter.key().as_ref(),
+        ],
         bump,
     )]
-    pub creator_counter: Account<'info, CreatorCounter>,
+    pub vote: Account<'info, ArbitratorVote>,
 
+    /// Voter must have a registered agent profile (sybil protection).
+    #[account(
+        seeds = [b"agent", voter.key().as_ref()],
+        bump = voter_profile.bump,
+    )]
+    pub voter_profile: Account<'info, AgentProfile>,
+
+    /// Voter must be a registered arbitrator for the task's category.
     #[account(
         mut,
+        seeds = [b"arbitrator", voter.key().as_ref()],
+        bump = arbitrator.bump,
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    #[account(
         seeds = [
-            b"template",
-            template.creator.as_ref(),
-            &template.template_index.to_le_bytes(),
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
         ],
-        bump = template.bump,
-        constraint = template.is_active @ VerbittoError::TemplateInactive,
+        bump = task.bump,
+        constraint = dispute.task == task.key() @ VerbittoError::DisputeTaskMismatch,
     )]
-    pub template: Account<'info, TaskTemplate>,
+    pub task: Account<'info, Task>,
 
     #[account(
-        mut,
         seeds = [b"platform"],
         bump = platform.bump,
     )]
     pub platform: Account<'info, Platform>,
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
 
-    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        seeds = [
+            b"vote",
+            dispute.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump = vote.bump,
+    )]
+    pub vote: Account<'info, ArbitratorVote>,
+
+    pub voter: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ClaimTask<'info> {
+pub struct ChangeVote<'info> {
+    #[account(
+        seeds = [
+            b"task",
+            task.creator.as_ref(),
+            &task.task_index.to_le_bytes(),
+        ],
+        bump = task.bump,
+        constraint = dispute.task == task.key() @ VerbittoError::DisputeTaskMismatch,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
     #[account(
         mut,
+        seeds = [
+            b"vote",
+            dispute.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump = vote.bump,
+    )]
+    pub vote: Account<'info, ArbitratorVote>,
+
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVote<'info> {
+    #[account(
         seeds = [
             b"task",
             task.creator.as_ref(),
             &task.task_index.to_le_bytes(),
         ],
         bump = task.bump,
+        constraint = dispute.task == task.key() @ VerbittoError::DisputeTaskMismatch,
     )]
     pub task: Account<'info, Task>,
 
@@ -177,40 +1347,93 @@ pub struct ClaimTask<'info> {
     )]
     pub platform: Account<'info, Platform>,
 
-    /// Agent must have a registered profile to claim tasks.
     #[account(
-        seeds = [b"agent", agent.key().as_ref()],
-        bump = agent_profile.bump,
+        mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Closed to the voter, returning the stake and rent.
+    #[account(
+        mut,
+        seeds = [
+            b"vote",
+            dispute.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump = vote.bump,
+        close = voter,
+    )]
+    pub vote: Account<'info, ArbitratorVote>,
+
+    /// Voter's registration, one active vote fewer.
+    #[account(
+        mut,
+        seeds = [b"arbitrator", voter.key().as_ref()],
+        bump = arbitrator.bump,
     )]
-    pub agent_profile: Account<'info, AgentProfile>,
+    pub arbitrator: Account<'info, Arbitrator>,
 
-    pub agent: Signer<'info>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct SubmitDeliverable<'info> {
+pub struct AppealDispute<'info> {
     #[account(
-        mut,
         seeds = [
             b"task",
             task.creator.as_ref(),
             &task.task_index.to_le_bytes(),
         ],
         bump = task.bump,
+        constraint = dispute.task == task.key() @ VerbittoError::DisputeTaskMismatch,
     )]
     pub task: Account<'info, Task>,
 
+    /// Holds the appeal bond until the next round decides it.
+    #[account(
+        mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
     #[account(
         seeds = [b"platform"],
         bump = platform.bump,
     )]
     pub platform: Account<'info, Platform>,
 
-    pub agent: Signer<'info>,
+    /// Creator or agent appealing the current ruling.
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+
+    /// CHECK: The other task party, refunded a previous appeal bond it won.
+    /// Verified against task.creator / task.agent.
+    #[account(
+        mut,
+        constraint = counterparty.key() != appellant.key()
+            && (counterparty.key() == task.creator || counterparty.key() == task.agent)
+            @ VerbittoError::NotTaskParty,
+    )]
+    pub counterparty: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ApproveAndSettle<'info> {
+pub struct ResolveDispute<'info> {
+    /// Stays open after resolution until every vote is settled,
+    /// holding the arbitrators' reward pool.
+    #[account(
+        mut,
+        seeds = [b"dispute", task.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
     #[account(
         mut,
         seeds = [
@@ -230,17 +1453,21 @@ pub struct ApproveAndSettle<'info> {
     )]
     pub platform: Account<'info, Platform>,
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
+    /// CHECK: Task creator for refund (verified against task.creator).
+    #[account(
+        mut,
+        constraint = creator.key() == task.creator @ VerbittoError::NotTaskCreator,
+    )]
+    pub creator: AccountInfo<'info>,
 
-    /// CHECK: Agent account to receive payout. Verified against task.agent.
+    /// CHECK: Task agent for payout (verified against task.agent).
     #[account(
         mut,
         constraint = agent.key() == task.agent @ VerbittoError::NotAssignedAgent,
     )]
     pub agent: AccountInfo<'info>,
 
-    /// Agent's on-chain profile. Updated with completion stats.
+    /// Agent's on-chain profile. Updated with dispute outcome.
     #[account(
         mut,
         seeds = [b"agent", task.agent.as_ref()],
@@ -248,181 +1475,152 @@ pub struct ApproveAndSettle<'info> {
     )]
     pub agent_profile: Account<'info, AgentProfile>,
 
-    /// CHECK: Platform treasury to receive fee. Verified against platform.treasury.
+    /// CHECK: Platform treasury (verified against platform.treasury).
     #[account(
         mut,
         constraint = treasury.key() == platform.treasury @ VerbittoError::InvalidTreasury,
     )]
     pub treasury: AccountInfo<'info>,
-}
 
-#[derive(Accounts)]
-pub struct RejectSubmission<'info> {
+    /// CHECK: Dispute initiator, receiving its bond back and the dispute rent
+    /// when the dispute closes. Verified against dispute.initiator.
     #[account(
         mut,
-        seeds = [
-            b"task",
-            task.creator.as_ref(),
-            &task.task_index.to_le_bytes(),
-        ],
-        bump = task.bump,
+        address = dispute.initiator @ VerbittoError::NotTaskParty,
     )]
-    pub task: Account<'info, Task>,
+    pub initiator: AccountInfo<'info>,
 
-    pub creator: Signer<'info>,
-}
+    /// Anyone can trigger dispute resolution after voting period; only the
+    /// platform authority (or a governance signer executing `proposal`) can
+    /// rule via `admin_resolve_dispute`. Pays the reward vault's rent.
+    #[account(mut)]
+    pub caller: Signer<'info>,
 
-#[derive(Accounts)]
-pub struct CancelTask<'info> {
+    /// Bounty mint (token bounties only).
     #[account(
         mut,
-        seeds = [
-            b"task",
-            task.creator.as_ref(),
-            &task.task_index.to_le_bytes(),
-        ],
-        bump = task.bump,
-        close = creator,
+        address = task.bounty_mint @ VerbittoError::BountyMintMismatch,
     )]
-    pub task: Account<'info, Task>,
-
-    #[account(mut)]
-    pub creator: Signer<'info>,
-}
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
 
-#[derive(Accounts)]
-pub struct ExpireTask<'info> {
+    /// Task vault holding the token bounty (token bounties only).
     #[account(
         mut,
-        seeds = [
-            b"task",
-            task.creator.as_ref(),
-            &task.task_index.to_le_bytes(),
-        ],
-        bump = task.bump,
-        close = creator,
+        seeds = [b"vault", task.key().as_ref()],
+        bump,
     )]
-    pub task: Account<'info, Task>,
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: Task creator to receive refund. Verified against task.creator.
+    /// Creator's token account for the refund (token bounties only).
     #[account(
         mut,
-        constraint = creator.key() == task.creator @ VerbittoError::NotTaskCreator,
+        constraint = creator_token_account.mint == task.bounty_mint
+            && creator_token_account.owner == task.creator @ VerbittoError::InvalidTokenAccount,
     )]
-    pub creator: AccountInfo<'info>,
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Platform config (needed for claim_grace_period).
+    /// Agent's token account for the payout (token bounties only).
     #[account(
-        seeds = [b"platform"],
-        bump = platform.bump,
+        mut,
+        constraint = agent_token_account.mint == task.bounty_mint
+            && agent_token_account.owner == task.agent @ VerbittoError::InvalidTokenAccount,
     )]
-    pub platform: Account<'info, Platform>,
-
-    /// Anyone can trigger expiration.
-    pub caller: Signer<'info>,
-}
+    pub agent_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-#[derive(Accounts)]
-#[instruction(title: String, description_hash: [u8; 32], default_bounty_lamports: u64, category: TaskCategory)]
-pub struct CreateTemplate<'info> {
+    /// Treasury token account for the fee (token bounties only).
     #[account(
-        init,
-        payer = creator,
-        space = 8 + TaskTemplate::INIT_SPACE,
-        seeds = [
-            b"template",
-            creator.key().as_ref(),
-            &platform.template_count.to_le_bytes(),
-        ],
-        bump,
+        mut,
+        constraint = treasury_token_account.mint == task.bounty_mint
+            && treasury_token_account.owner == platform.treasury @ VerbittoError::InvalidTokenAccount,
     )]
-    pub template: Account<'info, TaskTemplate>,
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Dispute-owned vault holding the majority voters' share of the fee
+    /// until `settle_vote` pays it out (token bounties only). Its rent goes
+    /// to the initiator with the dispute's, or straight back to the caller
+    /// when there is no share to hold.
     #[account(
-        mut,
-        seeds = [b"platform"],
-        bump = platform.bump,
+        init,
+        payer = caller,
+        seeds = [b"reward_vault", dispute.key().as_ref()],
+        bump,
+        token::mint = bounty_mint,
+        token::authority = dispute,
+        token::token_program = token_program,
     )]
-    pub platform: Account<'info, Platform>,
+    pub reward_vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 
     pub system_program: Program<'info, System>,
-}
 
-#[derive(Accounts)]
-pub struct DeactivateTemplate<'info> {
+    /// Governance holding the platform authority (`admin_resolve_dispute`
+    /// on governed platforms only).
     #[account(
-        mut,
-        seeds = [
-            b"template",
-            template.creator.as_ref(),
-            &template.template_index.to_le_bytes(),
-        ],
-        bump = template.bump,
-        has_one = creator @ VerbittoError::NotTaskCreator,
+        seeds = [b"governance"],
+        bump = governance.bump,
     )]
-    pub template: Account<'info, TaskTemplate>,
+    pub governance: Option<Account<'info, Governance>>,
 
-    /// Platform config (needed for pause check).
+    /// Approved `AdminResolveDispute` proposal (`admin_resolve_dispute` on
+    /// governed platforms only).
     #[account(
-        seeds = [b"platform"],
-        bump = platform.bump,
+        mut,
+        seeds = [b"proposal", &proposal.proposal_index.to_le_bytes()],
+        bump = proposal.bump,
     )]
-    pub platform: Account<'info, Platform>,
-
-    pub creator: Signer<'info>,
+    pub proposal: Option<Account<'info, GovernanceProposal>>,
 }
 
 #[derive(Accounts)]
-pub struct OpenDispute<'info> {
+pub struct SettleVote<'info> {
+    /// Resolved dispute. The task may already be closed, so the dispute is
+    /// checked through the vote's seeds rather than its own.
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+
     #[account(
         mut,
         seeds = [
-            b"task",
-            task.creator.as_ref(),
-            &task.task_index.to_le_bytes(),
+            b"vote",
+            dispute.key().as_ref(),
+            arbitrator.key().as_ref(),
         ],
-        bump = task.bump,
+        bump = vote.bump,
+        close = arbitrator,
     )]
-    pub task: Account<'info, Task>,
+    pub vote: Account<'info, ArbitratorVote>,
 
+    /// CHECK: Arbitrator receiving stake, reward and vote rent. Verified against vote.arbitrator.
     #[account(
-        init,
-        payer = initiator,
-        space = 8 + Dispute::INIT_SPACE,
-        seeds = [b"dispute", task.key().as_ref()],
-        bump,
+        mut,
+        constraint = arbitrator.key() == vote.arbitrator @ VerbittoError::InvalidVoteAccount,
     )]
-    pub dispute: Account<'info, Dispute>,
-
-    #[account(mut)]
-    pub initiator: Signer<'info>,
+    pub arbitrator: AccountInfo<'info>,
 
-    pub system_program: Program<'info, System>,
-}
+    /// Arbitrator's profile, credited or debited reputation for the vote.
+    #[account(
+        mut,
+        seeds = [b"agent", arbitrator.key().as_ref()],
+        bump = arbitrator_profile.bump,
+    )]
+    pub arbitrator_profile: Account<'info, AgentProfile>,
 
-#[derive(Accounts)]
-pub struct CastVote<'info> {
-    /// Task account referenced by the dispute. Used to verify voter is not a party.
+    /// Arbitrator's registration, one active vote fewer.
     #[account(
-        seeds = [
-            b"task",
-            task.creator.as_ref(),
-            &task.task_index.to_le_bytes(),
-        ],
-        bump = task.bump,
-        constraint = dispute.task == task.key() @ VerbittoError::DisputeTaskMismatch,
+        mut,
+        seeds = [b"arbitrator", arbitrator.key().as_ref()],
+        bump = arbitrator_registration.bump,
     )]
-    pub task: Account<'info, Task>,
+    pub arbitrator_registration: Account<'info, Arbitrator>,
 
+    /// CHECK: Dispute initiator, refunded the dispute rent once the last vote
+    /// is settled. Verified against dispute.initiator.
     #[account(
         mut,
-        seeds = [b"dispute", task.key().as_ref()],
-        bump = dispute.bump,
+        constraint = initiator.key() == dispute.initiator @ VerbittoError::NotTaskParty,
     )]
-    pub dispute: Account<'info, Dispute>,
+    pub initiator: AccountInfo<'info>,
 
     #[account(
         seeds = [b"platform"],
@@ -430,90 +1628,94 @@ pub struct CastVote<'info> {
     )]
     pub platform: Account<'info, Platform>,
 
+    /// CHECK: Platform treasury receiving slashed stake (verified against platform.treasury).
     #[account(
-        init,
-        payer = voter,
-        space = 8 + ArbitratorVote::INIT_SPACE,
-        seeds = [
-            b"vote",
-            dispute.key().as_ref(),
-            voter.key().as_ref(),
-        ],
-        bump,
-    )]
-    pub vote: Account<'info, ArbitratorVote>,
-
-    /// Voter must have a registered agent profile (sybil protection).
-    #[account(
-        seeds = [b"agent", voter.key().as_ref()],
-        bump = voter_profile.bump,
+        mut,
+        constraint = treasury.key() == platform.treasury @ VerbittoError::InvalidTreasury,
     )]
-    pub voter_profile: Account<'info, AgentProfile>,
+    pub treasury: AccountInfo<'info>,
 
-    #[account(mut)]
-    pub voter: Signer<'info>,
+    /// Anyone can settle a vote once the dispute is resolved.
+    pub caller: Signer<'info>,
 
-    pub system_program: Program<'info, System>,
-}
+    /// Bounty mint (disputes with a token reward pool only).
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
 
-#[derive(Accounts)]
-pub struct ResolveDispute<'info> {
+    /// Dispute vault holding the token reward pool (disputes with one only).
     #[account(
         mut,
-        seeds = [b"dispute", task.key().as_ref()],
-        bump = dispute.bump,
-        close = creator,
+        seeds = [b"reward_vault", dispute.key().as_ref()],
+        bump,
+        token::mint = bounty_mint,
+        token::token_program = token_program,
     )]
-    pub dispute: Account<'info, Dispute>,
+    pub reward_vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Arbitrator's token account for its reward (majority votes on
+    /// disputes with a token reward pool only). Checked in the handler.
+    #[account(mut)]
+    pub arbitrator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury token account for the pool's rounding dust, swept when the
+    /// last vote is settled (disputes with a token reward pool only).
     #[account(
         mut,
-        seeds = [
-            b"task",
-            task.creator.as_ref(),
-            &task.task_index.to_le_bytes(),
-        ],
-        bump = task.bump,
-        close = creator,
+        constraint = treasury_token_account.owner == platform.treasury @ VerbittoError::InvalidTokenAccount,
     )]
-    pub task: Account<'info, Task>,
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseVotes<'info> {
+    /// CHECK: Dispute the batched votes belong to: either a resolved Dispute
+    /// (votes are settled) or an account that no longer exists (votes are
+    /// refunded). Checked in the handler.
+    #[account(mut)]
+    pub dispute: AccountInfo<'info>,
+
+    /// CHECK: Dispute initiator, refunded the dispute rent once the last vote
+    /// is settled. Verified against dispute.initiator while the dispute exists.
+    #[account(mut)]
+    pub initiator: AccountInfo<'info>,
 
     #[account(
-        mut,
         seeds = [b"platform"],
         bump = platform.bump,
     )]
     pub platform: Account<'info, Platform>,
 
-    /// CHECK: Task creator for refund (verified against task.creator).
+    /// CHECK: Platform treasury receiving slashed stake (verified against platform.treasury).
     #[account(
         mut,
-        constraint = creator.key() == task.creator @ VerbittoError::NotTaskCreator,
+        constraint = treasury.key() == platform.treasury @ VerbittoError::InvalidTreasury,
     )]
-    pub creator: AccountInfo<'info>,
+    pub treasury: AccountInfo<'info>,
 
-    /// CHECK: Task agent for payout (verified against task.agent).
-    #[account(
-        mut,
-        constraint = agent.key() == task.agent @ VerbittoError::NotAssignedAgent,
-    )]
-    pub agent: AccountInfo<'info>,
+    /// Anyone can close votes.
+    pub caller: Signer<'info>,
 
-    /// Agent's on-chain profile. Updated with dispute outcome.
+    /// Bounty mint (disputes with a token reward pool only).
+    pub bounty_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Dispute vault holding the token reward pool (disputes with one only).
     #[account(
         mut,
-        seeds = [b"agent", task.agent.as_ref()],
-        bump = agent_profile.bump,
+        seeds = [b"reward_vault", dispute.key().as_ref()],
+        bump,
+        token::mint = bounty_mint,
+        token::token_program = token_program,
     )]
-    pub agent_profile: Account<'info, AgentProfile>,
+    pub reward_vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: Platform treasury (verified against platform.treasury).
+    /// Treasury token account for the pool's rounding dust, swept when the
+    /// last vote is settled (disputes with a token reward pool only).
     #[account(
         mut,
-        constraint = treasury.key() == platform.treasury @ VerbittoError::InvalidTreasury,
+        constraint = treasury_token_account.owner == platform.treasury @ VerbittoError::InvalidTokenAccount,
     )]
-    pub treasury: AccountInfo<'info>,
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Anyone can trigger dispute resolution after voting period.
-    pub caller: Signer<'info>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
@@ -58,7 +58,7 @@ pub enum VerbittoError {
     NotProfileOwner,
     #[msg("Voter reputation is below the minimum required to vote")]
     InsufficientReputation,
-    #[msg("Platform is paused")]
+    #[msg("This instruction class is paused")]
     PlatformPaused,
     #[msg("Platform is already paused")]
     PlatformAlreadyPaused,
@@ -76,4 +76,106 @@ pub enum VerbittoError {
     TemplateAlreadyActive,
     #[msg("Creator cannot claim their own task")]
     CreatorCannotClaim,
+    #[msg("Token accounts are required for a token bounty")]
+    MissingTokenAccounts,
+    #[msg("Mint does not match the task bounty mint")]
+    BountyMintMismatch,
+    #[msg("Token account owner or mint is invalid")]
+    InvalidTokenAccount,
+    #[msg("Milestones must be 1–8 entries with positive amounts and ordered deadlines")]
+    InvalidMilestones,
+    #[msg("Milestone amounts must sum to the bounty")]
+    MilestoneSumMismatch,
+    #[msg("Review period has not ended yet")]
+    ReviewPeriodNotEnded,
+    #[msg("Dispute account must be supplied exactly when the rejection limit is reached")]
+    DisputeAccountMismatch,
+    #[msg("Reveal phase has not started yet")]
+    RevealPhaseNotStarted,
+    #[msg("Reveal phase has ended")]
+    RevealPhaseEnded,
+    #[msg("Revealed ruling and salt do not match the commitment")]
+    CommitmentMismatch,
+    #[msg("Vote has already been revealed")]
+    VoteAlreadyRevealed,
+    #[msg("Dispute has not been resolved yet")]
+    DisputeNotResolved,
+    #[msg("Vote account does not belong to this arbitrator")]
+    InvalidVoteAccount,
+    #[msg("Appeal window has closed")]
+    AppealWindowClosed,
+    #[msg("Appeal window is still open")]
+    AppealWindowOpen,
+    #[msg("Maximum appeal rounds reached")]
+    AppealLimitReached,
+    #[msg("Cannot appeal a ruling in your favor")]
+    CannotAppealRuling,
+    #[msg("Evidence limit reached for this party")]
+    EvidenceLimitReached,
+    #[msg("Submission was made before the deadline")]
+    SubmissionNotLate,
+    #[msg("Window to contest the cancellation has closed")]
+    ContestWindowClosed,
+    #[msg("Split percentage must be a multiple of 10 up to 100, and 0 for other rulings")]
+    InvalidSplitPercentage,
+    #[msg("Arbitrator pool is full")]
+    ArbitratorPoolFull,
+    #[msg("This dispute is open to all eligible voters")]
+    JuryNotRequired,
+    #[msg("Jury already drawn for this round")]
+    JuryAlreadyDrawn,
+    #[msg("Jury has not been drawn yet")]
+    JuryNotDrawn,
+    #[msg("No eligible arbitrators in the pool")]
+    InsufficientJurors,
+    #[msg("Voter is not on this dispute's jury")]
+    NotOnJury,
+    #[msg("Arbitrator stake below platform minimum")]
+    ArbitratorStakeTooLow,
+    #[msg("Categories must be a non-empty set of task categories")]
+    InvalidCategories,
+    #[msg("Arbitrator has not been approved")]
+    ArbitratorNotApproved,
+    #[msg("Arbitrator is not registered for this task's category")]
+    CategoryNotQualified,
+    #[msg("Remaining accounts must be (vote, arbitrator, profile, registration) groups")]
+    InvalidVoteBatch,
+    #[msg("Admin resolution requires paused disputes or a flagged dispute")]
+    AdminResolutionNotAllowed,
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("Caller is not the pending platform authority")]
+    NotPendingAuthority,
+    #[msg("Governance needs 1–10 distinct signers and a threshold between 1 and the signer count")]
+    InvalidGovernanceConfig,
+    #[msg("Caller is not a governance signer")]
+    NotGovernanceSigner,
+    #[msg("Proposal is not active")]
+    ProposalNotActive,
+    #[msg("Signer has already approved this proposal")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal does not have enough approvals")]
+    ThresholdNotMet,
+    #[msg("Caller is not the proposer")]
+    NotProposer,
+    #[msg("Proposal action does not match this instruction")]
+    WrongProposalAction,
+    #[msg("No platform configuration change is queued")]
+    NoPendingConfig,
+    #[msg("Queued platform configuration is still timelocked")]
+    ConfigTimelockActive,
+    #[msg("Pause flags must be a non-empty subset of PAUSE_ALL")]
+    InvalidPauseFlags,
+    #[msg("Settlement has not been paused long enough for an emergency withdrawal")]
+    EmergencyWithdrawNotAvailable,
+    #[msg("Task cannot be withdrawn in its current status")]
+    TaskNotWithdrawable,
+    #[msg("Account is not a program account of the expected type")]
+    InvalidLegacyAccount,
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("Arbitrator still has votes to settle")]
+    ArbitratorHasActiveVotes,
+    #[msg("The jury draw slot has not been reached yet")]
+    JuryDrawTooEarly,
 }
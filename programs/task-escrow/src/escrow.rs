@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::{
+    TransferFeeAmount, TransferFeeConfig,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::{Account as SplAccount, Mint as SplMint};
+use anchor_spl::token_interface::{
+    self, harvest_withheld_tokens_to_mint, CloseAccount, HarvestWithheldTokensToMint, Mint,
+    TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::VerbittoError;
+use crate::state::Task;
+
+// ============================================================
+// Escrow helpers — shared by every path that moves a bounty
+// ============================================================
+
+/// Token accounts backing an SPL / Token-2022 bounty.
+/// Absent (`None`) for SOL tasks, whose bounty sits in the Task PDA itself.
+pub struct TokenEscrow<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+/// Resolve the token escrow for a task from a context's optional accounts.
+/// Returns `None` for SOL tasks; errors if a token task is missing accounts.
+pub fn token_escrow<'a, 'info>(
+    task: &Task,
+    mint: &'a Option<InterfaceAccount<'info, Mint>>,
+    vault: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+    token_program: &'a Option<Interface<'info, TokenInterface>>,
+) -> Result<Option<TokenEscrow<'a, 'info>>> {
+    if !task.is_token_bounty() {
+        return Ok(None);
+    }
+    match (mint, vault, token_program) {
+        (Some(mint), Some(vault), Some(token_program)) => Ok(Some(TokenEscrow {
+            mint,
+            vault,
+            token_program,
+        })),
+        _ => err!(VerbittoError::MissingTokenAccounts),
+    }
+}
+
+/// Token-2022 transfer fee withheld when moving `amount` of `mint`.
+/// Zero for legacy SPL mints and Token-2022 mints without the extension.
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let Ok(state) = StateWithExtensions::<SplMint>::unpack(&data) else {
+        return Ok(0);
+    };
+    let Ok(config) = state.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+    let epoch = Clock::get()?.epoch;
+    config
+        .calculate_epoch_fee(epoch, amount)
+        .ok_or_else(|| error!(VerbittoError::ArithmeticOverflow))
+}
+
+/// Move `amount` of the bounty out of escrow to a recipient.
+///
+/// SOL tasks debit the Task PDA's lamports directly; token tasks CPI a
+/// `transfer_checked` from the vault signed by the Task PDA.
+/// Returns the amount the recipient actually receives (net of any
+/// Token-2022 transfer fee).
+pub fn release<'info>(
+    task: &Account<'info, Task>,
+    escrow: Option<&TokenEscrow<'_, 'info>>,
+    to: &AccountInfo<'info>,
+    to_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+    amount: u64,
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    let Some(e) = escrow else {
+        **task.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? += amount;
+        return Ok(amount);
+    };
+
+    let to_token = to_token.ok_or(VerbittoError::MissingTokenAccounts)?;
+    let index = task.task_index.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"task", task.creator.as_ref(), &index, &[task.bump]];
+    transfer_out(e, &task.to_account_info(), seeds, to_token, amount)
+}
+
+/// Transfer `amount` out of `e.vault`, signed by its PDA owner `authority`
+/// with `seeds`. Returns the amount the recipient actually receives (net of
+/// any Token-2022 transfer fee).
+pub fn transfer_out<'info>(
+    e: &TokenEscrow<'_, 'info>,
+    authority: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    to_token: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            e.token_program.to_account_info(),
+            TransferChecked {
+                from: e.vault.to_account_info(),
+                mint: e.mint.to_account_info(),
+                to: to_token.to_account_info(),
+                authority: authority.clone(),
+            },
+            &[seeds],
+        ),
+        amount,
+        e.mint.decimals,
+    )?;
+
+    let fee = transfer_fee(&e.mint.to_account_info(), amount)?;
+    Ok(amount.saturating_sub(fee))
+}
+
+/// Close the vault after the bounty has been released, returning its rent
+/// to `rent_to`.
+///
+/// Anyone can send tokens to the vault, and a token account with a balance
+/// cannot be closed, so whatever is left is swept to `sweep_to` first.
+/// Withheld Token-2022 transfer fees are harvested to the mint for the same
+/// reason.
+pub fn close_vault<'info>(
+    task: &Account<'info, Task>,
+    escrow: &TokenEscrow<'_, 'info>,
+    sweep_to: Option<&InterfaceAccount<'info, TokenAccount>>,
+    rent_to: &AccountInfo<'info>,
+) -> Result<()> {
+    let index = task.task_index.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"task", task.creator.as_ref(), &index, &[task.bump]];
+    close_token_account(escrow, &task.to_account_info(), seeds, sweep_to, rent_to)
+}
+
+/// Close `escrow.vault`, signed by its PDA owner `authority` with `seeds`,
+/// as `close_vault` does for a task vault.
+pub fn close_token_account<'info>(
+    escrow: &TokenEscrow<'_, 'info>,
+    authority: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    sweep_to: Option<&InterfaceAccount<'info, TokenAccount>>,
+    rent_to: &AccountInfo<'info>,
+) -> Result<()> {
+    let vault_info = escrow.vault.to_account_info();
+    // Read the live balance: the deserialized vault is stale after the
+    // release transfers above.
+    let (balance, withheld) = {
+        let data = vault_info.try_borrow_data()?;
+        let state = StateWithExtensions::<SplAccount>::unpack(&data)?;
+        let withheld = state
+            .get_extension::<TransferFeeAmount>()
+            .map(|f| u64::from(f.withheld_amount))
+            .unwrap_or(0);
+        (state.base.amount, withheld)
+    };
+
+    if balance > 0 {
+        let sweep_to = sweep_to.ok_or(VerbittoError::MissingTokenAccounts)?;
+        transfer_out(escrow, authority, seeds, sweep_to, balance)?;
+    }
+    if withheld > 0 {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                escrow.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: escrow.token_program.to_account_info(),
+                    mint: escrow.mint.to_account_info(),
+                },
+            ),
+            vec![vault_info.clone()],
+        )?;
+    }
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        escrow.token_program.to_account_info(),
+        CloseAccount {
+            account: vault_info,
+            destination: rent_to.clone(),
+            authority: authority.clone(),
+        },
+        &[seeds],
+    ))
+}
+
+/// Escrow a token bounty by transferring it from the creator into the vault.
+/// Returns the amount the vault actually received, which is less than
+/// `amount` for Token-2022 mints with a transfer fee.
+pub fn deposit<'info>(
+    mint: &InterfaceAccount<'info, Mint>,
+    vault: &mut InterfaceAccount<'info, TokenAccount>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    authority: &Signer<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<u64> {
+    let before = vault.amount;
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: vault.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+        ),
+        amount,
+        mint.decimals,
+    )?;
+    vault.reload()?;
+    vault
+        .amount
+        .checked_sub(before)
+        .ok_or_else(|| error!(VerbittoError::ArithmeticOverflow))
+}
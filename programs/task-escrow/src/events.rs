@@ -9,13 +9,103 @@ pub struct PlatformInitialized {
     pub treasury: Pubkey,
 }
 
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct PlatformConfigQueued {
+    /// Configuration in effect until the queued one is applied
+    pub old_config: PlatformConfig,
+    pub new_config: PlatformConfig,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct PlatformUpdated {
+    pub old_config: PlatformConfig,
+    pub new_config: PlatformConfig,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+}
+
+#[event]
+pub struct PlatformPaused {
+    pub paused_by: Pubkey,
+    pub flags: u8,
+    pub pause_flags: u8,
+}
+
+#[event]
+pub struct PlatformResumed {
+    pub resumed_by: Pubkey,
+    pub flags: u8,
+    pub pause_flags: u8,
+}
+
+#[event]
+pub struct GovernanceInitialized {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct GovernanceSignersUpdated {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub proposal_index: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub proposal: Pubkey,
+    pub cancelled_by: Pubkey,
+}
+
 #[event]
 pub struct TaskCreated {
     pub task: Pubkey,
     pub creator: Pubkey,
     pub task_index: u64,
     pub bounty_lamports: u64,
+    pub bounty_mint: Pubkey,
     pub deadline: i64,
+    pub milestone_count: u8,
 }
 
 #[event]
@@ -29,6 +119,7 @@ pub struct TaskClaimed {
 pub struct DeliverableSubmitted {
     pub task: Pubkey,
     pub agent: Pubkey,
+    pub milestone_index: u8,
     pub deliverable_hash: [u8; 32],
 }
 
@@ -36,6 +127,16 @@ pub struct DeliverableSubmitted {
 pub struct TaskSettled {
     pub task: Pubkey,
     pub agent: Pubkey,
+    pub bounty_mint: Pubkey,
+    pub payout_lamports: u64,
+    pub fee_lamports: u64,
+}
+
+#[event]
+pub struct MilestoneSettled {
+    pub task: Pubkey,
+    pub agent: Pubkey,
+    pub milestone_index: u8,
     pub payout_lamports: u64,
     pub fee_lamports: u64,
 }
@@ -44,6 +145,7 @@ pub struct TaskSettled {
 pub struct SubmissionRejected {
     pub task: Pubkey,
     pub agent: Pubkey,
+    pub milestone_index: u8,
     pub reason_hash: [u8; 32],
 }
 
@@ -54,6 +156,13 @@ pub struct TaskCancelled {
     pub refunded_lamports: u64,
 }
 
+#[event]
+pub struct LateSubmissionCancelled {
+    pub task: Pubkey,
+    pub creator: Pubkey,
+    pub agent: Pubkey,
+}
+
 #[event]
 pub struct TaskExpired {
     pub task: Pubkey,
@@ -75,13 +184,82 @@ pub struct DisputeOpened {
     pub task: Pubkey,
     pub initiator: Pubkey,
     pub reason: DisputeReason,
+    pub bond_lamports: u64,
+}
+
+#[event]
+pub struct JuryDrawn {
+    pub dispute: Pubkey,
+    pub round: u8,
+    pub jurors: Vec<Pubkey>,
+}
+
+#[event]
+pub struct JuryDrawDeferred {
+    pub dispute: Pubkey,
+    pub round: u8,
+    pub draw_slot: u64,
+}
+
+#[event]
+pub struct EvidenceSubmitted {
+    pub dispute: Pubkey,
+    pub task: Pubkey,
+    pub submitter: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub submitted_at: i64,
 }
 
 #[event]
 pub struct VoteCast {
+    pub dispute: Pubkey,
+    pub voter: Pubkey,
+    pub commitment: [u8; 32],
+}
+
+#[event]
+pub struct VoteChanged {
+    pub dispute: Pubkey,
+    pub voter: Pubkey,
+    pub commitment: [u8; 32],
+    pub withdrawn: bool,
+}
+
+#[event]
+pub struct VoteRevealed {
     pub dispute: Pubkey,
     pub voter: Pubkey,
     pub ruling: Ruling,
+    pub split_pct: u8,
+    pub weight: u64,
+}
+
+#[event]
+pub struct VoteSettled {
+    pub dispute: Pubkey,
+    pub voter: Pubkey,
+    pub majority: bool,
+    pub reward_lamports: u64,
+    pub reward_tokens: u64,
+    pub slashed_lamports: u64,
+    pub reputation_delta: i64,
+}
+
+#[event]
+pub struct VoteClosed {
+    pub dispute: Pubkey,
+    pub voter: Pubkey,
+    pub refund_lamports: u64,
+}
+
+#[event]
+pub struct DisputeAppealed {
+    pub dispute: Pubkey,
+    pub task: Pubkey,
+    pub appellant: Pubkey,
+    pub appealed_ruling: Ruling,
+    pub round: u8,
+    pub bond_lamports: u64,
 }
 
 #[event]
@@ -89,7 +267,27 @@ pub struct DisputeResolved {
     pub dispute: Pubkey,
     pub task: Pubkey,
     pub ruling: Ruling,
+    pub resolution: ResolutionPath,
     pub total_votes: u16,
+    pub agent_share_pct: u8,
+    pub bond_outcome: BondOutcome,
+}
+
+#[event]
+pub struct DisputeFlagged {
+    pub dispute: Pubkey,
+    pub task: Pubkey,
+    pub flagged_by: Pubkey,
+}
+
+#[event]
+pub struct DisputeAdminResolved {
+    pub dispute: Pubkey,
+    pub task: Pubkey,
+    pub authority: Pubkey,
+    pub ruling: Ruling,
+    pub agent_share_pct: u8,
+    pub bond_outcome: BondOutcome,
 }
 
 #[event]
@@ -104,3 +302,35 @@ pub struct AgentProfileUpdated {
     pub reputation_score: i64,
     pub tasks_completed: u64,
 }
+
+#[event]
+pub struct ArbitratorRegistered {
+    pub arbitrator: Pubkey,
+    pub categories: u8,
+    pub stake_lamports: u64,
+    pub is_approved: bool,
+}
+
+#[event]
+pub struct ArbitratorUpdated {
+    pub arbitrator: Pubkey,
+    pub categories: u8,
+    pub is_available: bool,
+    pub is_approved: bool,
+}
+
+#[event]
+pub struct ArbitratorDeregistered {
+    pub arbitrator: Pubkey,
+    pub stake_lamports: u64,
+}
+
+#[event]
+pub struct EmergencyWithdrawal {
+    pub task: Pubkey,
+    pub creator: Pubkey,
+    pub agent: Pubkey,
+    pub caller: Pubkey,
+    pub refunded_lamports: u64,
+    pub payout_lamports: u64,
+}
@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
 
 use crate::contexts::*;
+use crate::errors::VerbittoError;
 use crate::events::*;
+use crate::state::PAUSE_AGENT_REGISTRATION;
 
 /// Register an on-chain agent profile. Required before claiming tasks.
 pub fn register_agent(ctx: Context<RegisterAgent>, skill_tags: u8) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_AGENT_REGISTRATION),
+        VerbittoError::PlatformPaused
+    );
     let authority_key = ctx.accounts.authority.key();
     let profile_key = ctx.accounts.agent_profile.key();
     let profile = &mut ctx.accounts.agent_profile;
@@ -14,6 +20,8 @@ pub fn register_agent(ctx: Context<RegisterAgent>, skill_tags: u8) -> Result<()>
     profile.tasks_disputed = 0;
     profile.disputes_won = 0;
     profile.disputes_lost = 0;
+    profile.disputes_judged = 0;
+    profile.majority_votes = 0;
     profile.total_earned_lamports = 0;
     profile.registered_at = Clock::get()?.unix_timestamp;
     profile.skill_tags = skill_tags;
@@ -29,6 +37,10 @@ pub fn register_agent(ctx: Context<RegisterAgent>, skill_tags: u8) -> Result<()>
 
 /// Update agent skill tags.
 pub fn update_agent_skills(ctx: Context<UpdateAgentSkills>, skill_tags: u8) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_AGENT_REGISTRATION),
+        VerbittoError::PlatformPaused
+    );
     let agent_key = ctx.accounts.authority.key();
     let profile = &mut ctx.accounts.agent_profile;
     profile.skill_tags = skill_tags;
@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::contexts::*;
+use crate::errors::VerbittoError;
+use crate::events::*;
+use crate::instructions::governance::authorize_admin;
+use crate::state::*;
+
+/// Create the (empty) arbitrator pool. Anyone can call: the pool has no
+/// settings, so whoever pays its rent gains nothing over anyone else.
+pub fn initialize_arbitrator_pool(ctx: Context<InitializeArbitratorPool>) -> Result<()> {
+    let pool = &mut ctx.accounts.arbitrator_pool;
+    pool.members = Vec::new();
+    pool.bump = ctx.bumps.arbitrator_pool;
+    Ok(())
+}
+
+/// Register as an arbitrator for the given `categories` (TaskCategory
+/// bitmap), locking `stake_lamports` (at least
+/// `platform.min_arbitrator_stake_lamports`) until deregistration.
+/// Requires `platform.min_voter_reputation`. Unless the platform requires
+/// approval, the arbitrator joins the jury pool straight away.
+pub fn register_arbitrator(
+    ctx: Context<RegisterArbitrator>,
+    categories: u8,
+    stake_lamports: u64,
+) -> Result<()> {
+    let platform = &ctx.accounts.platform;
+    require!(
+        !platform.is_paused(PAUSE_AGENT_REGISTRATION),
+        VerbittoError::PlatformPaused
+    );
+    require!(
+        ctx.accounts.agent_profile.reputation_score >= platform.min_voter_reputation,
+        VerbittoError::InsufficientReputation
+    );
+    require!(
+        categories != 0 && categories & !TASK_CATEGORY_MASK == 0,
+        VerbittoError::InvalidCategories
+    );
+    require!(
+        stake_lamports >= platform.min_arbitrator_stake_lamports,
+        VerbittoError::ArbitratorStakeTooLow
+    );
+
+    if stake_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.arbitrator.to_account_info(),
+                },
+            ),
+            stake_lamports,
+        )?;
+    }
+
+    let authority = ctx.accounts.authority.key();
+    let a = &mut ctx.accounts.arbitrator;
+    a.authority = authority;
+    a.categories = categories;
+    a.is_available = true;
+    a.is_approved = !platform.arbitrator_approval_required;
+    a.stake_lamports = stake_lamports;
+    a.registered_at = Clock::get()?.unix_timestamp;
+    a.active_votes = 0;
+    a.bump = ctx.bumps.arbitrator;
+
+    sync_pool(&mut ctx.accounts.arbitrator_pool, a)?;
+
+    emit!(ArbitratorRegistered {
+        arbitrator: authority,
+        categories,
+        stake_lamports,
+        is_approved: a.is_approved,
+    });
+
+    Ok(())
+}
+
+/// Change the categories an arbitrator is qualified for, or step in and out
+/// of the jury pool via `is_available`. Juries already drawn are unaffected.
+pub fn update_arbitrator(
+    ctx: Context<UpdateArbitrator>,
+    categories: u8,
+    is_available: bool,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_AGENT_REGISTRATION),
+        VerbittoError::PlatformPaused
+    );
+    require!(
+        categories != 0 && categories & !TASK_CATEGORY_MASK == 0,
+        VerbittoError::InvalidCategories
+    );
+
+    let a = &mut ctx.accounts.arbitrator;
+    a.categories = categories;
+    a.is_available = is_available;
+
+    sync_pool(&mut ctx.accounts.arbitrator_pool, a)?;
+
+    emit!(ArbitratorUpdated {
+        arbitrator: a.authority,
+        categories,
+        is_available,
+        is_approved: a.is_approved,
+    });
+
+    Ok(())
+}
+
+/// Approve or revoke an arbitrator. Only authority can call, or a governance
+/// signer executing a matching `SetArbitratorApproval` proposal.
+pub fn set_arbitrator_approval(ctx: Context<SetArbitratorApproval>, approved: bool) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.platform,
+        ctx.accounts.authority.key(),
+        ctx.accounts.governance.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        &GovernanceAction::SetArbitratorApproval {
+            arbitrator: ctx.accounts.arbitrator.authority,
+            approved,
+        },
+    )?;
+
+    let a = &mut ctx.accounts.arbitrator;
+    a.is_approved = approved;
+
+    sync_pool(&mut ctx.accounts.arbitrator_pool, a)?;
+
+    emit!(ArbitratorUpdated {
+        arbitrator: a.authority,
+        categories: a.categories,
+        is_available: a.is_available,
+        is_approved: approved,
+    });
+
+    Ok(())
+}
+
+/// Leave the registry: drop out of the jury pool and close the arbitrator
+/// account, returning its stake and rent. Only allowed once every vote the
+/// arbitrator cast has been settled or withdrawn.
+pub fn deregister_arbitrator(ctx: Context<DeregisterArbitrator>) -> Result<()> {
+    require!(
+        ctx.accounts.arbitrator.active_votes == 0,
+        VerbittoError::ArbitratorHasActiveVotes
+    );
+    let arbitrator = ctx.accounts.authority.key();
+    let pool = &mut ctx.accounts.arbitrator_pool;
+    if let Some(index) = pool.members.iter().position(|m| m.arbitrator == arbitrator) {
+        pool.members.swap_remove(index);
+    }
+
+    emit!(ArbitratorDeregistered {
+        arbitrator,
+        stake_lamports: ctx.accounts.arbitrator.stake_lamports,
+    });
+
+    Ok(())
+}
+
+/// Keep the arbitrator's pool entry in step with its registration: listed
+/// (with its current categories) while approved and available.
+fn sync_pool(pool: &mut ArbitratorPool, arbitrator: &Arbitrator) -> Result<()> {
+    let index = pool
+        .members
+        .iter()
+        .position(|m| m.arbitrator == arbitrator.authority);
+    let listed = arbitrator.is_approved && arbitrator.is_available;
+
+    match (index, listed) {
+        (Some(i), true) => pool.members[i].categories = arbitrator.categories,
+        (Some(i), false) => {
+            pool.members.swap_remove(i);
+        }
+        (None, true) => {
+            require!(
+                pool.members.len() < MAX_POOL_SIZE,
+                VerbittoError::ArbitratorPoolFull
+            );
+            pool.members.push(PoolMember {
+                arbitrator: arbitrator.authority,
+                categories: arbitrator.categories,
+            });
+        }
+        (None, false) => {}
+    }
+
+    Ok(())
+}
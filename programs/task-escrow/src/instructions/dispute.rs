@@ -1,24 +1,36 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::slot_hashes;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::contexts::*;
 use crate::errors::VerbittoError;
+use crate::escrow::{self, TokenEscrow};
 use crate::events::*;
+use crate::instructions::governance::authorize_admin;
 use crate::state::*;
 
 /// Open a dispute on a submitted or rejected task.
 /// Only the task creator or assigned agent may initiate.
+/// Tasks escalated by MAX_REJECTIONS before dispute records were opened
+/// automatically can also be brought into arbitration this way.
+///
+/// Missed deadlines can be disputed too (reason forced to DeadlineMissed):
+/// the creator may dispute a Claimed task past its current deadline, and the
+/// agent may contest the cancellation of a late submission within
+/// `platform.review_period` of it.
 pub fn open_dispute(
     ctx: Context<OpenDispute>,
     reason: DisputeReason,
     evidence_hash: [u8; 32],
 ) -> Result<()> {
-    let task_key = ctx.accounts.task.key();
-    let dispute_key = ctx.accounts.dispute.key();
-    let task = &mut ctx.accounts.task;
     require!(
-        task.status == TaskStatus::Submitted || task.status == TaskStatus::Rejected,
-        VerbittoError::TaskNotDisputable
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
     );
+    let task_key = ctx.accounts.task.key();
+    let task = &mut ctx.accounts.task;
 
     let initiator = ctx.accounts.initiator.key();
     require!(
@@ -26,40 +38,368 @@ pub fn open_dispute(
         VerbittoError::NotTaskParty
     );
 
+    // The dispute PDA's `init` guarantees no record exists yet
+    let now = Clock::get()?.unix_timestamp;
+    let reason = match task.status {
+        TaskStatus::Submitted | TaskStatus::Rejected => reason,
+        TaskStatus::Disputed => DisputeReason::RejectionLimit,
+        TaskStatus::Claimed => {
+            require!(initiator == task.creator, VerbittoError::NotTaskCreator);
+            require!(
+                now >= task.current_deadline(),
+                VerbittoError::DeadlineNotReached
+            );
+            DisputeReason::DeadlineMissed
+        }
+        TaskStatus::Cancelled => {
+            require!(initiator == task.agent, VerbittoError::NotAssignedAgent);
+            let contest_deadline = task
+                .settled_at
+                .checked_add(ctx.accounts.platform.review_period)
+                .ok_or(VerbittoError::ArithmeticOverflow)?;
+            require!(now < contest_deadline, VerbittoError::ContestWindowClosed);
+            task.settled_at = 0;
+            DisputeReason::DeadlineMissed
+        }
+        _ => return err!(VerbittoError::TaskNotDisputable),
+    };
     task.status = TaskStatus::Disputed;
 
-    let d = &mut ctx.accounts.dispute;
-    d.task = task_key;
-    d.initiator = initiator;
+    record_dispute(
+        &mut ctx.accounts.dispute,
+        task_key,
+        &ctx.accounts.initiator,
+        reason,
+        evidence_hash,
+        &ctx.accounts.platform,
+        &ctx.accounts.system_program,
+        ctx.bumps.dispute,
+    )
+}
+
+/// Initialise a freshly created dispute account, take the initiator's
+/// `platform.dispute_bond_lamports` bond into it and emit `DisputeOpened`.
+/// Shared by `open_dispute` and the MAX_REJECTIONS escalation in
+/// `reject_submission`.
+pub(crate) fn record_dispute<'info>(
+    d: &mut Account<'info, Dispute>,
+    task: Pubkey,
+    initiator: &Signer<'info>,
+    reason: DisputeReason,
+    evidence_hash: [u8; 32],
+    platform: &Platform,
+    system_program: &Program<'info, System>,
+    bump: u8,
+) -> Result<()> {
+    let bond = platform.dispute_bond_lamports;
+    if bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: initiator.to_account_info(),
+                    to: d.to_account_info(),
+                },
+            ),
+            bond,
+        )?;
+    }
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let voting_period = platform.dispute_voting_period;
+    let commit_deadline = now
+        .checked_add(voting_period)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    let reveal_deadline = commit_deadline
+        .checked_add(voting_period)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+
+    d.task = task;
+    d.initiator = initiator.key();
     d.reason = reason;
     d.evidence_hash = evidence_hash;
     d.status = DisputeStatus::Open;
+    d.legacy_votes_for_creator = 0;
+    d.legacy_votes_for_agent = 0;
+    d.legacy_votes_for_split = 0;
     d.votes_for_creator = 0;
     d.votes_for_agent = 0;
     d.votes_for_split = 0;
-    d.opened_at = Clock::get()?.unix_timestamp;
+    d.opened_at = now;
+    d.commit_deadline = commit_deadline;
+    d.reveal_deadline = reveal_deadline;
     d.resolved_at = 0;
     d.ruling = Ruling::Pending;
-    d.bump = ctx.bumps.dispute;
+    d.resolution = ResolutionPath::Pending;
+    d.votes_committed = 0;
+    d.votes_settled = 0;
+    d.reward_pool_lamports = 0;
+    d.reward_pool_tokens = 0;
+    d.slash_bps = 0;
+    d.arbitrator_rep_reward = 0;
+    d.arbitrator_rep_penalty = 0;
+    d.bond_lamports = bond;
+    d.bond_outcome = if bond > 0 {
+        BondOutcome::Pending
+    } else {
+        BondOutcome::None
+    };
+    d.round = 0;
+    d.min_votes = platform.dispute_min_votes as u16;
+    d.min_weight = platform.dispute_min_weight;
+    d.votes_revealed = 0;
+    d.appellant = Pubkey::default();
+    d.appealed_ruling = Ruling::Pending;
+    d.appeal_bond_lamports = 0;
+    d.prior_votes_for_creator = 0;
+    d.prior_votes_for_agent = 0;
+    d.prior_votes_for_split = 0;
+    d.evidence = Vec::new();
+    d.split_pct_votes = [0; SPLIT_PCT_BUCKETS];
+    d.agent_share_pct = 0;
+    d.jury_size = platform.jury_size;
+    d.jurors = Vec::new();
+    d.jury_start = 0;
+    d.draw_slot = clock
+        .slot
+        .checked_add(JURY_DRAW_DELAY_SLOTS)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    d.flagged = false;
+    d.bump = bump;
 
     emit!(DisputeOpened {
-        dispute: dispute_key,
-        task: d.task,
-        initiator,
+        dispute: d.key(),
+        task,
+        initiator: d.initiator,
         reason,
+        bond_lamports: bond,
+    });
+
+    Ok(())
+}
+
+/// Draw the current round's jury from the arbitrator pool. Anyone can crank
+/// this once per round on disputes opened with `platform.jury_size` > 0,
+/// before the commit phase ends.
+///
+/// Entropy is the hash of the first block at or after `dispute.draw_slot`,
+/// committed when the round started, mixed with the dispute key and round.
+/// The draw waits until that block exists. If it has already aged out of
+/// the SlotHashes sysvar, the draw is re-committed to a new future slot
+/// instead. Only pool members qualified for the task's category are eligible.
+/// The jury doubles with each appeal round, and excludes the task parties
+/// and anyone drawn in an earlier round; a shallow pool yields a smaller
+/// jury rather than blocking the dispute.
+pub fn draw_jury(ctx: Context<DrawJury>) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
+    let dispute_key = ctx.accounts.dispute.key();
+    let task = &ctx.accounts.task;
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(
+        dispute.status == DisputeStatus::Open,
+        VerbittoError::DisputeNotOpen
+    );
+    require!(dispute.jury_size > 0, VerbittoError::JuryNotRequired);
+    require!(
+        dispute.current_jury().is_empty(),
+        VerbittoError::JuryAlreadyDrawn
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < dispute.commit_deadline,
+        VerbittoError::VotingPeriodEnded
+    );
+
+    let slot_hash = {
+        let slot_hashes = ctx.accounts.slot_hashes.try_borrow_data()?;
+        first_hash_from(&slot_hashes, dispute.draw_slot)?
+    };
+    let Some(slot_hash) = slot_hash else {
+        dispute.draw_slot = clock
+            .slot
+            .checked_add(JURY_DRAW_DELAY_SLOTS)
+            .ok_or(VerbittoError::ArithmeticOverflow)?;
+        emit!(JuryDrawDeferred {
+            dispute: dispute_key,
+            round: dispute.round,
+            draw_slot: dispute.draw_slot,
+        });
+        return Ok(());
+    };
+    let seed = hashv(&[&slot_hash, dispute_key.as_ref(), &[dispute.round]]).to_bytes();
+
+    let category = task.category.bit();
+    let mut candidates: Vec<Pubkey> = ctx
+        .accounts
+        .arbitrator_pool
+        .members
+        .iter()
+        .filter(|m| m.categories & category != 0)
+        .map(|m| m.arbitrator)
+        .filter(|a| *a != task.creator && *a != task.agent && !dispute.jurors.contains(a))
+        .collect();
+    let wanted = (dispute.jury_size as usize)
+        .checked_shl(dispute.round as u32)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    let count = wanted
+        .min(candidates.len())
+        .min(MAX_JURORS - dispute.jurors.len());
+    require!(count > 0, VerbittoError::InsufficientJurors);
+
+    for i in 0..count {
+        let draw = hashv(&[&seed, &(i as u32).to_le_bytes()]).to_bytes();
+        let roll = u64::from_le_bytes(draw[..8].try_into().unwrap());
+        let juror = candidates.swap_remove((roll % candidates.len() as u64) as usize);
+        dispute.jurors.push(juror);
+    }
+
+    emit!(JuryDrawn {
+        dispute: dispute_key,
+        round: dispute.round,
+        jurors: dispute.current_jury().to_vec(),
+    });
+
+    Ok(())
+}
+
+/// Hash of the first block at or after `slot` in the raw SlotHashes sysvar
+/// (u64 entry count, then (slot u64, hash [u8; 32]) entries, most recent
+/// first). `None` once that block has aged out of the sysvar.
+fn first_hash_from(slot_hashes: &[u8], slot: u64) -> Result<Option<[u8; 32]>> {
+    let count = u64::from_le_bytes(slot_hashes[..8].try_into().unwrap()) as usize;
+    // A sysvar that has not filled up yet still lists every block
+    let mut complete = count < slot_hashes::MAX_ENTRIES;
+    let mut hash = None;
+    for entry in slot_hashes[8..].chunks_exact(40).take(count) {
+        let entry_slot = u64::from_le_bytes(entry[..8].try_into().unwrap());
+        if entry_slot < slot {
+            complete = true;
+            break;
+        }
+        hash = Some(entry[8..].try_into().unwrap());
+        if entry_slot == slot {
+            complete = true;
+            break;
+        }
+    }
+    require!(hash.is_some(), VerbittoError::JuryDrawTooEarly);
+    Ok(if complete { hash } else { None })
+}
+
+/// Add an evidence hash to an open dispute before the commit phase ends.
+/// Either party (creator or agent) may submit up to MAX_EVIDENCE_PER_PARTY
+/// entries, so both sides can be put on-chain for arbitrators.
+pub fn submit_evidence(ctx: Context<SubmitEvidence>, evidence_hash: [u8; 32]) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
+    let dispute_key = ctx.accounts.dispute.key();
+    let task_key = ctx.accounts.task.key();
+    let submitter = ctx.accounts.submitter.key();
+    let task = &ctx.accounts.task;
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(
+        dispute.status == DisputeStatus::Open,
+        VerbittoError::DisputeNotOpen
+    );
+    require!(
+        submitter == task.creator || submitter == task.agent,
+        VerbittoError::NotTaskParty
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now < dispute.commit_deadline,
+        VerbittoError::VotingPeriodEnded
+    );
+
+    let submitted = dispute
+        .evidence
+        .iter()
+        .filter(|e| e.submitter == submitter)
+        .count();
+    require!(
+        submitted < MAX_EVIDENCE_PER_PARTY,
+        VerbittoError::EvidenceLimitReached
+    );
+
+    dispute.evidence.push(Evidence {
+        submitter,
+        evidence_hash,
+        submitted_at: now,
+    });
+
+    emit!(EvidenceSubmitted {
+        dispute: dispute_key,
+        task: task_key,
+        submitter,
+        evidence_hash,
+        submitted_at: now,
+    });
+
+    Ok(())
+}
+
+/// Flag an open dispute for the platform authority, allowing it to rule via
+/// `admin_resolve_dispute` (e.g. when the vote is stuck or abused). Only the
+/// task creator or agent may flag, at any point before resolution.
+pub fn flag_dispute(ctx: Context<FlagDispute>) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
+    let party = ctx.accounts.party.key();
+    let task = &ctx.accounts.task;
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(
+        dispute.status == DisputeStatus::Open,
+        VerbittoError::DisputeNotOpen
+    );
+    require!(
+        party == task.creator || party == task.agent,
+        VerbittoError::NotTaskParty
+    );
+
+    dispute.flagged = true;
+
+    emit!(DisputeFlagged {
+        dispute: dispute.key(),
+        task: task.key(),
+        flagged_by: party,
     });
 
     Ok(())
 }
 
-/// Cast an arbitration vote on an open dispute.
-/// Voter must not be a party to the task (neither creator nor agent).
-pub fn cast_vote(ctx: Context<CastVote>, ruling: Ruling) -> Result<()> {
+/// Commit a sealed arbitration vote on an open dispute.
+/// `commitment` is sha256(ruling_u8 || salt || voter), hiding the ruling
+/// until the reveal phase so later voters cannot herd onto the leader.
+/// Voter must not be a party to the task (neither creator nor agent), and
+/// locks `platform.arbitrator_stake_lamports` in the vote account until
+/// `settle_vote`. Each arbitrator votes once per dispute, so appeal juries
+/// are drawn from arbitrators who have not yet sat on it. When the dispute
+/// has a jury, only the current round's drawn jurors may vote. Voters must be
+/// approved arbitrators registered for the task's category. The vote's weight
+/// is fixed from the voter's reputation now, per `platform.vote_weight_mode`.
+pub fn cast_vote(ctx: Context<CastVote>, commitment: [u8; 32]) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
     let dispute_key = ctx.accounts.dispute.key();
     let voter_key = ctx.accounts.voter.key();
     let task_creator = ctx.accounts.task.creator;
     let task_agent = ctx.accounts.task.agent;
-    let voting_period = ctx.accounts.platform.dispute_voting_period;
+    let task_category = ctx.accounts.task.category;
 
     let dispute = &mut ctx.accounts.dispute;
 
@@ -67,11 +407,12 @@ pub fn cast_vote(ctx: Context<CastVote>, ruling: Ruling) -> Result<()> {
         dispute.status == DisputeStatus::Open,
         VerbittoError::DisputeNotOpen
     );
-    require!(ruling != Ruling::Pending, VerbittoError::InvalidRuling);
 
     let now = Clock::get()?.unix_timestamp;
-    let voting_deadline = dispute.opened_at + voting_period;
-    require!(now < voting_deadline, VerbittoError::VotingPeriodEnded);
+    require!(
+        now < dispute.commit_deadline,
+        VerbittoError::VotingPeriodEnded
+    );
 
     // Voter must not be a party
     require!(
@@ -79,68 +420,268 @@ pub fn cast_vote(ctx: Context<CastVote>, ruling: Ruling) -> Result<()> {
         VerbittoError::PartyCannotVote
     );
 
+    if dispute.jury_size > 0 {
+        require!(
+            !dispute.current_jury().is_empty(),
+            VerbittoError::JuryNotDrawn
+        );
+        require!(
+            dispute.current_jury().contains(&voter_key),
+            VerbittoError::NotOnJury
+        );
+    }
+
+    let arbitrator = &ctx.accounts.arbitrator;
+    require!(arbitrator.is_approved, VerbittoError::ArbitratorNotApproved);
+    require!(
+        arbitrator.categories & task_category.bit() != 0,
+        VerbittoError::CategoryNotQualified
+    );
+
     // Voter must have sufficient reputation (sybil protection)
-    let min_rep = ctx.accounts.platform.min_voter_reputation;
+    let reputation = ctx.accounts.voter_profile.reputation_score;
     require!(
-        ctx.accounts.voter_profile.reputation_score >= min_rep,
+        reputation >= ctx.accounts.platform.min_voter_reputation,
         VerbittoError::InsufficientReputation
     );
+    let weight = ctx.accounts.platform.vote_weight(reputation);
 
-    // Tally
-    match ruling {
-        Ruling::CreatorWins => dispute.votes_for_creator += 1,
-        Ruling::AgentWins => dispute.votes_for_agent += 1,
-        Ruling::Split => dispute.votes_for_split += 1,
-        Ruling::Pending => unreachable!(),
+    // Lock the arbitrator's stake in the vote account
+    let stake = ctx.accounts.platform.arbitrator_stake_lamports;
+    if stake > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.voter.to_account_info(),
+                    to: ctx.accounts.vote.to_account_info(),
+                },
+            ),
+            stake,
+        )?;
     }
+    dispute.votes_committed += 1;
+    ctx.accounts.arbitrator.active_votes += 1;
 
-    // Record individual vote
+    // Record sealed vote; it is tallied on reveal
     let v = &mut ctx.accounts.vote;
     v.dispute = dispute_key;
     v.arbitrator = voter_key;
-    v.ruling = ruling;
+    v.commitment = commitment;
+    v.ruling = Ruling::Pending;
+    v.split_pct = 0;
+    v.stake_lamports = stake;
+    v.weight = weight;
+    v.round = dispute.round;
     v.voted_at = now;
     v.bump = ctx.bumps.vote;
 
     emit!(VoteCast {
         dispute: dispute_key,
         voter: voter_key,
-        ruling,
+        commitment,
     });
 
     Ok(())
 }
 
-/// Resolve a dispute after the voting period ends.
-/// Anyone can trigger this. Funds are distributed per the majority ruling.
-pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
+/// Replace a sealed vote's commitment during the commit phase of the round
+/// it was cast in. Nothing is tallied before reveal, so only the commitment
+/// changes.
+pub fn change_vote(ctx: Context<ChangeVote>, commitment: [u8; 32]) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
+    let dispute = &ctx.accounts.dispute;
+    let vote = &mut ctx.accounts.vote;
+    require_commit_phase(dispute, vote)?;
+
+    vote.commitment = commitment;
+    vote.voted_at = Clock::get()?.unix_timestamp;
+
+    emit!(VoteChanged {
+        dispute: dispute.key(),
+        voter: vote.arbitrator,
+        commitment,
+        withdrawn: false,
+    });
+
+    Ok(())
+}
+
+/// Withdraw a sealed vote during the commit phase of the round it was cast
+/// in. The vote account closes to the voter, returning the stake, and no
+/// longer counts towards the votes the dispute must settle.
+pub fn withdraw_vote(ctx: Context<WithdrawVote>) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
     let dispute_key = ctx.accounts.dispute.key();
-    let task_key = ctx.accounts.task.key();
     let dispute = &mut ctx.accounts.dispute;
-    let task = &mut ctx.accounts.task;
-    let platform = &mut ctx.accounts.platform;
+    let vote = &ctx.accounts.vote;
+    require_commit_phase(dispute, vote)?;
+
+    dispute.votes_committed -= 1;
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    arbitrator.active_votes = arbitrator.active_votes.saturating_sub(1);
 
+    emit!(VoteChanged {
+        dispute: dispute_key,
+        voter: vote.arbitrator,
+        commitment: [0; 32],
+        withdrawn: true,
+    });
+
+    Ok(())
+}
+
+/// A sealed vote can change only while its own round is still committing.
+fn require_commit_phase(dispute: &Dispute, vote: &ArbitratorVote) -> Result<()> {
     require!(
         dispute.status == DisputeStatus::Open,
         VerbittoError::DisputeNotOpen
     );
     require!(
-        task.status == TaskStatus::Disputed,
-        VerbittoError::TaskNotDisputed
+        vote.round == dispute.round,
+        VerbittoError::VotingPeriodEnded
+    );
+    require!(
+        Clock::get()?.unix_timestamp < dispute.commit_deadline,
+        VerbittoError::VotingPeriodEnded
+    );
+    Ok(())
+}
+
+/// Reveal a committed vote during the reveal phase and add it to the tally.
+/// Votes never revealed are excluded from the count, and votes from an
+/// earlier round can no longer be revealed once it has been appealed.
+/// A Split vote carries the agent's share (`split_pct`, a multiple of
+/// SPLIT_PCT_STEP up to 100); other rulings must pass 0.
+pub fn reveal_vote(
+    ctx: Context<RevealVote>,
+    ruling: Ruling,
+    split_pct: u8,
+    salt: [u8; 32],
+) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
+    let dispute_key = ctx.accounts.dispute.key();
+    let voter_key = ctx.accounts.voter.key();
+    let dispute = &mut ctx.accounts.dispute;
+    let vote = &mut ctx.accounts.vote;
+
+    require!(
+        dispute.status == DisputeStatus::Open,
+        VerbittoError::DisputeNotOpen
     );
+    require!(ruling != Ruling::Pending, VerbittoError::InvalidRuling);
+    let valid_pct = if ruling == Ruling::Split {
+        split_pct <= 100 && split_pct.is_multiple_of(SPLIT_PCT_STEP)
+    } else {
+        split_pct == 0
+    };
+    require!(valid_pct, VerbittoError::InvalidSplitPercentage);
+    require!(
+        vote.ruling == Ruling::Pending,
+        VerbittoError::VoteAlreadyRevealed
+    );
+    require!(vote.round == dispute.round, VerbittoError::RevealPhaseEnded);
 
     let now = Clock::get()?.unix_timestamp;
-    let voting_deadline = dispute.opened_at + platform.dispute_voting_period;
-    require!(now >= voting_deadline, VerbittoError::VotingPeriodNotEnded);
+    require!(
+        now >= dispute.commit_deadline,
+        VerbittoError::RevealPhaseNotStarted
+    );
+    require!(
+        now < dispute.reveal_deadline,
+        VerbittoError::RevealPhaseEnded
+    );
 
-    let total_votes =
-        dispute.votes_for_creator + dispute.votes_for_agent + dispute.votes_for_split;
     require!(
-        total_votes >= platform.dispute_min_votes as u16,
-        VerbittoError::InsufficientVotes
+        vote_commitment(ruling, split_pct, &salt, &voter_key) == vote.commitment,
+        VerbittoError::CommitmentMismatch
     );
 
-    // Determine ruling (strict majority; ties default to Split for fairness)
+    // Tally by weight
+    let weight = vote.weight;
+    let tally = match ruling {
+        Ruling::CreatorWins => &mut dispute.votes_for_creator,
+        Ruling::AgentWins => &mut dispute.votes_for_agent,
+        Ruling::Split => {
+            let bucket = &mut dispute.split_pct_votes[(split_pct / SPLIT_PCT_STEP) as usize];
+            *bucket = bucket
+                .checked_add(weight)
+                .ok_or(VerbittoError::ArithmeticOverflow)?;
+            &mut dispute.votes_for_split
+        }
+        Ruling::Pending => unreachable!(),
+    };
+    *tally = tally
+        .checked_add(weight)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    dispute.votes_revealed += 1;
+    vote.ruling = ruling;
+    vote.split_pct = split_pct;
+
+    emit!(VoteRevealed {
+        dispute: dispute_key,
+        voter: voter_key,
+        ruling,
+        split_pct,
+        weight,
+    });
+
+    Ok(())
+}
+
+/// sha256(ruling_u8 || split_pct_u8 || salt || voter). Binding the voter
+/// stops anyone copying another arbitrator's commitment.
+pub fn vote_commitment(ruling: Ruling, split_pct: u8, salt: &[u8; 32], voter: &Pubkey) -> [u8; 32] {
+    hashv(&[&[ruling as u8, split_pct], salt, voter.as_ref()]).to_bytes()
+}
+
+/// Outcome of the current dispute round: its ruling, how it was reached and
+/// when the round ended. Quorum needs both `min_votes` revealed votes and
+/// `min_weight` of revealed weight. Fails until the reveal phase is over and,
+/// if quorum was never reached, until `platform.dispute_fallback_period` has
+/// also passed; the round is then decided on the plurality of the weight
+/// revealed, or as a Split if no votes were.
+fn round_outcome(
+    dispute: &Dispute,
+    platform: &Platform,
+    now: i64,
+) -> Result<(Ruling, ResolutionPath, i64)> {
+    require!(
+        now >= dispute.reveal_deadline,
+        VerbittoError::VotingPeriodNotEnded
+    );
+
+    let total_votes = dispute.votes_revealed;
+    let total_weight =
+        dispute.votes_for_creator + dispute.votes_for_agent + dispute.votes_for_split;
+    let quorum = total_votes >= dispute.min_votes && total_weight >= dispute.min_weight;
+    let (resolution, ended_at) = if quorum {
+        (ResolutionPath::Quorum, dispute.reveal_deadline)
+    } else {
+        // No quorum: wait out the fallback period, then rule on what we have
+        let fallback_deadline = dispute
+            .reveal_deadline
+            .checked_add(platform.dispute_fallback_period)
+            .ok_or(VerbittoError::ArithmeticOverflow)?;
+        require!(now >= fallback_deadline, VerbittoError::InsufficientVotes);
+        if total_votes == 0 {
+            (ResolutionPath::DefaultSplit, fallback_deadline)
+        } else {
+            (ResolutionPath::Plurality, fallback_deadline)
+        }
+    };
+
+    // Determine ruling (strict majority; ties default to Split for fairness).
+    // With no votes at all every count ties at zero, giving Split.
     let ruling = if dispute.votes_for_creator > dispute.votes_for_agent
         && dispute.votes_for_creator > dispute.votes_for_split
     {
@@ -154,105 +695,533 @@ pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
         Ruling::Split
     };
 
+    Ok((ruling, resolution, ended_at))
+}
+
+/// Appeal the current round's ruling within `platform.appeal_window` of the
+/// round ending. Only a party the ruling went against may appeal (either
+/// party for a Split), posting `platform.appeal_bond_lamports` into the
+/// dispute account.
+///
+/// The dispute reopens for another round with twice the quorum (votes and
+/// weight) and twice
+/// the voting period of the last. Revealed votes carry over for settlement,
+/// but the new round is decided on its own votes. A pending appeal is judged
+/// by the next round: its bond is refunded if that round's ruling differs
+/// from the one appealed, and otherwise joins the arbitrators' reward pool.
+pub fn appeal_dispute(ctx: Context<AppealDispute>) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
+    let dispute_key = ctx.accounts.dispute.key();
+    let task_key = ctx.accounts.task.key();
+    let appellant_key = ctx.accounts.appellant.key();
+    let task = &ctx.accounts.task;
+    let platform = &ctx.accounts.platform;
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(
+        dispute.status == DisputeStatus::Open,
+        VerbittoError::DisputeNotOpen
+    );
+    require!(
+        dispute.round < platform.max_appeal_rounds,
+        VerbittoError::AppealLimitReached
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let (ruling, _, ended_at) = round_outcome(dispute, platform, now)?;
+    let appeal_deadline = ended_at
+        .checked_add(platform.appeal_window)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    require!(now < appeal_deadline, VerbittoError::AppealWindowClosed);
+
+    let allowed = match ruling {
+        Ruling::CreatorWins => appellant_key == task.agent,
+        Ruling::AgentWins => appellant_key == task.creator,
+        Ruling::Split => appellant_key == task.creator || appellant_key == task.agent,
+        Ruling::Pending => false,
+    };
+    require!(allowed, VerbittoError::CannotAppealRuling);
+
+    // Judge the previous appeal against this round's ruling
+    if dispute.appeal_bond_lamports > 0 {
+        let bond = dispute.appeal_bond_lamports;
+        if ruling != dispute.appealed_ruling {
+            let previous = if dispute.appellant == appellant_key {
+                ctx.accounts.appellant.to_account_info()
+            } else {
+                ctx.accounts.counterparty.to_account_info()
+            };
+            **dispute.to_account_info().try_borrow_mut_lamports()? -= bond;
+            **previous.try_borrow_mut_lamports()? += bond;
+        } else {
+            dispute.reward_pool_lamports = dispute
+                .reward_pool_lamports
+                .checked_add(bond)
+                .ok_or(VerbittoError::ArithmeticOverflow)?;
+        }
+        dispute.appeal_bond_lamports = 0;
+    }
+
+    let bond = platform.appeal_bond_lamports;
+    if bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.appellant.to_account_info(),
+                    to: dispute.to_account_info(),
+                },
+            ),
+            bond,
+        )?;
+    }
+
+    // Larger jury, longer vote
+    let voting_period = dispute
+        .reveal_deadline
+        .checked_sub(dispute.commit_deadline)
+        .and_then(|p| p.checked_mul(2))
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    let commit_deadline = now
+        .checked_add(voting_period)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    let reveal_deadline = commit_deadline
+        .checked_add(voting_period)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+
+    dispute.prior_votes_for_creator += dispute.votes_for_creator;
+    dispute.prior_votes_for_agent += dispute.votes_for_agent;
+    dispute.prior_votes_for_split += dispute.votes_for_split;
+    dispute.votes_for_creator = 0;
+    dispute.votes_for_agent = 0;
+    dispute.votes_for_split = 0;
+    dispute.votes_revealed = 0;
+    dispute.split_pct_votes = [0; SPLIT_PCT_BUCKETS];
+    dispute.min_votes = dispute
+        .min_votes
+        .checked_mul(2)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    dispute.min_weight = dispute
+        .min_weight
+        .checked_mul(2)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    dispute.commit_deadline = commit_deadline;
+    dispute.reveal_deadline = reveal_deadline;
+    dispute.round += 1;
+    dispute.jury_start = dispute.jurors.len() as u8;
+    dispute.draw_slot = Clock::get()?
+        .slot
+        .checked_add(JURY_DRAW_DELAY_SLOTS)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    dispute.appellant = appellant_key;
+    dispute.appealed_ruling = ruling;
+    dispute.appeal_bond_lamports = bond;
+
+    emit!(DisputeAppealed {
+        dispute: dispute_key,
+        task: task_key,
+        appellant: appellant_key,
+        appealed_ruling: ruling,
+        round: dispute.round,
+        bond_lamports: bond,
+    });
+
+    Ok(())
+}
+
+/// Resolve a dispute after its last round. Anyone can trigger this once the
+/// round is decided (see `round_outcome`) and either no appeal rounds remain
+/// or `platform.appeal_window` has lapsed unused. Funds are distributed per
+/// the final round's ruling, and a pending appeal bond is refunded if that
+/// ruling differs from the one appealed. The initiator's dispute bond is
+/// refunded unless the ruling went to the other party (Split counts as
+/// neither), in which case it is forfeited like a failed appeal bond.
+///
+/// `platform.arbitrator_reward_bps` of the fee is moved into the dispute
+/// account (or, for a token bounty, the dispute's `reward_vault`) as a reward
+/// pool for voters (across all rounds) who sided with the final ruling; the
+/// dispute stays open until each vote is settled.
+/// For milestone tasks the ruling covers the current milestone only and the
+/// unearned remainder is refunded to the creator.
+pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
+    let dispute_key = ctx.accounts.dispute.key();
+    let task_key = ctx.accounts.task.key();
+    let dispute = &ctx.accounts.dispute;
+    let platform = &ctx.accounts.platform;
+
+    require!(
+        dispute.status == DisputeStatus::Open,
+        VerbittoError::DisputeNotOpen
+    );
+    require!(
+        ctx.accounts.task.status == TaskStatus::Disputed,
+        VerbittoError::TaskNotDisputed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let (ruling, resolution, ended_at) = round_outcome(dispute, platform, now)?;
+    if dispute.round < platform.max_appeal_rounds {
+        let appeal_deadline = ended_at
+            .checked_add(platform.appeal_window)
+            .ok_or(VerbittoError::ArithmeticOverflow)?;
+        require!(now >= appeal_deadline, VerbittoError::AppealWindowOpen);
+    }
+    let total_votes = dispute.votes_revealed;
+    let agent_share_pct = match ruling {
+        Ruling::CreatorWins => 0,
+        Ruling::AgentWins => 100,
+        Ruling::Split => dispute.median_split_pct(),
+        Ruling::Pending => unreachable!(),
+    };
+
+    let bond_outcome = apply_ruling(ctx.accounts, ruling, agent_share_pct, resolution, now)?;
+
+    emit!(DisputeResolved {
+        dispute: dispute_key,
+        task: task_key,
+        ruling,
+        resolution,
+        total_votes,
+        agent_share_pct,
+        bond_outcome,
+    });
+
+    // Nothing left to settle: return the dispute rent straight away
+    if ctx.accounts.dispute.votes_committed == 0 {
+        ctx.accounts
+            .dispute
+            .close(ctx.accounts.initiator.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+/// Rule on a dispute as the platform authority (or through an approved
+/// `AdminResolveDispute` proposal), bypassing the vote. Only
+/// allowed while disputes are paused or after a task party has flagged
+/// the dispute. `split_pct` is the agent's share for a Split ruling (a
+/// multiple of SPLIT_PCT_STEP up to 100) and must be 0 otherwise.
+///
+/// Funds and bonds are distributed exactly as by `resolve_dispute`, except
+/// that the whole fee goes to the treasury: votes on an admin-resolved
+/// dispute are neither rewarded nor slashed, only refunded on settlement.
+pub fn admin_resolve_dispute(
+    ctx: Context<ResolveDispute>,
+    ruling: Ruling,
+    split_pct: u8,
+) -> Result<()> {
+    let dispute_key = ctx.accounts.dispute.key();
+    let task_key = ctx.accounts.task.key();
+    authorize_admin(
+        &ctx.accounts.platform,
+        ctx.accounts.caller.key(),
+        ctx.accounts.governance.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        &GovernanceAction::AdminResolveDispute {
+            dispute: dispute_key,
+            ruling,
+            split_pct,
+        },
+    )?;
+    let dispute = &ctx.accounts.dispute;
+    let platform = &ctx.accounts.platform;
+
+    require!(
+        dispute.status == DisputeStatus::Open,
+        VerbittoError::DisputeNotOpen
+    );
+    require!(
+        ctx.accounts.task.status == TaskStatus::Disputed,
+        VerbittoError::TaskNotDisputed
+    );
+    require!(
+        platform.is_paused(PAUSE_DISPUTES) || dispute.flagged,
+        VerbittoError::AdminResolutionNotAllowed
+    );
+    require!(ruling != Ruling::Pending, VerbittoError::InvalidRuling);
+    let agent_share_pct = match ruling {
+        Ruling::CreatorWins => 0,
+        Ruling::AgentWins => 100,
+        _ => split_pct,
+    };
+    let valid_pct = if ruling == Ruling::Split {
+        split_pct <= 100 && split_pct.is_multiple_of(SPLIT_PCT_STEP)
+    } else {
+        split_pct == 0
+    };
+    require!(valid_pct, VerbittoError::InvalidSplitPercentage);
+
+    let now = Clock::get()?.unix_timestamp;
+    let bond_outcome = apply_ruling(
+        ctx.accounts,
+        ruling,
+        agent_share_pct,
+        ResolutionPath::Admin,
+        now,
+    )?;
+
+    emit!(DisputeAdminResolved {
+        dispute: dispute_key,
+        task: task_key,
+        authority: ctx.accounts.caller.key(),
+        ruling,
+        agent_share_pct,
+        bond_outcome,
+    });
+
+    // Nothing left to settle: return the dispute rent straight away
+    if ctx.accounts.dispute.votes_committed == 0 {
+        ctx.accounts
+            .dispute
+            .close(ctx.accounts.initiator.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+/// Pay out the disputed task per `ruling` (the agent getting
+/// `agent_share_pct` of the amount after fee), judge the appeal and dispute
+/// bonds, and mark the dispute resolved. Returns what became of the
+/// initiator's bond.
+fn apply_ruling(
+    accounts: &mut ResolveDispute,
+    ruling: Ruling,
+    agent_share_pct: u8,
+    resolution: ResolutionPath,
+    now: i64,
+) -> Result<BondOutcome> {
+    let dispute = &mut accounts.dispute;
+    let task = &mut accounts.task;
+    let platform = &mut accounts.platform;
+
+    // Only the current milestone is in dispute; any later milestones are
+    // unearned and go back to the creator whatever the ruling.
+    let at_stake = task.milestone_amount();
+    let unearned = task
+        .remaining_bounty()
+        .checked_sub(at_stake)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+
     // Calculate fee
-    let fee = task
-        .bounty_lamports
-        .checked_mul(platform.fee_bps as u64)
+    let fee = at_stake
+        .checked_mul(task.fee_bps as u64)
         .ok_or(VerbittoError::ArithmeticOverflow)?
         .checked_div(10000)
         .ok_or(VerbittoError::ArithmeticOverflow)?;
+    let after_fee = at_stake
+        .checked_sub(fee)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
 
-    match ruling {
-        Ruling::CreatorWins => {
-            // Full refund to creator (no fee charged)
-            **task.to_account_info().try_borrow_mut_lamports()? -= task.bounty_lamports;
-            **ctx
-                .accounts
-                .creator
-                .to_account_info()
-                .try_borrow_mut_lamports()? += task.bounty_lamports;
-            task.status = TaskStatus::Cancelled;
+    let token = escrow::token_escrow(
+        task,
+        &accounts.bounty_mint,
+        &accounts.vault,
+        &accounts.token_program,
+    )?;
+    let creator_info = accounts.creator.to_account_info();
+    let creator_token = accounts.creator_token_account.as_ref();
+    let agent_token = accounts.agent_token_account.as_ref();
+    let treasury_token = accounts.treasury_token_account.as_ref();
+
+    // (creator, agent, treasury) shares of the disputed amount
+    let (creator_share, agent_share, fee_share) = match ruling {
+        // Full refund to creator (no fee charged)
+        Ruling::CreatorWins => (at_stake, 0, 0),
+        // Pay agent (minus fee)
+        Ruling::AgentWins => (0, after_fee, fee),
+        // Split (amount - fee) at the median voted share; creator gets the
+        // rounding remainder
+        Ruling::Split => {
+            let agent_part = after_fee
+                .checked_mul(agent_share_pct as u64)
+                .ok_or(VerbittoError::ArithmeticOverflow)?
+                / 100;
+            (after_fee - agent_part, agent_part, fee)
         }
-        Ruling::AgentWins => {
-            // Pay agent (minus fee)
-            let agent_payout = task
-                .bounty_lamports
-                .checked_sub(fee)
-                .ok_or(VerbittoError::ArithmeticOverflow)?;
-            **task.to_account_info().try_borrow_mut_lamports()? -= agent_payout;
-            **ctx
-                .accounts
-                .agent
-                .to_account_info()
-                .try_borrow_mut_lamports()? += agent_payout;
-
-            if fee > 0 {
-                **task.to_account_info().try_borrow_mut_lamports()? -= fee;
-                **ctx
-                    .accounts
-                    .treasury
-                    .to_account_info()
-                    .try_borrow_mut_lamports()? += fee;
+        Ruling::Pending => unreachable!(),
+    };
+    let creator_refund = creator_share
+        .checked_add(unearned)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+
+    escrow::release(
+        task,
+        token.as_ref(),
+        &creator_info,
+        creator_token,
+        creator_refund,
+    )?;
+    escrow::release(
+        task,
+        token.as_ref(),
+        &accounts.agent,
+        agent_token,
+        agent_share,
+    )?;
+
+    // Majority voters' cut of the fee stays with the dispute until claimed:
+    // in its lamports for a SOL bounty, in its reward vault for a token one.
+    // An admin ruling overrides the vote, so no voter is on the winning side.
+    let winners = if resolution == ResolutionPath::Admin {
+        0
+    } else {
+        dispute.votes_for(ruling)
+    };
+    let reward_cut = if winners > 0 {
+        fee_share
+            .checked_mul(platform.arbitrator_reward_bps as u64)
+            .ok_or(VerbittoError::ArithmeticOverflow)?
+            / 10000
+    } else {
+        0
+    };
+    let treasury_share = fee_share - reward_cut;
+
+    escrow::release(
+        task,
+        token.as_ref(),
+        &accounts.treasury,
+        treasury_token,
+        treasury_share,
+    )?;
+    let reward_cut = match (&token, &accounts.reward_vault) {
+        (None, _) => {
+            escrow::release(task, None, &dispute.to_account_info(), None, reward_cut)?;
+            reward_cut
+        }
+        (Some(token), Some(reward_vault)) => {
+            dispute.reward_pool_tokens = escrow::release(
+                task,
+                Some(token),
+                &dispute.to_account_info(),
+                Some(reward_vault),
+                reward_cut,
+            )?;
+            if dispute.reward_pool_tokens == 0 {
+                // Nothing to hold: close the vault straight back to the caller
+                let reward_escrow = TokenEscrow {
+                    mint: token.mint,
+                    vault: reward_vault,
+                    token_program: token.token_program,
+                };
+                close_reward_vault(
+                    dispute,
+                    &dispute.to_account_info(),
+                    &reward_escrow,
+                    None,
+                    &accounts.caller.to_account_info(),
+                )?;
             }
+            0
+        }
+        (Some(_), None) => {
+            require!(reward_cut == 0, VerbittoError::MissingTokenAccounts);
+            0
+        }
+    };
 
-            task.status = TaskStatus::Approved;
-            platform.total_settled_lamports += task.bounty_lamports;
+    // Judge a pending appeal against the final ruling
+    let mut reward_pool = dispute.reward_pool_lamports;
+    if dispute.appeal_bond_lamports > 0 {
+        let bond = dispute.appeal_bond_lamports;
+        if ruling != dispute.appealed_ruling {
+            let appellant = if dispute.appellant == task.creator {
+                creator_info.clone()
+            } else {
+                accounts.agent.clone()
+            };
+            **dispute.to_account_info().try_borrow_mut_lamports()? -= bond;
+            **appellant.try_borrow_mut_lamports()? += bond;
+        } else {
+            reward_pool = reward_pool
+                .checked_add(bond)
+                .ok_or(VerbittoError::ArithmeticOverflow)?;
         }
-        Ruling::Split => {
-            // Split (bounty - fee) 50/50
-            let after_fee = task
-                .bounty_lamports
-                .checked_sub(fee)
+        dispute.appeal_bond_lamports = 0;
+    }
+    // Judge the initiator's dispute bond
+    if dispute.bond_outcome == BondOutcome::Pending {
+        let bond = dispute.bond_lamports;
+        let lost = match ruling {
+            Ruling::CreatorWins => dispute.initiator == task.agent,
+            Ruling::AgentWins => dispute.initiator == task.creator,
+            _ => false,
+        };
+        if lost {
+            reward_pool = reward_pool
+                .checked_add(bond)
                 .ok_or(VerbittoError::ArithmeticOverflow)?;
-            let half = after_fee / 2;
-            let creator_share = after_fee - half; // creator gets ceiling
-
-            **task.to_account_info().try_borrow_mut_lamports()? -= creator_share;
-            **ctx
-                .accounts
-                .creator
-                .to_account_info()
-                .try_borrow_mut_lamports()? += creator_share;
-
-            **task.to_account_info().try_borrow_mut_lamports()? -= half;
-            **ctx
-                .accounts
-                .agent
-                .to_account_info()
-                .try_borrow_mut_lamports()? += half;
-
-            if fee > 0 {
-                **task.to_account_info().try_borrow_mut_lamports()? -= fee;
-                **ctx
-                    .accounts
-                    .treasury
-                    .to_account_info()
-                    .try_borrow_mut_lamports()? += fee;
-            }
+            dispute.bond_outcome = BondOutcome::Forfeited;
+        } else {
+            **dispute.to_account_info().try_borrow_mut_lamports()? -= bond;
+            **accounts.initiator.try_borrow_mut_lamports()? += bond;
+            dispute.bond_outcome = BondOutcome::Returned;
+        }
+    }
+    // Forfeited bonds with nobody to reward go to the treasury
+    if winners == 0 && reward_pool > 0 {
+        **dispute.to_account_info().try_borrow_mut_lamports()? -= reward_pool;
+        **accounts.treasury.try_borrow_mut_lamports()? += reward_pool;
+        reward_pool = 0;
+    }
+    reward_pool = reward_pool
+        .checked_add(reward_cut)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+
+    if let Some(token) = &token {
+        // Leftover tokens follow the refund, or the payout when there is none
+        let sweep_to = if creator_refund > 0 {
+            creator_token
+        } else {
+            agent_token
+        };
+        escrow::close_vault(task, token, sweep_to, &creator_info)?;
+    }
 
-            task.status = TaskStatus::Approved;
-            platform.total_settled_lamports += task.bounty_lamports;
+    if ruling == Ruling::CreatorWins {
+        task.status = TaskStatus::Cancelled;
+    } else {
+        task.status = TaskStatus::Approved;
+        if !task.is_token_bounty() {
+            platform.total_settled_lamports += at_stake;
         }
-        Ruling::Pending => unreachable!(),
     }
 
     dispute.ruling = ruling;
+    dispute.agent_share_pct = agent_share_pct;
+    dispute.resolution = resolution;
+    dispute.reward_pool_lamports = reward_pool;
+    if resolution == ResolutionPath::Admin {
+        // Votes were overridden: refund them without slashing or reputation
+        dispute.slash_bps = 0;
+        dispute.arbitrator_rep_reward = 0;
+        dispute.arbitrator_rep_penalty = 0;
+    } else {
+        dispute.slash_bps = platform.slash_bps;
+        dispute.arbitrator_rep_reward = platform.arbitrator_rep_reward;
+        dispute.arbitrator_rep_penalty = platform.arbitrator_rep_penalty;
+    }
     dispute.status = DisputeStatus::Resolved;
     dispute.resolved_at = now;
     task.settled_at = now;
 
     // Update agent profile
-    let profile = &mut ctx.accounts.agent_profile;
+    let profile = &mut accounts.agent_profile;
     profile.tasks_disputed += 1;
     match ruling {
         Ruling::AgentWins => {
             profile.disputes_won += 1;
             profile.reputation_score += task.reputation_reward;
-            let earned = task
-                .bounty_lamports
-                .checked_sub(fee)
-                .ok_or(VerbittoError::ArithmeticOverflow)?;
-            profile.total_earned_lamports += earned;
+            if !task.is_token_bounty() {
+                profile.total_earned_lamports += agent_share;
+            }
         }
         Ruling::CreatorWins => {
             profile.disputes_lost += 1;
@@ -261,20 +1230,348 @@ pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
                 .saturating_sub(task.reputation_reward / 2);
         }
         Ruling::Split => {
-            let after_fee = task
-                .bounty_lamports
-                .checked_sub(fee)
-                .ok_or(VerbittoError::ArithmeticOverflow)?;
-            profile.total_earned_lamports += after_fee / 2;
+            if !task.is_token_bounty() {
+                profile.total_earned_lamports += agent_share;
+            }
         }
         Ruling::Pending => unreachable!(),
     }
 
-    emit!(DisputeResolved {
+    Ok(dispute.bond_outcome)
+}
+
+/// Settle one arbitrator's vote on a resolved dispute. Anyone can call this.
+///
+/// Voters who sided with the ruling get their stake back plus a share of the
+/// dispute's reward pool proportional to their vote weight; on a token
+/// bounty the fee share is paid from the reward vault to their token
+/// account. Minority and unrevealed votes forfeit `dispute.slash_bps` of
+/// their stake to the treasury. The vote account is closed to the
+/// arbitrator, and the dispute (with its reward vault) is closed to its
+/// initiator once the last vote is settled.
+///
+/// The arbitrator's profile records the dispute as judged, and its
+/// reputation rises by `dispute.arbitrator_rep_reward` for a majority vote
+/// or falls by `dispute.arbitrator_rep_penalty` otherwise.
+pub fn settle_vote(ctx: Context<SettleVote>) -> Result<()> {
+    let dispute_info = ctx.accounts.dispute.to_account_info();
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(
+        dispute.status == DisputeStatus::Resolved,
+        VerbittoError::DisputeNotResolved
+    );
+
+    let rewards = reward_escrow(
+        dispute,
+        &ctx.accounts.bounty_mint,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.token_program,
+    )?;
+    settle(
+        dispute,
+        &dispute_info,
+        &ctx.accounts.vote,
+        &ctx.accounts.arbitrator,
+        &mut ctx.accounts.arbitrator_profile,
+        &mut ctx.accounts.arbitrator_registration,
+        &ctx.accounts.treasury,
+        rewards.as_ref(),
+        ctx.accounts.arbitrator_token_account.as_ref(),
+    )?;
+
+    // Last vote settled: dispute rent (and any rounding dust) to the initiator
+    if dispute.votes_settled >= dispute.votes_committed {
+        let initiator = ctx.accounts.initiator.to_account_info();
+        if let Some(rewards) = &rewards {
+            close_reward_vault(
+                dispute,
+                &dispute_info,
+                rewards,
+                ctx.accounts.treasury_token_account.as_ref(),
+                &initiator,
+            )?;
+        }
+        dispute.close(initiator)?;
+    }
+
+    Ok(())
+}
+
+/// Close a batch of votes on one dispute. Anyone can call this.
+///
+/// `remaining_accounts` holds a (vote, arbitrator, arbitrator profile,
+/// arbitrator registration) group per vote, plus the arbitrator's token
+/// account for the bounty mint when the dispute holds a token reward pool.
+/// While the resolved dispute is still open, each vote is settled exactly as
+/// by `settle_vote`. Once the dispute account no longer exists, each vote's
+/// stake and rent go straight back to its arbitrator and the profile and
+/// registration are not used. Such orphaned votes may predate the current
+/// `ArbitratorVote` layout, so they are checked and closed from their raw
+/// data, relying only on the `dispute` and `arbitrator` fields both layouts
+/// start with.
+pub fn close_votes<'info>(ctx: Context<'_, '_, 'info, 'info, CloseVotes<'info>>) -> Result<()> {
+    let dispute_info = &ctx.accounts.dispute;
+    let dispute_key = dispute_info.key();
+    let batch = ctx.remaining_accounts;
+
+    // Live disputes are read and written back by hand: `Account` would
+    // need the dispute to outlive this context.
+    let mut dispute = if dispute_info.owner == &crate::ID && !dispute_info.data_is_empty() {
+        let dispute = Dispute::try_deserialize(&mut &dispute_info.try_borrow_data()?[..])?;
+        require!(
+            dispute.status == DisputeStatus::Resolved,
+            VerbittoError::DisputeNotResolved
+        );
+        require!(
+            ctx.accounts.initiator.key() == dispute.initiator,
+            VerbittoError::NotTaskParty
+        );
+        Some(dispute)
+    } else {
+        None
+    };
+    let rewards = match &dispute {
+        Some(dispute) => reward_escrow(
+            dispute,
+            &ctx.accounts.bounty_mint,
+            &ctx.accounts.reward_vault,
+            &ctx.accounts.token_program,
+        )?,
+        None => None,
+    };
+    let group = if rewards.is_some() { 5 } else { 4 };
+    require!(
+        !batch.is_empty() && batch.len().is_multiple_of(group),
+        VerbittoError::InvalidVoteBatch
+    );
+
+    for accounts in batch.chunks(group) {
+        let arbitrator = &accounts[1];
+        let Some(dispute) = dispute.as_mut() else {
+            close_orphaned_vote(&accounts[0], arbitrator, dispute_key)?;
+            continue;
+        };
+
+        let vote = Account::<ArbitratorVote>::try_from(&accounts[0])?;
+        require!(
+            arbitrator.key() == vote.arbitrator,
+            VerbittoError::InvalidVoteAccount
+        );
+        let mut profile = Account::<AgentProfile>::try_from(&accounts[2])?;
+        let mut registration = Account::<Arbitrator>::try_from(&accounts[3])?;
+        require!(
+            profile.authority == vote.arbitrator && registration.authority == vote.arbitrator,
+            VerbittoError::InvalidVoteAccount
+        );
+        let arbitrator_token = match accounts.get(4) {
+            Some(info) => Some(InterfaceAccount::<TokenAccount>::try_from(info)?),
+            None => None,
+        };
+        settle(
+            dispute,
+            dispute_info,
+            &vote,
+            arbitrator,
+            &mut profile,
+            &mut registration,
+            &ctx.accounts.treasury,
+            rewards.as_ref(),
+            arbitrator_token.as_ref(),
+        )?;
+        profile.exit(&crate::ID)?;
+        registration.exit(&crate::ID)?;
+        vote.close(arbitrator.clone())?;
+    }
+
+    if let Some(dispute) = dispute {
+        if dispute.votes_settled >= dispute.votes_committed {
+            // Last vote settled: dispute rent (and any rounding dust) to the initiator
+            let initiator = &ctx.accounts.initiator;
+            if let Some(rewards) = &rewards {
+                close_reward_vault(
+                    &dispute,
+                    dispute_info,
+                    rewards,
+                    ctx.accounts.treasury_token_account.as_ref(),
+                    initiator,
+                )?;
+            }
+            **initiator.try_borrow_mut_lamports()? += dispute_info.lamports();
+            **dispute_info.try_borrow_mut_lamports()? = 0;
+            dispute_info.assign(&system_program::ID);
+            dispute_info.resize(0)?;
+        } else {
+            dispute.try_serialize(&mut &mut dispute_info.try_borrow_mut_data()?[..])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The reward vault holding `dispute`'s token reward pool, from a context's
+/// optional accounts. `None` when the dispute has no token rewards; errors
+/// if it has and any account is missing.
+fn reward_escrow<'a, 'info>(
+    dispute: &Dispute,
+    mint: &'a Option<InterfaceAccount<'info, Mint>>,
+    vault: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+    token_program: &'a Option<Interface<'info, TokenInterface>>,
+) -> Result<Option<TokenEscrow<'a, 'info>>> {
+    if dispute.reward_pool_tokens == 0 {
+        return Ok(None);
+    }
+    match (mint, vault, token_program) {
+        (Some(mint), Some(vault), Some(token_program)) => Ok(Some(TokenEscrow {
+            mint,
+            vault,
+            token_program,
+        })),
+        _ => err!(VerbittoError::MissingTokenAccounts),
+    }
+}
+
+/// Close the reward vault once every vote is settled: rounding dust goes to
+/// the treasury's token account and the rent to `rent_to`.
+fn close_reward_vault<'info>(
+    dispute: &Dispute,
+    dispute_info: &AccountInfo<'info>,
+    rewards: &TokenEscrow<'_, 'info>,
+    treasury_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+    rent_to: &AccountInfo<'info>,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"dispute", dispute.task.as_ref(), &[dispute.bump]];
+    escrow::close_token_account(rewards, dispute_info, seeds, treasury_token, rent_to)
+}
+
+/// Refund a vote whose dispute no longer exists: all its lamports go to
+/// `arbitrator` and the account is closed. Reads only the discriminator and
+/// the leading `dispute` and `arbitrator` fields, so legacy votes qualify.
+fn close_orphaned_vote<'info>(
+    vote: &AccountInfo<'info>,
+    arbitrator: &AccountInfo<'info>,
+    dispute_key: Pubkey,
+) -> Result<()> {
+    {
+        let data = vote.try_borrow_data()?;
+        require!(
+            vote.owner == &crate::ID
+                && data.len() >= 72
+                && data[..8] == *ArbitratorVote::DISCRIMINATOR,
+            VerbittoError::InvalidVoteAccount
+        );
+        require!(
+            data[8..40] == dispute_key.to_bytes(),
+            VerbittoError::DisputeTaskMismatch
+        );
+        require!(
+            data[40..72] == arbitrator.key().to_bytes(),
+            VerbittoError::InvalidVoteAccount
+        );
+    }
+
+    let refund_lamports = vote.lamports();
+    **arbitrator.try_borrow_mut_lamports()? += refund_lamports;
+    **vote.try_borrow_mut_lamports()? = 0;
+    vote.assign(&system_program::ID);
+    vote.resize(0)?;
+
+    emit!(VoteClosed {
         dispute: dispute_key,
-        task: task_key,
-        ruling,
-        total_votes,
+        voter: arbitrator.key(),
+        refund_lamports,
+    });
+
+    Ok(())
+}
+
+/// Pay out one vote on a resolved dispute (reward, or slash to `treasury`)
+/// and apply its reputation effect. The caller closes the vote account.
+fn settle<'info>(
+    dispute: &mut Dispute,
+    dispute_info: &AccountInfo<'info>,
+    vote: &Account<'info, ArbitratorVote>,
+    arbitrator: &AccountInfo<'info>,
+    profile: &mut AgentProfile,
+    registration: &mut Arbitrator,
+    treasury: &AccountInfo<'info>,
+    rewards: Option<&TokenEscrow<'_, 'info>>,
+    arbitrator_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+) -> Result<()> {
+    let dispute_key = dispute_info.key();
+    require!(
+        vote.dispute == dispute_key,
+        VerbittoError::DisputeTaskMismatch
+    );
+
+    let majority = vote.ruling == dispute.ruling;
+    let (reward, reward_tokens, slashed) = if majority {
+        let winners = dispute.votes_for(dispute.ruling).max(1) as u128;
+        let reward = dispute.reward_pool_lamports as u128 * vote.weight as u128 / winners;
+        let reward_tokens = dispute.reward_pool_tokens as u128 * vote.weight as u128 / winners;
+        (reward as u64, reward_tokens as u64, 0)
+    } else {
+        let slashed = vote
+            .stake_lamports
+            .checked_mul(dispute.slash_bps as u64)
+            .ok_or(VerbittoError::ArithmeticOverflow)?
+            / 10000;
+        (0, 0, slashed)
+    };
+
+    // Slash from the vote account; the rest (stake + rent) goes back to the
+    // arbitrator when the vote account closes.
+    if slashed > 0 {
+        **vote.to_account_info().try_borrow_mut_lamports()? -= slashed;
+        **treasury.try_borrow_mut_lamports()? += slashed;
+    }
+    if reward > 0 {
+        **dispute_info.try_borrow_mut_lamports()? -= reward;
+        **arbitrator.try_borrow_mut_lamports()? += reward;
+    }
+    if reward_tokens > 0 {
+        let rewards = rewards.ok_or(VerbittoError::MissingTokenAccounts)?;
+        let arbitrator_token = arbitrator_token.ok_or(VerbittoError::MissingTokenAccounts)?;
+        require!(
+            arbitrator_token.mint == rewards.mint.key()
+                && arbitrator_token.owner == arbitrator.key(),
+            VerbittoError::InvalidTokenAccount
+        );
+        let seeds: &[&[u8]] = &[b"dispute", dispute.task.as_ref(), &[dispute.bump]];
+        escrow::transfer_out(
+            rewards,
+            dispute_info,
+            seeds,
+            arbitrator_token,
+            reward_tokens,
+        )?;
+    }
+
+    dispute.votes_settled += 1;
+    registration.active_votes = registration.active_votes.saturating_sub(1);
+
+    // Schelling-point reputation: reward agreeing with the final ruling
+    let reputation_delta = if majority {
+        dispute.arbitrator_rep_reward
+    } else {
+        -dispute.arbitrator_rep_penalty
+    };
+    if dispute.resolution != ResolutionPath::Admin {
+        profile.disputes_judged += 1;
+        if majority {
+            profile.majority_votes += 1;
+        }
+    }
+    profile.reputation_score = profile.reputation_score.saturating_add(reputation_delta);
+
+    emit!(VoteSettled {
+        dispute: dispute_key,
+        voter: vote.arbitrator,
+        majority,
+        reward_lamports: reward,
+        reward_tokens,
+        slashed_lamports: slashed,
+        reputation_delta,
     });
 
     Ok(())
@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+
+use crate::contexts::*;
+use crate::errors::VerbittoError;
+use crate::events::*;
+use crate::instructions::platform::{
+    cancel_transfer, merge_update, pause, propose, queue_config, queued_or_current, resume,
+    validate_config, validate_pause_flags,
+};
+use crate::state::*;
+
+/// Set up M-of-N governance: `threshold` of the distinct `signers` must
+/// approve a proposal before it is executed. Only authority can call.
+///
+/// The governance PDA becomes the platform authority, so from here on every
+/// admin instruction goes through a proposal; the previous authority keeps
+/// no special rights.
+pub fn initialize_governance(
+    ctx: Context<InitializeGovernance>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    validate_signers(&signers, threshold)?;
+
+    let g = &mut ctx.accounts.governance;
+    g.signers = signers.clone();
+    g.threshold = threshold;
+    g.proposal_count = 0;
+    g.bump = ctx.bumps.governance;
+
+    let platform = &mut ctx.accounts.platform;
+    let previous_authority = platform.authority;
+    platform.authority = g.key();
+    platform.pending_authority = Pubkey::default();
+
+    emit!(GovernanceInitialized { signers, threshold });
+    emit!(AuthorityTransferred {
+        previous_authority,
+        new_authority: platform.authority,
+    });
+
+    Ok(())
+}
+
+/// Propose a platform change. Only governance signers can call; the
+/// proposer's approval is recorded straight away.
+pub fn create_proposal(ctx: Context<CreateProposal>, action: GovernanceAction) -> Result<()> {
+    let proposer = ctx.accounts.proposer.key();
+    let g = &mut ctx.accounts.governance;
+    require!(
+        g.signers.contains(&proposer),
+        VerbittoError::NotGovernanceSigner
+    );
+    match &action {
+        // Checked against today's configuration here, and again on execution
+        GovernanceAction::UpdatePlatform(update) => {
+            let (config, treasury) = merge_update(&ctx.accounts.platform, update);
+            validate_config(&config)?;
+            require!(treasury != Pubkey::default(), VerbittoError::InvalidConfig);
+        }
+        GovernanceAction::SetTreasury(treasury) => {
+            require!(*treasury != Pubkey::default(), VerbittoError::InvalidConfig)
+        }
+        GovernanceAction::PausePlatform(flags) | GovernanceAction::ResumePlatform(flags) => {
+            validate_pause_flags(*flags)?
+        }
+        GovernanceAction::SetSigners { signers, threshold } => {
+            validate_signers(signers, *threshold)?
+        }
+        GovernanceAction::AdminResolveDispute { ruling, .. } => {
+            require!(*ruling != Ruling::Pending, VerbittoError::InvalidRuling)
+        }
+        GovernanceAction::ProposeAuthority(new_authority) => {
+            require!(
+                *new_authority != Pubkey::default(),
+                VerbittoError::InvalidConfig
+            )
+        }
+        GovernanceAction::SetArbitratorApproval { .. }
+        | GovernanceAction::CancelAuthorityTransfer => {}
+    }
+
+    let proposal_index = g.proposal_count;
+    g.proposal_count += 1;
+
+    let p = &mut ctx.accounts.proposal;
+    p.proposal_index = proposal_index;
+    p.proposer = proposer;
+    p.action = action;
+    p.approvals = vec![proposer];
+    p.status = ProposalStatus::Active;
+    p.created_at = Clock::get()?.unix_timestamp;
+    p.closed_at = 0;
+    p.bump = ctx.bumps.proposal;
+
+    emit!(ProposalCreated {
+        proposal: p.key(),
+        proposal_index,
+        proposer,
+    });
+
+    Ok(())
+}
+
+/// Approve an active proposal. Each governance signer approves once.
+pub fn approve_proposal(ctx: Context<ReviewProposal>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    require!(
+        ctx.accounts.governance.signers.contains(&signer),
+        VerbittoError::NotGovernanceSigner
+    );
+
+    let p = &mut ctx.accounts.proposal;
+    require!(
+        p.status == ProposalStatus::Active,
+        VerbittoError::ProposalNotActive
+    );
+    require!(
+        !p.approvals.contains(&signer),
+        VerbittoError::ProposalAlreadyApproved
+    );
+    p.approvals.push(signer);
+
+    emit!(ProposalApproved {
+        proposal: p.key(),
+        signer,
+        approvals: p.approvals.len() as u8,
+    });
+
+    Ok(())
+}
+
+/// Withdraw an active proposal. Only its proposer can call.
+pub fn cancel_proposal(ctx: Context<ReviewProposal>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let p = &mut ctx.accounts.proposal;
+    require!(
+        p.status == ProposalStatus::Active,
+        VerbittoError::ProposalNotActive
+    );
+    require!(p.proposer == signer, VerbittoError::NotProposer);
+
+    p.status = ProposalStatus::Cancelled;
+    p.closed_at = Clock::get()?.unix_timestamp;
+
+    emit!(ProposalCancelled {
+        proposal: p.key(),
+        cancelled_by: signer,
+    });
+
+    Ok(())
+}
+
+/// Apply a proposal once `governance.threshold` signers have approved it.
+/// Any governance signer can execute it, as long as governance still holds
+/// the platform authority. Dispute rulings and arbitrator
+/// approvals are executed by their own instructions instead, which take the
+/// proposal as an account (see `authorize_admin`).
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let executor = ctx.accounts.executor.key();
+    let g = &mut ctx.accounts.governance;
+    let platform = &mut ctx.accounts.platform;
+    require_keys_eq!(
+        platform.authority,
+        g.key(),
+        VerbittoError::NotPlatformAuthority
+    );
+    let p = &mut ctx.accounts.proposal;
+    mark_executed(g, p, executor)?;
+
+    match p.action.clone() {
+        GovernanceAction::UpdatePlatform(update) => {
+            let (config, treasury) = merge_update(platform, &update);
+            queue_config(platform, &config, treasury)?;
+        }
+        GovernanceAction::PausePlatform(flags) => pause(platform, flags, executor)?,
+        GovernanceAction::ResumePlatform(flags) => resume(platform, flags, executor)?,
+        GovernanceAction::SetTreasury(treasury) => {
+            let (config, _) = queued_or_current(platform);
+            queue_config(platform, &config, treasury)?;
+        }
+        GovernanceAction::SetSigners { signers, threshold } => {
+            g.signers = signers.clone();
+            g.threshold = threshold;
+
+            emit!(GovernanceSignersUpdated { signers, threshold });
+        }
+        GovernanceAction::ProposeAuthority(new_authority) => propose(platform, new_authority)?,
+        GovernanceAction::CancelAuthorityTransfer => cancel_transfer(platform)?,
+        GovernanceAction::AdminResolveDispute { .. }
+        | GovernanceAction::SetArbitratorApproval { .. } => {
+            return err!(VerbittoError::WrongProposalAction)
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that `caller` may run an admin instruction performing `action`:
+/// either it is the platform authority, or governance holds the authority
+/// and `proposal` is an approved proposal for exactly this action, which is
+/// then marked executed.
+pub(crate) fn authorize_admin(
+    platform: &Platform,
+    caller: Pubkey,
+    governance: Option<&Account<Governance>>,
+    proposal: Option<&mut Account<GovernanceProposal>>,
+    action: &GovernanceAction,
+) -> Result<()> {
+    if caller == platform.authority {
+        return Ok(());
+    }
+    let (Some(g), Some(p)) = (governance, proposal) else {
+        return err!(VerbittoError::NotPlatformAuthority);
+    };
+    require_keys_eq!(
+        g.key(),
+        platform.authority,
+        VerbittoError::NotPlatformAuthority
+    );
+    require!(p.action == *action, VerbittoError::WrongProposalAction);
+
+    mark_executed(g, p, caller)
+}
+
+/// Close an approved proposal as executed by `executor`. Only approvals
+/// from current signers count towards the threshold, so a proposal cannot
+/// outlive a change of signers on stale approvals.
+fn mark_executed(
+    g: &Governance,
+    p: &mut Account<GovernanceProposal>,
+    executor: Pubkey,
+) -> Result<()> {
+    require!(
+        g.signers.contains(&executor),
+        VerbittoError::NotGovernanceSigner
+    );
+    require!(
+        p.status == ProposalStatus::Active,
+        VerbittoError::ProposalNotActive
+    );
+    let approvals = p.approvals.iter().filter(|a| g.signers.contains(a)).count();
+    require!(
+        approvals >= g.threshold as usize,
+        VerbittoError::ThresholdNotMet
+    );
+
+    p.status = ProposalStatus::Executed;
+    p.closed_at = Clock::get()?.unix_timestamp;
+
+    emit!(ProposalExecuted {
+        proposal: p.key(),
+        executor,
+    });
+
+    Ok(())
+}
+
+/// Require 1..=MAX_GOVERNANCE_SIGNERS distinct, non-default signers and a
+/// threshold they can reach.
+fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_GOVERNANCE_SIGNERS,
+        VerbittoError::InvalidGovernanceConfig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        VerbittoError::InvalidGovernanceConfig
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(
+            *signer != Pubkey::default() && !signers[..i].contains(signer),
+            VerbittoError::InvalidGovernanceConfig
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+
+use crate::contexts::*;
+use crate::errors::VerbittoError;
+use crate::instructions::platform::apply_config;
+use crate::state::*;
+
+// ============================================================
+// Account migration — Platform, Task, AgentProfile and Dispute accounts
+// created by the first deployment keep their original fields as a
+// prefix of the current layout. Migrating grows them to the current
+// size; the appended bytes start zeroed, which is the right default for
+// most new fields, and the rest are set below.
+// ============================================================
+
+/// Upgrade the platform account to the current layout and give it the
+/// settings the first deployment didn't have. Only authority can call; it
+/// pays the extra rent. A paused platform comes back with every class
+/// paused.
+pub fn migrate_platform(ctx: Context<MigratePlatform>, config: PlatformConfig) -> Result<()> {
+    let info = ctx.accounts.platform.to_account_info();
+    let space = 8 + Platform::INIT_SPACE;
+    check_legacy(&info, Platform::DISCRIMINATOR, space)?;
+
+    // The authority is the first field in either layout
+    let authority = Pubkey::try_from(&info.try_borrow_data()?[8..40]).unwrap();
+    require_keys_eq!(
+        authority,
+        ctx.accounts.authority.key(),
+        VerbittoError::NotPlatformAuthority
+    );
+
+    grow(
+        &info,
+        space,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    let mut p = Platform::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    apply_config(&mut p, &config)?;
+    p.pending_config = config;
+    // `pause_flags` occupies the old `is_paused` flag
+    if p.pause_flags != 0 {
+        p.pause_flags = PAUSE_ALL;
+        p.settlement_paused_at = Clock::get()?.unix_timestamp;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    p.try_serialize(&mut &mut data[..])
+}
+
+/// Upgrade a task to the current layout. Anyone can call; the payer covers
+/// the extra rent. The task keeps the platform's current fee, and a
+/// Submitted task gets a full review period from now.
+pub fn migrate_task(ctx: Context<MigrateTask>) -> Result<()> {
+    let info = ctx.accounts.task.to_account_info();
+    let space = 8 + Task::INIT_SPACE;
+    check_legacy(&info, Task::DISCRIMINATOR, space)?;
+    grow(
+        &info,
+        space,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    let mut task = Task::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    task.category = TaskCategory::Other;
+    task.fee_bps = ctx.accounts.platform.fee_bps;
+    if task.status == TaskStatus::Submitted {
+        task.submitted_at = Clock::get()?.unix_timestamp;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    task.try_serialize(&mut &mut data[..])
+}
+
+/// Upgrade an agent profile to the current layout. Anyone can call; the
+/// payer covers the extra rent. The appended arbitration stats start at 0.
+pub fn migrate_agent_profile(ctx: Context<MigrateAgentProfile>) -> Result<()> {
+    let info = ctx.accounts.agent_profile.to_account_info();
+    let space = 8 + AgentProfile::INIT_SPACE;
+    check_legacy(&info, AgentProfile::DISCRIMINATOR, space)?;
+    grow(
+        &info,
+        space,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Upgrade a dispute to the current layout. Anyone can call; the payer
+/// covers the extra rent. An open dispute keeps the votes already cast as
+/// revealed weight 1 each in its first round, whose commit phase ends where
+/// the old voting period did; the reveal phase follows it. Those legacy
+/// votes carry no stake, so they earn no reward; their accounts are refunded
+/// by `close_votes` once the dispute closes.
+pub fn migrate_dispute(ctx: Context<MigrateDispute>) -> Result<()> {
+    let info = ctx.accounts.dispute.to_account_info();
+    let space = 8 + Dispute::INIT_SPACE;
+    check_legacy(&info, Dispute::DISCRIMINATOR, space)?;
+    grow(
+        &info,
+        space,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    let platform = &ctx.accounts.platform;
+    let mut d = Dispute::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    d.votes_for_creator = d.legacy_votes_for_creator as u64;
+    d.votes_for_agent = d.legacy_votes_for_agent as u64;
+    d.votes_for_split = d.legacy_votes_for_split as u64;
+    d.votes_revealed =
+        d.legacy_votes_for_creator + d.legacy_votes_for_agent + d.legacy_votes_for_split;
+    // Legacy splits were always 50/50
+    d.split_pct_votes[(50 / SPLIT_PCT_STEP) as usize] = d.legacy_votes_for_split as u64;
+    d.commit_deadline = d
+        .opened_at
+        .checked_add(platform.dispute_voting_period)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    d.reveal_deadline = d
+        .commit_deadline
+        .checked_add(platform.dispute_voting_period)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    d.resolution = ResolutionPath::Pending;
+    d.bond_outcome = BondOutcome::None;
+    d.min_votes = platform.dispute_min_votes as u16;
+    d.min_weight = platform.dispute_min_weight;
+    d.appealed_ruling = Ruling::Pending;
+
+    let mut data = info.try_borrow_mut_data()?;
+    d.try_serialize(&mut &mut data[..])
+}
+
+/// Require `info` to be one of this program's accounts of the given type,
+/// still smaller than the current `space`.
+fn check_legacy(info: &AccountInfo, discriminator: &[u8], space: usize) -> Result<()> {
+    require_keys_eq!(*info.owner, crate::ID, VerbittoError::InvalidLegacyAccount);
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 + 32 && data[..8] == *discriminator,
+        VerbittoError::InvalidLegacyAccount
+    );
+    require!(data.len() < space, VerbittoError::AccountAlreadyMigrated);
+    Ok(())
+}
+
+/// Resize `info` to `space`, topping its rent up from `payer`.
+fn grow<'info>(
+    info: &AccountInfo<'info>,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    let old_len = info.data_len();
+    info.resize(space)?;
+    info.try_borrow_mut_data()?[old_len..].fill(0);
+    Ok(())
+}
@@ -1,11 +1,17 @@
 pub mod agent;
+pub mod arbitrator;
 pub mod dispute;
+pub mod governance;
+pub mod migration;
 pub mod platform;
 pub mod task;
 pub mod template;
 
 pub use agent::*;
+pub use arbitrator::*;
 pub use dispute::*;
+pub use governance::*;
+pub use migration::*;
 pub use platform::*;
 pub use task::*;
 pub use template::*;
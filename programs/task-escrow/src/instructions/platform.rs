@@ -3,88 +3,348 @@ use anchor_lang::prelude::*;
 use crate::contexts::*;
 use crate::errors::VerbittoError;
 use crate::events::*;
+use crate::state::*;
 
-/// Initialize the global platform configuration.
-pub fn initialize_platform(
-    ctx: Context<InitializePlatform>,
-    fee_bps: u16,
-    min_bounty_lamports: u64,
-    dispute_voting_period: i64,
-    dispute_min_votes: u8,
-    min_voter_reputation: i64,
-    claim_grace_period: i64,
-) -> Result<()> {
-    require!(fee_bps <= 3001, VerbittoError::InvalidFee);
-    require!(dispute_voting_period > 0, VerbittoError::InvalidConfig);
-    require!(dispute_min_votes > 0, VerbittoError::InvalidConfig);
-    require!(min_voter_reputation >= 0, VerbittoError::InvalidConfig);
-    require!(claim_grace_period >= 0, VerbittoError::InvalidConfig);
-
+/// Initialize the global platform configuration. `config` is validated as
+/// by `update_platform`.
+pub fn initialize_platform(ctx: Context<InitializePlatform>, config: PlatformConfig) -> Result<()> {
     let p = &mut ctx.accounts.platform;
+    apply_config(p, &config)?;
     p.authority = ctx.accounts.authority.key();
-    p.fee_bps = fee_bps;
-    p.min_bounty_lamports = min_bounty_lamports;
     p.treasury = ctx.accounts.treasury.key();
     p.task_count = 0;
     p.template_count = 0;
     p.total_settled_lamports = 0;
-    p.dispute_voting_period = dispute_voting_period;
-    p.dispute_min_votes = dispute_min_votes;
-    p.min_voter_reputation = min_voter_reputation;
-    p.claim_grace_period = claim_grace_period;
-    p.is_paused = false;
+    p.pending_authority = Pubkey::default();
+    p.pending_config = config;
+    p.pending_treasury = Pubkey::default();
+    p.config_effective_at = 0;
+    p.pause_flags = 0;
+    p.settlement_paused_at = 0;
     p.bump = ctx.bumps.platform;
 
     emit!(PlatformInitialized {
         authority: p.authority,
-        fee_bps,
+        fee_bps: config.fee_bps,
         treasury: p.treasury,
     });
 
     Ok(())
 }
 
-/// Pause the platform. Only authority can call.
-pub fn pause_platform(ctx: Context<PlatformAdmin>) -> Result<()> {
-    let p = &mut ctx.accounts.platform;
-    require!(!p.is_paused, VerbittoError::PlatformAlreadyPaused);
-    p.is_paused = true;
+/// Pause the instruction classes in `flags` (`PAUSE_*` bits). Only authority
+/// can call.
+pub fn pause_platform(ctx: Context<PlatformAdmin>, flags: u8) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    pause(&mut ctx.accounts.platform, flags, authority)
+}
+
+/// Resume the instruction classes in `flags`. Only authority can call.
+pub fn resume_platform(ctx: Context<PlatformAdmin>, flags: u8) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    resume(&mut ctx.accounts.platform, flags, authority)
+}
+
+/// Set `flags` in `p.pause_flags`; at least one of them must be new.
+pub(crate) fn pause(p: &mut Platform, flags: u8, paused_by: Pubkey) -> Result<()> {
+    validate_pause_flags(flags)?;
+    require!(
+        p.pause_flags & flags != flags,
+        VerbittoError::PlatformAlreadyPaused
+    );
+    if flags & PAUSE_SETTLEMENT != 0 && !p.is_paused(PAUSE_SETTLEMENT) {
+        p.settlement_paused_at = Clock::get()?.unix_timestamp;
+    }
+    p.pause_flags |= flags;
+
+    emit!(PlatformPaused {
+        paused_by,
+        flags,
+        pause_flags: p.pause_flags,
+    });
+
+    Ok(())
+}
+
+/// Clear `flags` from `p.pause_flags`; at least one of them must be set.
+pub(crate) fn resume(p: &mut Platform, flags: u8, resumed_by: Pubkey) -> Result<()> {
+    validate_pause_flags(flags)?;
+    require!(p.is_paused(flags), VerbittoError::PlatformNotPaused);
+    p.pause_flags &= !flags;
+    if !p.is_paused(PAUSE_SETTLEMENT) {
+        p.settlement_paused_at = 0;
+    }
+
+    emit!(PlatformResumed {
+        resumed_by,
+        flags,
+        pause_flags: p.pause_flags,
+    });
+
+    Ok(())
+}
+
+/// Reject an empty mask or bits outside `PAUSE_ALL`.
+pub(crate) fn validate_pause_flags(flags: u8) -> Result<()> {
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        VerbittoError::InvalidPauseFlags
+    );
     Ok(())
 }
 
-/// Resume the platform. Only authority can call.
-pub fn resume_platform(ctx: Context<PlatformAdmin>) -> Result<()> {
+/// Queue a platform configuration change. Only authority can call. Fields
+/// left as `None` keep their value (the queued one, if a change is already
+/// pending, which this update replaces). The change takes effect
+/// `config_timelock` seconds from now, once someone calls
+/// `apply_platform_config`.
+pub fn update_platform(ctx: Context<PlatformAdmin>, update: PlatformConfigUpdate) -> Result<()> {
     let p = &mut ctx.accounts.platform;
-    require!(p.is_paused, VerbittoError::PlatformNotPaused);
-    p.is_paused = false;
+    let (config, treasury) = merge_update(p, &update);
+    queue_config(p, &config, treasury)
+}
+
+/// Apply the queued configuration once its timelock has passed. Anyone can
+/// call.
+pub fn apply_platform_config(ctx: Context<ApplyPlatformConfig>) -> Result<()> {
+    let p = &mut ctx.accounts.platform;
+    require!(p.config_effective_at != 0, VerbittoError::NoPendingConfig);
+    require!(
+        Clock::get()?.unix_timestamp >= p.config_effective_at,
+        VerbittoError::ConfigTimelockActive
+    );
+
+    let old_config = current_config(p);
+    let old_treasury = p.treasury;
+    let config = p.pending_config;
+    apply_config(p, &config)?;
+    p.treasury = p.pending_treasury;
+    p.pending_treasury = Pubkey::default();
+    p.config_effective_at = 0;
+
+    emit!(PlatformUpdated {
+        old_config,
+        new_config: config,
+        old_treasury,
+        new_treasury: p.treasury,
+    });
+
     Ok(())
 }
 
-/// Update platform configuration. Only authority can call.
-pub fn update_platform(
-    ctx: Context<PlatformAdmin>,
-    fee_bps: u16,
-    min_bounty_lamports: u64,
-    dispute_voting_period: i64,
-    dispute_min_votes: u8,
-    min_voter_reputation: i64,
-    claim_grace_period: i64,
-    treasury: Pubkey,
-) -> Result<()> {
-    require!(fee_bps <= 3001, VerbittoError::InvalidFee);
-    require!(dispute_voting_period > 0, VerbittoError::InvalidConfig);
-    require!(dispute_min_votes > 0, VerbittoError::InvalidConfig);
-    require!(min_voter_reputation >= 0, VerbittoError::InvalidConfig);
-    require!(claim_grace_period >= 0, VerbittoError::InvalidConfig);
+/// Propose `new_authority` as the next platform authority. Only authority can
+/// call. Nothing changes until the new key signs `accept_authority`; a new
+/// proposal replaces any pending one.
+pub fn propose_authority(ctx: Context<PlatformAdmin>, new_authority: Pubkey) -> Result<()> {
+    propose(&mut ctx.accounts.platform, new_authority)
+}
+
+/// Record `new_authority` as `p`'s pending authority.
+pub(crate) fn propose(p: &mut Platform, new_authority: Pubkey) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default(),
+        VerbittoError::InvalidConfig
+    );
+
+    p.pending_authority = new_authority;
+
+    emit!(AuthorityTransferProposed {
+        authority: p.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
 
+/// Complete a proposed transfer. Only the pending authority can call.
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
     let p = &mut ctx.accounts.platform;
-    p.fee_bps = fee_bps;
-    p.min_bounty_lamports = min_bounty_lamports;
-    p.dispute_voting_period = dispute_voting_period;
-    p.dispute_min_votes = dispute_min_votes;
-    p.min_voter_reputation = min_voter_reputation;
-    p.claim_grace_period = claim_grace_period;
-    p.treasury = treasury;
+    let previous = p.authority;
+    p.authority = ctx.accounts.new_authority.key();
+    p.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferred {
+        previous_authority: previous,
+        new_authority: p.authority,
+    });
+
+    Ok(())
+}
+
+/// Withdraw a pending transfer proposal. Only authority can call.
+pub fn cancel_authority_transfer(ctx: Context<PlatformAdmin>) -> Result<()> {
+    cancel_transfer(&mut ctx.accounts.platform)
+}
+
+/// Clear `p`'s pending authority; there must be one.
+pub(crate) fn cancel_transfer(p: &mut Platform) -> Result<()> {
+    require!(
+        p.pending_authority != Pubkey::default(),
+        VerbittoError::NoPendingAuthority
+    );
+    let cancelled = p.pending_authority;
+    p.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferCancelled {
+        authority: p.authority,
+        pending_authority: cancelled,
+    });
+
+    Ok(())
+}
+
+/// Validate `c` and queue it, with `treasury`, behind the current timelock.
+pub(crate) fn queue_config(p: &mut Platform, c: &PlatformConfig, treasury: Pubkey) -> Result<()> {
+    validate_config(c)?;
+    require!(treasury != Pubkey::default(), VerbittoError::InvalidConfig);
+
+    let effective_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(p.config_timelock)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    p.pending_config = *c;
+    p.pending_treasury = treasury;
+    p.config_effective_at = effective_at;
+
+    emit!(PlatformConfigQueued {
+        old_config: current_config(p),
+        new_config: *c,
+        old_treasury: p.treasury,
+        new_treasury: treasury,
+        effective_at,
+    });
+
+    Ok(())
+}
+
+/// The configuration and treasury a new change should start from: the queued
+/// ones if a change is pending, otherwise the live ones.
+pub(crate) fn queued_or_current(p: &Platform) -> (PlatformConfig, Pubkey) {
+    if p.config_effective_at != 0 {
+        (p.pending_config, p.pending_treasury)
+    } else {
+        (current_config(p), p.treasury)
+    }
+}
+
+/// The configuration and treasury `update` would queue: the patch applied on
+/// top of `queued_or_current`.
+pub(crate) fn merge_update(
+    p: &Platform,
+    update: &PlatformConfigUpdate,
+) -> (PlatformConfig, Pubkey) {
+    let (mut config, treasury) = queued_or_current(p);
+    update.apply_to(&mut config);
+    (config, update.treasury.unwrap_or(treasury))
+}
+
+/// The configuration the platform is running with.
+pub(crate) fn current_config(p: &Platform) -> PlatformConfig {
+    PlatformConfig {
+        fee_bps: p.fee_bps,
+        min_bounty_lamports: p.min_bounty_lamports,
+        dispute_voting_period: p.dispute_voting_period,
+        dispute_min_votes: p.dispute_min_votes,
+        min_voter_reputation: p.min_voter_reputation,
+        claim_grace_period: p.claim_grace_period,
+        review_period: p.review_period,
+        dispute_fallback_period: p.dispute_fallback_period,
+        arbitrator_stake_lamports: p.arbitrator_stake_lamports,
+        arbitrator_reward_bps: p.arbitrator_reward_bps,
+        slash_bps: p.slash_bps,
+        appeal_window: p.appeal_window,
+        appeal_bond_lamports: p.appeal_bond_lamports,
+        max_appeal_rounds: p.max_appeal_rounds,
+        vote_weight_mode: p.vote_weight_mode,
+        vote_weight_cap: p.vote_weight_cap,
+        dispute_min_weight: p.dispute_min_weight,
+        jury_size: p.jury_size,
+        min_arbitrator_stake_lamports: p.min_arbitrator_stake_lamports,
+        arbitrator_approval_required: p.arbitrator_approval_required,
+        arbitrator_rep_reward: p.arbitrator_rep_reward,
+        arbitrator_rep_penalty: p.arbitrator_rep_penalty,
+        dispute_bond_lamports: p.dispute_bond_lamports,
+        config_timelock: p.config_timelock,
+        emergency_withdraw_delay: p.emergency_withdraw_delay,
+        emergency_agent_share_bps: p.emergency_agent_share_bps,
+    }
+}
+
+/// Validate `c` and write it into the platform.
+pub(crate) fn apply_config(p: &mut Platform, c: &PlatformConfig) -> Result<()> {
+    validate_config(c)?;
+
+    p.fee_bps = c.fee_bps;
+    p.min_bounty_lamports = c.min_bounty_lamports;
+    p.dispute_voting_period = c.dispute_voting_period;
+    p.dispute_min_votes = c.dispute_min_votes;
+    p.min_voter_reputation = c.min_voter_reputation;
+    p.claim_grace_period = c.claim_grace_period;
+    p.review_period = c.review_period;
+    p.dispute_fallback_period = c.dispute_fallback_period;
+    p.arbitrator_stake_lamports = c.arbitrator_stake_lamports;
+    p.arbitrator_reward_bps = c.arbitrator_reward_bps;
+    p.slash_bps = c.slash_bps;
+    p.appeal_window = c.appeal_window;
+    p.appeal_bond_lamports = c.appeal_bond_lamports;
+    p.max_appeal_rounds = c.max_appeal_rounds;
+    p.vote_weight_mode = c.vote_weight_mode;
+    p.vote_weight_cap = c.vote_weight_cap;
+    p.dispute_min_weight = c.dispute_min_weight;
+    p.jury_size = c.jury_size;
+    p.min_arbitrator_stake_lamports = c.min_arbitrator_stake_lamports;
+    p.arbitrator_approval_required = c.arbitrator_approval_required;
+    p.arbitrator_rep_reward = c.arbitrator_rep_reward;
+    p.arbitrator_rep_penalty = c.arbitrator_rep_penalty;
+    p.dispute_bond_lamports = c.dispute_bond_lamports;
+    p.config_timelock = c.config_timelock;
+    p.emergency_withdraw_delay = c.emergency_withdraw_delay;
+    p.emergency_agent_share_bps = c.emergency_agent_share_bps;
+
+    Ok(())
+}
+
+/// Reject platform settings the program cannot run with.
+pub(crate) fn validate_config(c: &PlatformConfig) -> Result<()> {
+    require!(c.fee_bps <= 3001, VerbittoError::InvalidFee);
+    require!(c.dispute_voting_period > 0, VerbittoError::InvalidConfig);
+    require!(c.dispute_min_votes > 0, VerbittoError::InvalidConfig);
+    require!(c.min_voter_reputation >= 0, VerbittoError::InvalidConfig);
+    require!(c.claim_grace_period >= 0, VerbittoError::InvalidConfig);
+    require!(c.review_period > 0, VerbittoError::InvalidConfig);
+    require!(c.dispute_fallback_period >= 0, VerbittoError::InvalidConfig);
+    require!(
+        c.arbitrator_reward_bps <= 10000,
+        VerbittoError::InvalidConfig
+    );
+    require!(c.slash_bps <= 10000, VerbittoError::InvalidConfig);
+    require!(c.appeal_window >= 0, VerbittoError::InvalidConfig);
+    require!(c.config_timelock >= 0, VerbittoError::InvalidConfig);
+    require!(c.emergency_withdraw_delay > 0, VerbittoError::InvalidConfig);
+    require!(
+        c.emergency_agent_share_bps <= 10000,
+        VerbittoError::InvalidConfig
+    );
+    require!(
+        c.arbitrator_rep_reward >= 0 && c.arbitrator_rep_penalty >= 0,
+        VerbittoError::InvalidConfig
+    );
+    require!(
+        c.vote_weight_mode != VoteWeightMode::Capped || c.vote_weight_cap > 0,
+        VerbittoError::InvalidConfig
+    );
+    // Each appeal round doubles the jury, and every round's jurors share the
+    // dispute's MAX_JURORS seats
+    let mut jury = c.jury_size as usize;
+    let mut seats = 0usize;
+    for _ in 0..=c.max_appeal_rounds {
+        seats = seats.saturating_add(jury);
+        jury = jury.saturating_mul(2);
+    }
+    require!(
+        c.jury_size == 0 || (c.jury_size >= c.dispute_min_votes && seats <= MAX_JURORS),
+        VerbittoError::InvalidConfig
+    );
 
     Ok(())
 }
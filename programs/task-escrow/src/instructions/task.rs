@@ -1,12 +1,94 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::contexts::*;
 use crate::errors::VerbittoError;
+use crate::escrow;
 use crate::events::*;
-use crate::state::TaskStatus;
+use crate::instructions::dispute::record_dispute;
+use crate::state::{
+    AgentProfile, DisputeReason, Milestone, Platform, Task, TaskCategory, TaskStatus,
+    MAX_MILESTONES, PAUSE_CLAIMING, PAUSE_DISPUTES, PAUSE_SETTLEMENT, PAUSE_TASK_CREATION,
+};
+
+/// Escrow a new task's bounty: lamports into the Task PDA, or tokens into
+/// the task vault when a bounty mint is supplied.
+/// Returns the bounty mint (default for SOL) and the amount escrowed.
+fn escrow_bounty<'info>(
+    creator: &Signer<'info>,
+    task: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    vault: Option<&mut InterfaceAccount<'info, TokenAccount>>,
+    creator_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    amount: u64,
+    min_bounty_lamports: u64,
+) -> Result<(Pubkey, u64)> {
+    let Some(mint) = mint else {
+        require!(amount >= min_bounty_lamports, VerbittoError::BountyTooLow);
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: creator.to_account_info(),
+                    to: task.clone(),
+                },
+            ),
+            amount,
+        )?;
+        return Ok((Pubkey::default(), amount));
+    };
+
+    let (Some(vault), Some(from), Some(token_program)) =
+        (vault, creator_token_account, token_program)
+    else {
+        return err!(VerbittoError::MissingTokenAccounts);
+    };
+    let received = escrow::deposit(mint, vault, from, creator, token_program, amount)?;
+    require!(received > 0, VerbittoError::BountyTooLow);
+    Ok((mint.key(), received))
+}
+
+/// Validate a milestone schedule: 1–MAX_MILESTONES positive amounts summing
+/// to the bounty, with any deadlines strictly increasing and within the
+/// task deadline.
+fn validate_milestones(
+    milestones: &[Milestone],
+    bounty_lamports: u64,
+    now: i64,
+    deadline: i64,
+) -> Result<()> {
+    require!(
+        !milestones.is_empty() && milestones.len() <= MAX_MILESTONES,
+        VerbittoError::InvalidMilestones
+    );
 
-/// Create a new task with SOL bounty escrowed in the Task PDA.
+    let mut total: u64 = 0;
+    let mut prev_deadline = now;
+    for m in milestones {
+        require!(m.amount > 0, VerbittoError::InvalidMilestones);
+        total = total
+            .checked_add(m.amount)
+            .ok_or(VerbittoError::ArithmeticOverflow)?;
+        if m.deadline > 0 {
+            require!(
+                m.deadline > prev_deadline && m.deadline <= deadline,
+                VerbittoError::InvalidMilestones
+            );
+            prev_deadline = m.deadline;
+        }
+    }
+    require!(
+        total == bounty_lamports,
+        VerbittoError::MilestoneSumMismatch
+    );
+    Ok(())
+}
+
+/// Create a new task with a SOL bounty escrowed in the Task PDA, or an
+/// SPL / Token-2022 bounty escrowed in the task vault.
 pub fn create_task(
     ctx: Context<CreateTask>,
     title: String,
@@ -15,32 +97,80 @@ pub fn create_task(
     task_index: u64,
     deadline: i64,
     reputation_reward: i64,
+) -> Result<()> {
+    init_task(
+        ctx,
+        title,
+        description_hash,
+        bounty_lamports,
+        task_index,
+        deadline,
+        reputation_reward,
+        Vec::new(),
+    )
+}
+
+/// Create a task whose bounty is released milestone by milestone.
+/// `bounty_lamports` must equal the sum of the milestone amounts.
+pub fn create_milestone_task(
+    ctx: Context<CreateTask>,
+    title: String,
+    description_hash: [u8; 32],
+    bounty_lamports: u64,
+    task_index: u64,
+    deadline: i64,
+    reputation_reward: i64,
+    milestones: Vec<Milestone>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    validate_milestones(&milestones, bounty_lamports, now, deadline)?;
+    init_task(
+        ctx,
+        title,
+        description_hash,
+        bounty_lamports,
+        task_index,
+        deadline,
+        reputation_reward,
+        milestones,
+    )
+}
+
+fn init_task(
+    ctx: Context<CreateTask>,
+    title: String,
+    description_hash: [u8; 32],
+    bounty_lamports: u64,
+    task_index: u64,
+    deadline: i64,
+    reputation_reward: i64,
+    milestones: Vec<Milestone>,
 ) -> Result<()> {
     let platform = &mut ctx.accounts.platform;
-    require!(!platform.is_paused, VerbittoError::PlatformPaused);
     require!(
-        bounty_lamports >= platform.min_bounty_lamports,
-        VerbittoError::BountyTooLow
+        !platform.is_paused(PAUSE_TASK_CREATION),
+        VerbittoError::PlatformPaused
     );
     require!(title.len() <= 64, VerbittoError::TitleTooLong);
 
     let now = Clock::get()?.unix_timestamp;
     require!(deadline > now, VerbittoError::DeadlineInPast);
     require!(
-        reputation_reward >= 0 && reputation_reward <= 1000,
+        (0..=1000).contains(&reputation_reward),
         VerbittoError::InvalidRepReward
     );
 
-    // Escrow: transfer bounty from creator to task PDA
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.creator.to_account_info(),
-                to: ctx.accounts.task.to_account_info(),
-            },
-        ),
+    // Escrow: transfer bounty from creator to task PDA (or task vault)
+    let (bounty_mint, bounty_lamports) = escrow_bounty(
+        &ctx.accounts.creator,
+        &ctx.accounts.task.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.accounts.bounty_mint.as_ref(),
+        ctx.accounts.vault.as_mut(),
+        ctx.accounts.creator_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
         bounty_lamports,
+        platform.min_bounty_lamports,
     )?;
 
     // Use per-creator counter (validated via PDA seed match)
@@ -55,10 +185,12 @@ pub fn create_task(
 
     let task_key = ctx.accounts.task.key();
     let creator_key = ctx.accounts.creator.key();
+    let milestones_len = milestones.len() as u8;
     let task = &mut ctx.accounts.task;
     task.creator = creator_key;
     task.task_index = task_index;
     task.bounty_lamports = bounty_lamports;
+    task.bounty_mint = bounty_mint;
     task.status = TaskStatus::Open;
     task.agent = Pubkey::default();
     task.deadline = deadline;
@@ -68,8 +200,14 @@ pub fn create_task(
     task.title = title;
     task.description_hash = description_hash;
     task.deliverable_hash = [0u8; 32];
+    task.submitted_at = 0;
     task.template_index = 0;
+    task.category = TaskCategory::Other;
     task.rejection_count = 0;
+    task.milestones = milestones;
+    task.current_milestone = 0;
+    task.released_lamports = 0;
+    task.fee_bps = platform.fee_bps;
     task.bump = ctx.bumps.task;
 
     emit!(TaskCreated {
@@ -77,7 +215,9 @@ pub fn create_task(
         creator: creator_key,
         task_index,
         bounty_lamports,
+        bounty_mint,
         deadline,
+        milestone_count: milestones_len,
     });
 
     Ok(())
@@ -94,35 +234,37 @@ pub fn create_task_from_template(
 ) -> Result<()> {
     let platform = &mut ctx.accounts.platform;
     let template = &mut ctx.accounts.template;
-    require!(!platform.is_paused, VerbittoError::PlatformPaused);
+    require!(
+        !platform.is_paused(PAUSE_TASK_CREATION),
+        VerbittoError::PlatformPaused
+    );
 
-    let bounty = if bounty_lamports > 0 {
+    // The template default is denominated in lamports, so token bounties
+    // must always state their amount explicitly.
+    let bounty = if bounty_lamports > 0 || ctx.accounts.bounty_mint.is_some() {
         bounty_lamports
     } else {
         template.default_bounty_lamports
     };
-    require!(
-        bounty >= platform.min_bounty_lamports,
-        VerbittoError::BountyTooLow
-    );
 
     let now = Clock::get()?.unix_timestamp;
     require!(deadline > now, VerbittoError::DeadlineInPast);
     require!(
-        reputation_reward >= 0 && reputation_reward <= 1000,
+        (0..=1000).contains(&reputation_reward),
         VerbittoError::InvalidRepReward
     );
 
     // Escrow
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.creator.to_account_info(),
-                to: ctx.accounts.task.to_account_info(),
-            },
-        ),
+    let (bounty_mint, bounty) = escrow_bounty(
+        &ctx.accounts.creator,
+        &ctx.accounts.task.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.accounts.bounty_mint.as_ref(),
+        ctx.accounts.vault.as_mut(),
+        ctx.accounts.creator_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
         bounty,
+        platform.min_bounty_lamports,
     )?;
 
     // Use per-creator counter (validated via PDA seed match)
@@ -142,6 +284,7 @@ pub fn create_task_from_template(
     task.creator = creator_key;
     task.task_index = task_index;
     task.bounty_lamports = bounty;
+    task.bounty_mint = bounty_mint;
     task.status = TaskStatus::Open;
     task.agent = Pubkey::default();
     task.deadline = deadline;
@@ -151,8 +294,14 @@ pub fn create_task_from_template(
     task.title = template.title.clone();
     task.description_hash = description_hash;
     task.deliverable_hash = [0u8; 32];
+    task.submitted_at = 0;
     task.template_index = template.template_index + 1; // 1-indexed, 0 = no template
+    task.category = template.category;
     task.rejection_count = 0;
+    task.milestones = Vec::new();
+    task.current_milestone = 0;
+    task.released_lamports = 0;
+    task.fee_bps = platform.fee_bps;
     task.bump = ctx.bumps.task;
 
     emit!(TaskCreated {
@@ -160,7 +309,9 @@ pub fn create_task_from_template(
         creator: creator_key,
         task_index,
         bounty_lamports: bounty,
+        bounty_mint,
         deadline,
+        milestone_count: 0,
     });
 
     Ok(())
@@ -172,7 +323,7 @@ pub fn claim_task(ctx: Context<ClaimTask>) -> Result<()> {
     let agent_key = ctx.accounts.agent.key();
     let task = &mut ctx.accounts.task;
     require!(
-        !ctx.accounts.platform.is_paused,
+        !ctx.accounts.platform.is_paused(PAUSE_CLAIMING),
         VerbittoError::PlatformPaused
     );
     require!(task.status == TaskStatus::Open, VerbittoError::TaskNotOpen);
@@ -202,7 +353,7 @@ pub fn submit_deliverable(
     let agent_key = ctx.accounts.agent.key();
     let task = &mut ctx.accounts.task;
     require!(
-        !ctx.accounts.platform.is_paused,
+        !ctx.accounts.platform.is_paused(PAUSE_CLAIMING),
         VerbittoError::PlatformPaused
     );
     require!(
@@ -212,11 +363,13 @@ pub fn submit_deliverable(
     require!(task.agent == agent_key, VerbittoError::NotAssignedAgent);
 
     task.deliverable_hash = deliverable_hash;
+    task.submitted_at = Clock::get()?.unix_timestamp;
     task.status = TaskStatus::Submitted;
 
     emit!(DeliverableSubmitted {
         task: task_key,
         agent: agent_key,
+        milestone_index: task.current_milestone,
         deliverable_hash,
     });
 
@@ -225,77 +378,188 @@ pub fn submit_deliverable(
 
 /// Creator approves the submission and settles the escrow.
 ///
-/// SOL flow:  Task PDA → Agent (bounty - fee) + Treasury (fee)
+/// SOL flow:    Task PDA → Agent (amount - fee) + Treasury (fee)
+/// Token flow:  Vault    → Agent (amount - fee) + Treasury (fee)
+///
+/// For milestone tasks only the current milestone's share is released and
+/// the agent moves on to the next milestone; the task (and vault) is closed
+/// once the final milestone is approved.
 pub fn approve_and_settle(ctx: Context<ApproveAndSettle>) -> Result<()> {
-    let task_key = ctx.accounts.task.key();
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_SETTLEMENT),
+        VerbittoError::PlatformPaused
+    );
     let creator_key = ctx.accounts.creator.key();
-    let task = &mut ctx.accounts.task;
-    let platform = &mut ctx.accounts.platform;
-
+    let task = &ctx.accounts.task;
     require!(
         task.status == TaskStatus::Submitted,
         VerbittoError::TaskNotSubmitted
     );
     require!(task.creator == creator_key, VerbittoError::NotTaskCreator);
 
-    // Calculate fee and payout
-    let fee = task
-        .bounty_lamports
-        .checked_mul(platform.fee_bps as u64)
+    let token = escrow::token_escrow(
+        task,
+        &ctx.accounts.bounty_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    settle_submission(
+        &mut ctx.accounts.task,
+        &mut ctx.accounts.platform,
+        &mut ctx.accounts.agent_profile,
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.agent,
+        &ctx.accounts.treasury,
+        token.as_ref(),
+        ctx.accounts.agent_token_account.as_ref(),
+        ctx.accounts.treasury_token_account.as_ref(),
+    )
+}
+
+/// Approve a submission the creator has left unreviewed. Anyone can call
+/// this once `platform.review_period` seconds have passed since the
+/// deliverable was submitted; settlement is identical to `approve_and_settle`.
+pub fn auto_approve_task(ctx: Context<AutoApproveTask>) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_SETTLEMENT),
+        VerbittoError::PlatformPaused
+    );
+    let task = &ctx.accounts.task;
+    require!(
+        task.status == TaskStatus::Submitted,
+        VerbittoError::TaskNotSubmitted
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let review_ends = task
+        .submitted_at
+        .checked_add(ctx.accounts.platform.review_period)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    require!(now >= review_ends, VerbittoError::ReviewPeriodNotEnded);
+
+    let token = escrow::token_escrow(
+        task,
+        &ctx.accounts.bounty_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    settle_submission(
+        &mut ctx.accounts.task,
+        &mut ctx.accounts.platform,
+        &mut ctx.accounts.agent_profile,
+        &ctx.accounts.creator,
+        &ctx.accounts.agent,
+        &ctx.accounts.treasury,
+        token.as_ref(),
+        ctx.accounts.agent_token_account.as_ref(),
+        ctx.accounts.treasury_token_account.as_ref(),
+    )
+}
+
+/// Release the current milestone (or the whole bounty) of a submitted task
+/// to the agent, taking the platform fee. Closes the task and vault after
+/// the final milestone, returning rent to the creator.
+fn settle_submission<'info>(
+    task: &mut Account<'info, Task>,
+    platform: &mut Account<'info, Platform>,
+    profile: &mut Account<'info, AgentProfile>,
+    creator: &AccountInfo<'info>,
+    agent: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    token: Option<&escrow::TokenEscrow<'_, 'info>>,
+    agent_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    treasury_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+) -> Result<()> {
+    let task_key = task.key();
+
+    // Calculate fee and payout for the current milestone
+    let amount = task.milestone_amount();
+    let fee = amount
+        .checked_mul(task.fee_bps as u64)
         .ok_or(VerbittoError::ArithmeticOverflow)?
         .checked_div(10000)
         .ok_or(VerbittoError::ArithmeticOverflow)?;
-    let agent_payout = task
-        .bounty_lamports
+    let agent_payout = amount
         .checked_sub(fee)
         .ok_or(VerbittoError::ArithmeticOverflow)?;
 
     // Transfer payout to agent
-    **task.to_account_info().try_borrow_mut_lamports()? -= agent_payout;
-    **ctx
-        .accounts
-        .agent
-        .to_account_info()
-        .try_borrow_mut_lamports()? += agent_payout;
+    let agent_received = escrow::release(task, token, agent, agent_token_account, agent_payout)?;
 
     // Transfer fee to treasury
-    if fee > 0 {
-        **task.to_account_info().try_borrow_mut_lamports()? -= fee;
-        **ctx
-            .accounts
-            .treasury
-            .to_account_info()
-            .try_borrow_mut_lamports()? += fee;
+    let fee_received = escrow::release(task, token, treasury, treasury_token_account, fee)?;
+
+    task.released_lamports = task
+        .released_lamports
+        .checked_add(amount)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+
+    // Lamport-denominated stats only track SOL bounties
+    if !task.is_token_bounty() {
+        platform.total_settled_lamports += amount;
+        profile.total_earned_lamports += agent_payout;
+    }
+
+    let milestone_index = task.current_milestone;
+    if !task.milestones.is_empty() {
+        emit!(MilestoneSettled {
+            task: task_key,
+            agent: task.agent,
+            milestone_index,
+            payout_lamports: agent_received,
+            fee_lamports: fee_received,
+        });
+    }
+
+    if !task.is_final_milestone() {
+        // Agent continues with the next milestone
+        task.current_milestone += 1;
+        task.rejection_count = 0;
+        task.deliverable_hash = [0u8; 32];
+        task.submitted_at = 0;
+        task.status = TaskStatus::Claimed;
+        return Ok(());
+    }
+
+    if let Some(token) = token {
+        escrow::close_vault(task, token, agent_token_account, creator)?;
     }
 
     task.status = TaskStatus::Approved;
     task.settled_at = Clock::get()?.unix_timestamp;
-    platform.total_settled_lamports += task.bounty_lamports;
 
     // Update agent profile
-    let profile = &mut ctx.accounts.agent_profile;
     profile.tasks_completed += 1;
     profile.reputation_score += task.reputation_reward;
-    profile.total_earned_lamports += agent_payout;
 
     emit!(TaskSettled {
         task: task_key,
         agent: task.agent,
-        payout_lamports: agent_payout,
-        fee_lamports: fee,
+        bounty_mint: task.bounty_mint,
+        payout_lamports: agent_received,
+        fee_lamports: fee_received,
     });
 
-    Ok(())
+    // Rent returned to creator; closed manually since milestone tasks
+    // stay open between approvals.
+    task.close(creator.clone())
 }
 
 /// Maximum number of rejections before a task is auto-disputed.
 const MAX_REJECTIONS: u8 = 3;
 
-/// Creator rejects a submitted deliverable.
-/// Agent can resubmit or open a dispute.
+/// Creator rejects a submitted deliverable (the current milestone's, for
+/// milestone tasks). Agent can resubmit or open a dispute.
 /// After MAX_REJECTIONS (3), the task automatically enters Disputed status
-/// to prevent indefinite rejection loops.
+/// to prevent indefinite rejection loops; that rejection must supply the
+/// dispute PDA, which is opened with `DisputeReason::RejectionLimit` (the
+/// creator posting the dispute bond) so the task flows straight into
+/// arbitration. That rejection is also blocked while disputes are paused.
 pub fn reject_submission(ctx: Context<RejectSubmission>, reason_hash: [u8; 32]) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_SETTLEMENT),
+        VerbittoError::PlatformPaused
+    );
     let task_key = ctx.accounts.task.key();
     let creator_key = ctx.accounts.creator.key();
     let task = &mut ctx.accounts.task;
@@ -306,8 +570,18 @@ pub fn reject_submission(ctx: Context<RejectSubmission>, reason_hash: [u8; 32])
     require!(task.creator == creator_key, VerbittoError::NotTaskCreator);
 
     task.rejection_count += 1;
+    let escalate = task.rejection_count >= MAX_REJECTIONS;
+    require!(
+        ctx.accounts.dispute.is_some() == escalate,
+        VerbittoError::DisputeAccountMismatch
+    );
+    // The escalating rejection opens a dispute, so it is a dispute action too
+    require!(
+        !escalate || !ctx.accounts.platform.is_paused(PAUSE_DISPUTES),
+        VerbittoError::PlatformPaused
+    );
 
-    if task.rejection_count >= MAX_REJECTIONS {
+    if escalate {
         // Auto-escalate to disputed after too many rejections
         task.status = TaskStatus::Disputed;
     } else {
@@ -317,75 +591,269 @@ pub fn reject_submission(ctx: Context<RejectSubmission>, reason_hash: [u8; 32])
     emit!(SubmissionRejected {
         task: task_key,
         agent: task.agent,
+        milestone_index: task.current_milestone,
         reason_hash,
     });
 
+    if let Some(dispute) = ctx.accounts.dispute.as_mut() {
+        let bump = ctx
+            .bumps
+            .dispute
+            .ok_or(VerbittoError::DisputeAccountMismatch)?;
+        record_dispute(
+            dispute,
+            task_key,
+            &ctx.accounts.creator,
+            DisputeReason::RejectionLimit,
+            reason_hash,
+            &ctx.accounts.platform,
+            &ctx.accounts.system_program,
+            bump,
+        )?;
+    }
+
     Ok(())
 }
 
-/// Cancel an open (unclaimed) task and refund the escrowed SOL.
-/// The task PDA is closed and all lamports (bounty + rent) are returned to the creator.
-/// Historical record is preserved via the `TaskCancelled` event.
+/// Cancel a task and refund the escrowed bounty. Only the creator can call.
+///
+/// - Open tasks are cancelled outright.
+/// - Claimed tasks are reclaimed from a silent agent once their (current
+///   milestone's) deadline and the claim grace period have both passed.
+/// - Submissions made after the deadline can be cancelled instead of
+///   reviewed. The bounty stays in escrow for `platform.review_period` so the
+///   agent can contest the cancellation with `open_dispute`; after that
+///   `expire_task` refunds the creator.
+///
+/// On an immediate refund the task PDA is closed and all lamports (bounty +
+/// rent) are returned to the creator. Token bounties are refunded from the
+/// vault, which is then closed. Historical record is preserved via the
+/// `TaskCancelled` event.
 pub fn cancel_task(ctx: Context<CancelTask>) -> Result<()> {
     let task_key = ctx.accounts.task.key();
     let creator_key = ctx.accounts.creator.key();
-    let task = &ctx.accounts.task;
-    require!(task.status == TaskStatus::Open, VerbittoError::TaskNotOpen);
+    let task = &mut ctx.accounts.task;
     require!(task.creator == creator_key, VerbittoError::NotTaskCreator);
 
-    // bounty + rent will be returned to creator via `close = creator`
+    let now = Clock::get()?.unix_timestamp;
+    match task.status {
+        TaskStatus::Open => {}
+        TaskStatus::Claimed => {
+            // The agent may still submit during the grace period
+            let reclaim_at = task
+                .current_deadline()
+                .checked_add(ctx.accounts.platform.claim_grace_period)
+                .ok_or(VerbittoError::ArithmeticOverflow)?;
+            require!(now >= reclaim_at, VerbittoError::DeadlineNotReached);
+        }
+        TaskStatus::Submitted => {
+            require!(
+                task.submitted_at > task.current_deadline(),
+                VerbittoError::SubmissionNotLate
+            );
+            // Hold the bounty while the agent may contest
+            task.status = TaskStatus::Cancelled;
+            task.settled_at = now;
+
+            emit!(LateSubmissionCancelled {
+                task: task_key,
+                creator: creator_key,
+                agent: task.agent,
+            });
+            return Ok(());
+        }
+        _ => return err!(VerbittoError::TaskNotOpen),
+    }
+
+    let refund = task.remaining_bounty();
+    let creator_info = ctx.accounts.creator.to_account_info();
+    let token = escrow::token_escrow(
+        task,
+        &ctx.accounts.bounty_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    if let Some(token) = &token {
+        escrow::release(
+            task,
+            Some(token),
+            &creator_info,
+            ctx.accounts.creator_token_account.as_ref(),
+            refund,
+        )?;
+        escrow::close_vault(
+            task,
+            token,
+            ctx.accounts.creator_token_account.as_ref(),
+            &creator_info,
+        )?;
+    }
+
     emit!(TaskCancelled {
         task: task_key,
         creator: creator_key,
-        refunded_lamports: task.bounty_lamports,
+        refunded_lamports: refund,
     });
 
-    Ok(())
+    // bounty + rent are returned to the creator
+    task.close(creator_info)
 }
 
 /// Expire a task past its deadline. Anyone can call this.
-/// Refunds escrowed SOL to the creator.
+/// Refunds the unreleased bounty to the creator.
 /// For Claimed tasks, a grace period applies — the agent gets extra
 /// time (platform.claim_grace_period seconds) after the deadline to
-/// submit their deliverable before the task can be expired.
+/// submit their deliverable before the task can be expired. Milestone
+/// tasks use the current milestone's deadline when it has one.
+/// Late submissions cancelled by the creator are refunded here once the
+/// agent's window to contest (platform.review_period) has passed.
 pub fn expire_task(ctx: Context<ExpireTask>) -> Result<()> {
     let task_key = ctx.accounts.task.key();
     let task = &mut ctx.accounts.task;
     let platform = &ctx.accounts.platform;
     let now = Clock::get()?.unix_timestamp;
 
-    require!(
-        task.status == TaskStatus::Open || task.status == TaskStatus::Claimed,
-        VerbittoError::TaskCannotExpire
-    );
-
     // For Claimed tasks, apply grace period so the agent has time to submit
-    let effective_deadline = if task.status == TaskStatus::Claimed {
-        task.deadline
+    let effective_deadline = match task.status {
+        TaskStatus::Open => task.deadline,
+        TaskStatus::Claimed => task
+            .current_deadline()
             .checked_add(platform.claim_grace_period)
-            .ok_or(VerbittoError::ArithmeticOverflow)?
-    } else {
-        task.deadline
+            .ok_or(VerbittoError::ArithmeticOverflow)?,
+        TaskStatus::Cancelled => task
+            .settled_at
+            .checked_add(platform.review_period)
+            .ok_or(VerbittoError::ArithmeticOverflow)?,
+        _ => return err!(VerbittoError::TaskCannotExpire),
     };
 
     require!(now >= effective_deadline, VerbittoError::DeadlineNotReached);
 
-    let refund = task.bounty_lamports;
+    let refund = task.remaining_bounty();
 
     // Refund to creator
-    **task.to_account_info().try_borrow_mut_lamports()? -= refund;
-    **ctx
-        .accounts
-        .creator
-        .to_account_info()
-        .try_borrow_mut_lamports()? += refund;
+    let token = escrow::token_escrow(
+        task,
+        &ctx.accounts.bounty_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    escrow::release(
+        task,
+        token.as_ref(),
+        &ctx.accounts.creator,
+        ctx.accounts.creator_token_account.as_ref(),
+        refund,
+    )?;
+    if let Some(token) = &token {
+        escrow::close_vault(
+            task,
+            token,
+            ctx.accounts.creator_token_account.as_ref(),
+            &ctx.accounts.creator,
+        )?;
+    }
+
+    if task.status == TaskStatus::Cancelled {
+        emit!(TaskCancelled {
+            task: task_key,
+            creator: task.creator,
+            refunded_lamports: refund,
+        });
+    } else {
+        task.status = TaskStatus::Expired;
+
+        emit!(TaskExpired {
+            task: task_key,
+            creator: task.creator,
+            refunded_lamports: refund,
+        });
+    }
+
+    Ok(())
+}
+
+/// Withdraw a stuck task's escrow once settlement has been paused for
+/// `emergency_withdraw_delay`. Claimed and Rejected tasks refund the creator
+/// in full. A Submitted task pays the agent `emergency_agent_share_bps` of
+/// the submitted milestone and refunds the rest; the creator or the agent can
+/// call. Disputed tasks are left to `admin_resolve_dispute`.
+pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+    let task_key = ctx.accounts.task.key();
+    let caller = ctx.accounts.caller.key();
+    let task = &mut ctx.accounts.task;
+    let platform = &ctx.accounts.platform;
+
+    let available_at = platform
+        .settlement_paused_at
+        .checked_add(platform.emergency_withdraw_delay)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
+    require!(
+        platform.is_paused(PAUSE_SETTLEMENT) && Clock::get()?.unix_timestamp >= available_at,
+        VerbittoError::EmergencyWithdrawNotAvailable
+    );
+
+    let agent_share_bps = match task.status {
+        TaskStatus::Claimed | TaskStatus::Rejected => {
+            require!(caller == task.creator, VerbittoError::NotTaskCreator);
+            0
+        }
+        TaskStatus::Submitted => {
+            require!(
+                caller == task.creator || caller == task.agent,
+                VerbittoError::NotTaskParty
+            );
+            platform.emergency_agent_share_bps
+        }
+        _ => return err!(VerbittoError::TaskNotWithdrawable),
+    };
 
-    task.status = TaskStatus::Expired;
+    let agent_amount = task
+        .milestone_amount()
+        .checked_mul(agent_share_bps as u64)
+        .ok_or(VerbittoError::ArithmeticOverflow)?
+        / 10000;
+    let refund = task
+        .remaining_bounty()
+        .checked_sub(agent_amount)
+        .ok_or(VerbittoError::ArithmeticOverflow)?;
 
-    emit!(TaskExpired {
+    let token = escrow::token_escrow(
+        task,
+        &ctx.accounts.bounty_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    let agent_received = escrow::release(
+        task,
+        token.as_ref(),
+        &ctx.accounts.agent,
+        ctx.accounts.agent_token_account.as_ref(),
+        agent_amount,
+    )?;
+    let refunded = escrow::release(
+        task,
+        token.as_ref(),
+        &ctx.accounts.creator,
+        ctx.accounts.creator_token_account.as_ref(),
+        refund,
+    )?;
+    if let Some(token) = &token {
+        escrow::close_vault(
+            task,
+            token,
+            ctx.accounts.creator_token_account.as_ref(),
+            &ctx.accounts.creator,
+        )?;
+    }
+
+    emit!(EmergencyWithdrawal {
         task: task_key,
         creator: task.creator,
-        refunded_lamports: refund,
+        agent: task.agent,
+        caller,
+        refunded_lamports: refunded,
+        payout_lamports: agent_received,
     });
 
     Ok(())
@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::contexts::*;
 use crate::errors::VerbittoError;
 use crate::events::*;
+use crate::state::PAUSE_TEMPLATES;
 
 /// Create a reusable task template.
 pub fn create_template(
@@ -12,6 +13,10 @@ pub fn create_template(
     default_bounty_lamports: u64,
     category: crate::state::TaskCategory,
 ) -> Result<()> {
+    require!(
+        !ctx.accounts.platform.is_paused(PAUSE_TEMPLATES),
+        VerbittoError::PlatformPaused
+    );
     require!(title.len() <= 64, VerbittoError::TitleTooLong);
 
     let platform = &mut ctx.accounts.platform;
@@ -44,7 +49,7 @@ pub fn create_template(
 /// Deactivate a task template. Only the template creator can call.
 pub fn deactivate_template(ctx: Context<DeactivateTemplate>) -> Result<()> {
     require!(
-        !ctx.accounts.platform.is_paused,
+        !ctx.accounts.platform.is_paused(PAUSE_TEMPLATES),
         VerbittoError::PlatformPaused
     );
     let t = &mut ctx.accounts.template;
@@ -56,7 +61,7 @@ pub fn deactivate_template(ctx: Context<DeactivateTemplate>) -> Result<()> {
 /// Reactivate a previously deactivated template. Only the template creator can call.
 pub fn reactivate_template(ctx: Context<DeactivateTemplate>) -> Result<()> {
     require!(
-        !ctx.accounts.platform.is_paused,
+        !ctx.accounts.platform.is_paused(PAUSE_TEMPLATES),
         VerbittoError::PlatformPaused
     );
     let t = &mut ctx.accounts.template;
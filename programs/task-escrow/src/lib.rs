@@ -1,15 +1,27 @@
-/**
+/*
  * Verbitto — Task Escrow Program
  *
  * Trustless task settlement for the OpenClaw agent ecosystem.
  *
  * Flow:
- *   Creator → create_task (SOL escrow) → Agent claims → submits →
- *   Creator approves → SOL released (minus platform fee)
+ *   Creator → create_task (SOL or SPL token escrow) → Agent claims → submits →
+ *   Creator approves → bounty released (minus platform fee)
+ *   (or anyone cranks auto_approve_task once the review window lapses)
+ *
+ * Milestone tasks repeat submit → approve per milestone, releasing each
+ * milestone's share until the last one closes the task.
  *
  * Dispute path:
- *   Either party → open_dispute → third-party votes →
- *   resolve_dispute → funds distributed per ruling
+ *   Either party → open_dispute (initiator posts a bond; + submit_evidence
+ *   from both sides) →
+ *   draw_jury from the arbitrator pool (if the platform uses juries) →
+ *   third-party votes (commit, then reveal) →
+ *   optional appeal_dispute (bond, larger jury, longer vote) per round →
+ *   resolve_dispute after the last round → funds distributed per ruling →
+ *   settle_vote per arbitrator (stake back + reward + reputation, or stake
+ *   slashed and reputation lost)
+ *   A party can flag_dispute; the authority may then (or whenever
+ *   disputes are paused) rule directly via admin_resolve_dispute.
  *
  * Accounts (PDAs):
  *   Platform         [b"platform"]
@@ -18,12 +30,24 @@
  *   Dispute          [b"dispute", task_key]
  *   AgentProfile     [b"agent", authority]
  *   ArbitratorVote   [b"vote", dispute_key, voter_key]
+ *   ArbitratorPool   [b"arbitrator_pool"]
+ *   Arbitrator       [b"arbitrator", authority]
+ *   Token vault      [b"vault", task_key]            (SPL / Token-2022 bounties)
+ *   Governance       [b"governance"]
+ *   GovernanceProposal [b"proposal", proposal_index_le_bytes]
  */
 
+#![allow(clippy::too_many_arguments)]
+// Anchor 0.31's `#[program]` expansion emits its IDL handlers (which call the
+// deprecated `AccountInfo::realloc`) in a `__private` module at the crate
+// root, so an `allow` on `task_escrow` itself does not reach them.
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
 pub mod contexts;
 pub mod errors;
+pub mod escrow;
 pub mod events;
 pub mod instructions;
 pub mod state;
@@ -45,52 +69,84 @@ pub mod task_escrow {
 
     pub fn initialize_platform(
         ctx: Context<InitializePlatform>,
-        fee_bps: u16,
-        min_bounty_lamports: u64,
-        dispute_voting_period: i64,
-        dispute_min_votes: u8,
-        min_voter_reputation: i64,
-        claim_grace_period: i64,
-    ) -> Result<()> {
-        instructions::initialize_platform(
-            ctx,
-            fee_bps,
-            min_bounty_lamports,
-            dispute_voting_period,
-            dispute_min_votes,
-            min_voter_reputation,
-            claim_grace_period,
-        )
+        config: PlatformConfig,
+    ) -> Result<()> {
+        instructions::initialize_platform(ctx, config)
     }
 
-    pub fn pause_platform(ctx: Context<PlatformAdmin>) -> Result<()> {
-        instructions::pause_platform(ctx)
+    pub fn pause_platform(ctx: Context<PlatformAdmin>, flags: u8) -> Result<()> {
+        instructions::pause_platform(ctx, flags)
     }
 
-    pub fn resume_platform(ctx: Context<PlatformAdmin>) -> Result<()> {
-        instructions::resume_platform(ctx)
+    pub fn resume_platform(ctx: Context<PlatformAdmin>, flags: u8) -> Result<()> {
+        instructions::resume_platform(ctx, flags)
     }
 
     pub fn update_platform(
         ctx: Context<PlatformAdmin>,
-        fee_bps: u16,
-        min_bounty_lamports: u64,
-        dispute_voting_period: i64,
-        dispute_min_votes: u8,
-        min_voter_reputation: i64,
-        claim_grace_period: i64,
-        treasury: Pubkey,
-    ) -> Result<()> {
-        instructions::update_platform(
-            ctx,
-            fee_bps,
-            min_bounty_lamports,
-            dispute_voting_period,
-            dispute_min_votes,
-            min_voter_reputation,
-            claim_grace_period,
-            treasury,
-        )
+        update: PlatformConfigUpdate,
+    ) -> Result<()> {
+        instructions::update_platform(ctx, update)
+    }
+
+    pub fn apply_platform_config(ctx: Context<ApplyPlatformConfig>) -> Result<()> {
+        instructions::apply_platform_config(ctx)
+    }
+
+    pub fn propose_authority(ctx: Context<PlatformAdmin>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<PlatformAdmin>) -> Result<()> {
+        instructions::cancel_authority_transfer(ctx)
+    }
+
+    // ─── Migration ───────────────────────────────────────────
+
+    pub fn migrate_platform(ctx: Context<MigratePlatform>, config: PlatformConfig) -> Result<()> {
+        instructions::migrate_platform(ctx, config)
+    }
+
+    pub fn migrate_task(ctx: Context<MigrateTask>) -> Result<()> {
+        instructions::migrate_task(ctx)
+    }
+
+    pub fn migrate_agent_profile(ctx: Context<MigrateAgentProfile>) -> Result<()> {
+        instructions::migrate_agent_profile(ctx)
+    }
+
+    pub fn migrate_dispute(ctx: Context<MigrateDispute>) -> Result<()> {
+        instructions::migrate_dispute(ctx)
+    }
+
+    // ─── Governance ──────────────────────────────────────────
+
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::initialize_governance(ctx, signers, threshold)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: GovernanceAction) -> Result<()> {
+        instructions::create_proposal(ctx, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ReviewProposal>) -> Result<()> {
+        instructions::approve_proposal(ctx)
+    }
+
+    pub fn cancel_proposal(ctx: Context<ReviewProposal>) -> Result<()> {
+        instructions::cancel_proposal(ctx)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal(ctx)
     }
 
     // ─── Agent identity ──────────────────────────────────────
@@ -103,6 +159,39 @@ pub mod task_escrow {
         instructions::update_agent_skills(ctx, skill_tags)
     }
 
+    // ─── Arbitrators ──────────────────────────────────────────
+
+    pub fn initialize_arbitrator_pool(ctx: Context<InitializeArbitratorPool>) -> Result<()> {
+        instructions::initialize_arbitrator_pool(ctx)
+    }
+
+    pub fn register_arbitrator(
+        ctx: Context<RegisterArbitrator>,
+        categories: u8,
+        stake_lamports: u64,
+    ) -> Result<()> {
+        instructions::register_arbitrator(ctx, categories, stake_lamports)
+    }
+
+    pub fn update_arbitrator(
+        ctx: Context<UpdateArbitrator>,
+        categories: u8,
+        is_available: bool,
+    ) -> Result<()> {
+        instructions::update_arbitrator(ctx, categories, is_available)
+    }
+
+    pub fn set_arbitrator_approval(
+        ctx: Context<SetArbitratorApproval>,
+        approved: bool,
+    ) -> Result<()> {
+        instructions::set_arbitrator_approval(ctx, approved)
+    }
+
+    pub fn deregister_arbitrator(ctx: Context<DeregisterArbitrator>) -> Result<()> {
+        instructions::deregister_arbitrator(ctx)
+    }
+
     // ─── Task lifecycle ────────────────────────────────────────
 
     pub fn create_task(
@@ -125,6 +214,28 @@ pub mod task_escrow {
         )
     }
 
+    pub fn create_milestone_task(
+        ctx: Context<CreateTask>,
+        title: String,
+        description_hash: [u8; 32],
+        bounty_lamports: u64,
+        task_index: u64,
+        deadline: i64,
+        reputation_reward: i64,
+        milestones: Vec<Milestone>,
+    ) -> Result<()> {
+        instructions::create_milestone_task(
+            ctx,
+            title,
+            description_hash,
+            bounty_lamports,
+            task_index,
+            deadline,
+            reputation_reward,
+            milestones,
+        )
+    }
+
     pub fn create_task_from_template(
         ctx: Context<CreateTaskFromTemplate>,
         bounty_lamports: u64,
@@ -151,6 +262,10 @@ pub mod task_escrow {
         instructions::approve_and_settle(ctx)
     }
 
+    pub fn auto_approve_task(ctx: Context<AutoApproveTask>) -> Result<()> {
+        instructions::auto_approve_task(ctx)
+    }
+
     pub fn reject_submission(
         ctx: Context<RejectSubmission>,
         reason_hash: [u8; 32],
@@ -166,6 +281,10 @@ pub mod task_escrow {
         instructions::expire_task(ctx)
     }
 
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+        instructions::emergency_withdraw(ctx)
+    }
+
     // ─── Templates ─────────────────────────────────────────────
 
     pub fn create_template(
@@ -196,11 +315,62 @@ pub mod task_escrow {
         instructions::open_dispute(ctx, reason, evidence_hash)
     }
 
-    pub fn cast_vote(ctx: Context<CastVote>, ruling: Ruling) -> Result<()> {
-        instructions::cast_vote(ctx, ruling)
+    pub fn draw_jury(ctx: Context<DrawJury>) -> Result<()> {
+        instructions::draw_jury(ctx)
+    }
+
+    pub fn submit_evidence(ctx: Context<SubmitEvidence>, evidence_hash: [u8; 32]) -> Result<()> {
+        instructions::submit_evidence(ctx, evidence_hash)
+    }
+
+    pub fn flag_dispute(ctx: Context<FlagDispute>) -> Result<()> {
+        instructions::flag_dispute(ctx)
+    }
+
+    pub fn cast_vote(ctx: Context<CastVote>, commitment: [u8; 32]) -> Result<()> {
+        instructions::cast_vote(ctx, commitment)
+    }
+
+    pub fn change_vote(ctx: Context<ChangeVote>, commitment: [u8; 32]) -> Result<()> {
+        instructions::change_vote(ctx, commitment)
+    }
+
+    pub fn withdraw_vote(ctx: Context<WithdrawVote>) -> Result<()> {
+        instructions::withdraw_vote(ctx)
+    }
+
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        ruling: Ruling,
+        split_pct: u8,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_vote(ctx, ruling, split_pct, salt)
+    }
+
+    pub fn appeal_dispute(ctx: Context<AppealDispute>) -> Result<()> {
+        instructions::appeal_dispute(ctx)
     }
 
     pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
         instructions::resolve_dispute(ctx)
     }
+
+    pub fn admin_resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        ruling: Ruling,
+        split_pct: u8,
+    ) -> Result<()> {
+        instructions::admin_resolve_dispute(ctx, ruling, split_pct)
+    }
+
+    pub fn settle_vote(ctx: Context<SettleVote>) -> Result<()> {
+        instructions::settle_vote(ctx)
+    }
+
+    pub fn close_votes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseVotes<'info>>,
+    ) -> Result<()> {
+        instructions::close_votes(ctx)
+    }
 }
@@ -1,5 +1,52 @@
 use anchor_lang::prelude::*;
 
+/// Maximum number of milestones on a single task.
+pub const MAX_MILESTONES: usize = 8;
+
+/// Maximum evidence entries each party may add to a dispute.
+pub const MAX_EVIDENCE_PER_PARTY: usize = 4;
+
+/// Maximum arbitrators in the jury pool.
+pub const MAX_POOL_SIZE: usize = 128;
+
+/// Maximum jurors drawn for a dispute across all of its rounds.
+pub const MAX_JURORS: usize = 32;
+
+/// Slots between opening (or appealing) a dispute and the block whose hash
+/// seeds its jury draw, so nobody knows the seed when the round starts.
+pub const JURY_DRAW_DELAY_SLOTS: u64 = 2;
+
+/// Maximum admin signers in platform governance.
+pub const MAX_GOVERNANCE_SIGNERS: usize = 10;
+
+// `Platform.pause_flags` bits, one per instruction class. Refunds,
+// cancellations and stake withdrawals are never paused.
+
+/// create_task, create_milestone_task, create_task_from_template
+pub const PAUSE_TASK_CREATION: u8 = 1 << 0;
+/// claim_task, submit_deliverable
+pub const PAUSE_CLAIMING: u8 = 1 << 1;
+/// approve_and_settle, auto_approve_task, reject_submission
+pub const PAUSE_SETTLEMENT: u8 = 1 << 2;
+/// open_dispute through resolve_dispute, and the reject_submission that escalates
+/// to a dispute (settle_vote and close_votes stay open)
+pub const PAUSE_DISPUTES: u8 = 1 << 3;
+/// create_template, deactivate_template, reactivate_template
+pub const PAUSE_TEMPLATES: u8 = 1 << 4;
+/// register_agent, update_agent_skills, register_arbitrator, update_arbitrator
+pub const PAUSE_AGENT_REGISTRATION: u8 = 1 << 5;
+/// Every pausable instruction class.
+pub const PAUSE_ALL: u8 = 0b0011_1111;
+
+/// Bits of every TaskCategory (bit = category as u8).
+pub const TASK_CATEGORY_MASK: u8 = 0b0111_1111;
+
+/// Granularity (percentage points) of the agent's share in a Split vote.
+pub const SPLIT_PCT_STEP: u8 = 10;
+
+/// Number of Split share buckets: 0%, 10%, ..., 100%.
+pub const SPLIT_PCT_BUCKETS: usize = 100 / SPLIT_PCT_STEP as usize + 1;
+
 // ============================================================
 // State accounts
 // ============================================================
@@ -30,13 +77,103 @@ pub struct Platform {
     pub min_voter_reputation: i64,
     /// Grace period (seconds) after deadline for Claimed tasks before expiry
     pub claim_grace_period: i64,
-    /// Whether the platform is paused (emergency stop)
-    pub is_paused: bool,
+    /// Paused instruction classes (`PAUSE_*` bits; 0 = fully running)
+    pub pause_flags: u8,
     /// PDA bump
     pub bump: u8,
+    // Fields below were appended after the first deployment; existing
+    // accounts are grown by `migrate_platform`.
+    /// Review window (seconds) after submission before anyone can auto-approve
+    pub review_period: i64,
+    /// Extra time (seconds) after voting ends before a dispute that missed
+    /// quorum can be resolved on whatever votes were cast
+    pub dispute_fallback_period: i64,
+    /// Stake (lamports) an arbitrator locks in their vote account when voting
+    pub arbitrator_stake_lamports: u64,
+    /// Share of a dispute's platform fee (bps) paid to majority voters
+    pub arbitrator_reward_bps: u16,
+    /// Share of a minority (or unrevealed) voter's stake (bps) slashed to the treasury
+    pub slash_bps: u16,
+    /// Window (seconds) after a dispute round ends in which either party may appeal
+    pub appeal_window: i64,
+    /// Bond (lamports) posted by an appellant, refunded if the appeal succeeds
+    pub appeal_bond_lamports: u64,
+    /// Maximum appeal rounds per dispute (0 disables appeals)
+    pub max_appeal_rounds: u8,
+    /// How an arbitrator's reputation translates into vote weight
+    pub vote_weight_mode: VoteWeightMode,
+    /// Maximum weight of a single vote in `VoteWeightMode::Capped`
+    pub vote_weight_cap: u64,
+    /// Minimum revealed vote weight for quorum, alongside `dispute_min_votes`
+    pub dispute_min_weight: u64,
+    /// First-round jurors drawn from the arbitrator pool per dispute (0 = open
+    /// voting). Doubles each appeal round; all rounds together fit MAX_JURORS.
+    pub jury_size: u8,
+    /// Stake (lamports) an arbitrator locks in their registration
+    pub min_arbitrator_stake_lamports: u64,
+    /// Whether new arbitrators need the authority's approval before voting
+    pub arbitrator_approval_required: bool,
+    /// Reputation gained by an arbitrator for each vote with the final ruling
+    pub arbitrator_rep_reward: i64,
+    /// Reputation lost by an arbitrator for each vote against the final
+    /// ruling (or left unrevealed)
+    pub arbitrator_rep_penalty: i64,
+    /// Bond (lamports) the initiator posts when opening a dispute
+    pub dispute_bond_lamports: u64,
+    /// Minimum delay (seconds) before a queued configuration change applies
+    pub config_timelock: i64,
+    /// How long (seconds) settlement must stay paused before creators can
+    /// pull their escrow out with `emergency_withdraw`
+    pub emergency_withdraw_delay: i64,
+    /// Share (bps) of the submitted milestone paid to the agent when a
+    /// Submitted task is withdrawn
+    pub emergency_agent_share_bps: u16,
+    /// Proposed new authority awaiting `accept_authority` (Pubkey::default if none)
+    pub pending_authority: Pubkey,
+    /// Configuration queued by `update_platform`
+    pub pending_config: PlatformConfig,
+    /// Treasury queued alongside `pending_config`
+    pub pending_treasury: Pubkey,
+    /// When `pending_config` may be applied (0 if nothing is queued)
+    pub config_effective_at: i64,
+    /// When settlement was last paused (0 while it is running)
+    pub settlement_paused_at: i64,
+}
+
+impl Platform {
+    /// Whether any of the instruction classes in `flags` is paused.
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_flags & flags != 0
+    }
+
+    /// Weight of a vote cast by an arbitrator with `reputation`. Every vote
+    /// weighs at least 1, so a Flat platform simply counts heads.
+    pub fn vote_weight(&self, reputation: i64) -> u64 {
+        let rep = reputation.max(1) as u64;
+        match self.vote_weight_mode {
+            VoteWeightMode::Flat => 1,
+            VoteWeightMode::Linear => rep,
+            VoteWeightMode::Sqrt => isqrt(rep),
+            VoteWeightMode::Capped => rep.min(self.vote_weight_cap).max(1),
+        }
+    }
+}
+
+/// Integer square root (floor).
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n / 2 + 1;
+    let mut y = (x + n / x) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
 }
 
-/// Individual task with escrowed SOL bounty.
+/// Individual task with escrowed SOL or SPL token bounty.
 #[account]
 #[derive(InitSpace)]
 pub struct Task {
@@ -44,7 +181,8 @@ pub struct Task {
     pub creator: Pubkey,
     /// Sequential task index (global)
     pub task_index: u64,
-    /// Bounty amount in lamports (held in this PDA)
+    /// Bounty amount in lamports (held in this PDA), or in base units of
+    /// `bounty_mint` (held in the task vault) for token bounties
     pub bounty_lamports: u64,
     /// Current task status
     pub status: TaskStatus,
@@ -54,7 +192,7 @@ pub struct Task {
     pub deadline: i64,
     /// Creation timestamp
     pub created_at: i64,
-    /// Settlement timestamp (0 if unsettled)
+    /// Settlement (or late-submission cancellation) timestamp (0 if unsettled)
     pub settled_at: i64,
     /// Reputation reward on task approval
     pub reputation_reward: i64,
@@ -71,6 +209,61 @@ pub struct Task {
     pub rejection_count: u8,
     /// PDA bump
     pub bump: u8,
+    // Fields below were appended after the first deployment; existing
+    // accounts are grown by `migrate_task`.
+    /// SPL / Token-2022 bounty mint (Pubkey::default for SOL bounties)
+    pub bounty_mint: Pubkey,
+    /// Timestamp of the latest submission (0 if none)
+    pub submitted_at: i64,
+    /// Category (from the template; Other for tasks created directly)
+    pub category: TaskCategory,
+    /// Ordered payment milestones (empty = single all-or-nothing delivery)
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+    /// Index of the milestone currently being worked on
+    pub current_milestone: u8,
+    /// Portion of the bounty already released by approved milestones
+    pub released_lamports: u64,
+    /// Platform fee (bps) in effect when the task was created
+    pub fee_bps: u16,
+}
+
+impl Task {
+    /// Whether the bounty is held in a token vault rather than as lamports.
+    pub fn is_token_bounty(&self) -> bool {
+        self.bounty_mint != Pubkey::default()
+    }
+
+    /// Bounty still held in escrow (not yet released to the agent).
+    pub fn remaining_bounty(&self) -> u64 {
+        self.bounty_lamports.saturating_sub(self.released_lamports)
+    }
+
+    /// Whether the current milestone is the last one (always true for
+    /// single-delivery tasks).
+    pub fn is_final_milestone(&self) -> bool {
+        self.current_milestone as usize + 1 >= self.milestones.len()
+    }
+
+    /// Amount at stake for the current milestone. The final milestone takes
+    /// whatever remains, absorbing any Token-2022 transfer-fee shortfall.
+    pub fn milestone_amount(&self) -> u64 {
+        let remaining = self.remaining_bounty();
+        if self.is_final_milestone() {
+            return remaining;
+        }
+        self.milestones[self.current_milestone as usize]
+            .amount
+            .min(remaining)
+    }
+
+    /// Deadline for the current milestone, falling back to the task deadline.
+    pub fn current_deadline(&self) -> i64 {
+        match self.milestones.get(self.current_milestone as usize) {
+            Some(m) if m.deadline > 0 => m.deadline,
+            _ => self.deadline,
+        }
+    }
 }
 
 /// Reusable task template.
@@ -112,12 +305,13 @@ pub struct Dispute {
     pub evidence_hash: [u8; 32],
     /// Current dispute status
     pub status: DisputeStatus,
-    /// Votes for creator wins
-    pub votes_for_creator: u16,
-    /// Votes for agent wins
-    pub votes_for_agent: u16,
-    /// Votes for 50/50 split
-    pub votes_for_split: u16,
+    /// Votes for creator wins cast under the first deployment (migrated
+    /// disputes only); already counted in `votes_for_creator`
+    pub legacy_votes_for_creator: u16,
+    /// Votes for agent wins cast under the first deployment
+    pub legacy_votes_for_agent: u16,
+    /// Votes for a 50/50 split cast under the first deployment
+    pub legacy_votes_for_split: u16,
     /// When dispute was opened
     pub opened_at: i64,
     /// When dispute was resolved (0 if open)
@@ -126,6 +320,130 @@ pub struct Dispute {
     pub ruling: Ruling,
     /// PDA bump
     pub bump: u8,
+    // Fields below were appended after the first deployment; existing
+    // accounts are grown by `migrate_dispute`.
+    /// Revealed vote weight for creator wins (current round)
+    pub votes_for_creator: u64,
+    /// Revealed vote weight for agent wins (current round)
+    pub votes_for_agent: u64,
+    /// Revealed vote weight for a split (current round)
+    pub votes_for_split: u64,
+    /// End of the current round's commit phase
+    pub commit_deadline: i64,
+    /// End of the current round's reveal phase
+    pub reveal_deadline: i64,
+    /// How the final ruling was reached
+    pub resolution: ResolutionPath,
+    /// Votes committed (each must be settled before the dispute closes)
+    pub votes_committed: u16,
+    /// Votes settled via `settle_vote`
+    pub votes_settled: u16,
+    /// Lamports held here for majority voters: forfeited appeal bonds, plus
+    /// the fee share added on resolution
+    pub reward_pool_lamports: u64,
+    /// Token-bounty fee share held for majority voters in the dispute's
+    /// reward vault, as received (token bounties only)
+    pub reward_pool_tokens: u64,
+    /// Slash rate (bps) snapshotted from the platform on resolution
+    pub slash_bps: u16,
+    /// Arbitrator reputation reward snapshotted from the platform on resolution
+    pub arbitrator_rep_reward: i64,
+    /// Arbitrator reputation penalty snapshotted from the platform on resolution
+    pub arbitrator_rep_penalty: i64,
+    /// Bond (lamports) posted by the initiator when opening the dispute
+    pub bond_lamports: u64,
+    /// What became of the initiator's bond
+    pub bond_outcome: BondOutcome,
+    /// Appeal round (0 = original jury)
+    pub round: u8,
+    /// Revealed votes needed for quorum in the current round
+    pub min_votes: u16,
+    /// Revealed vote weight needed for quorum in the current round
+    pub min_weight: u64,
+    /// Votes revealed in the current round
+    pub votes_revealed: u16,
+    /// Party that filed the latest appeal (Pubkey::default if none)
+    pub appellant: Pubkey,
+    /// Ruling the latest appeal was filed against
+    pub appealed_ruling: Ruling,
+    /// Bond (lamports) held here for the latest appeal
+    pub appeal_bond_lamports: u64,
+    /// Revealed vote weight for creator wins in earlier rounds
+    pub prior_votes_for_creator: u64,
+    /// Revealed vote weight for agent wins in earlier rounds
+    pub prior_votes_for_agent: u64,
+    /// Revealed vote weight for a split in earlier rounds
+    pub prior_votes_for_split: u64,
+    /// Evidence added by either party via `submit_evidence`
+    #[max_len(2 * MAX_EVIDENCE_PER_PARTY)]
+    pub evidence: Vec<Evidence>,
+    /// Revealed Split vote weight per agent-share bucket (current round)
+    pub split_pct_votes: [u64; SPLIT_PCT_BUCKETS],
+    /// Agent's share (%) of the disputed amount after fee, set on resolution
+    /// (median of Split votes, 100 for AgentWins, 0 for CreatorWins)
+    pub agent_share_pct: u8,
+    /// First-round jury size snapshotted from the platform (0 = open voting)
+    pub jury_size: u8,
+    /// Jurors drawn via `draw_jury`, across all rounds
+    #[max_len(MAX_JURORS)]
+    pub jurors: Vec<Pubkey>,
+    /// Index in `jurors` where the current round's jury starts
+    pub jury_start: u8,
+    /// The current round's jury is seeded by the first block at or after
+    /// this slot
+    pub draw_slot: u64,
+    /// Set by a task party to ask the platform authority to step in
+    pub flagged: bool,
+}
+
+impl Dispute {
+    /// Weighted median agent share (%) of the current round's Split votes;
+    /// the lower of the two middle shares on an even split, and 50 if there
+    /// are none.
+    pub fn median_split_pct(&self) -> u8 {
+        let total: u64 = self.split_pct_votes.iter().sum();
+        if total == 0 {
+            return 50;
+        }
+        let target = (total - 1) / 2;
+        let mut seen = 0;
+        for (bucket, &count) in self.split_pct_votes.iter().enumerate() {
+            seen += count;
+            if seen > target {
+                return bucket as u8 * SPLIT_PCT_STEP;
+            }
+        }
+        50
+    }
+
+    /// Jurors drawn for the current round (empty until `draw_jury`).
+    pub fn current_jury(&self) -> &[Pubkey] {
+        &self.jurors[self.jury_start as usize..]
+    }
+
+    /// Revealed vote weight for `ruling` across all rounds, from votes that
+    /// are settled here. Legacy votes carry no stake and are left out.
+    pub fn votes_for(&self, ruling: Ruling) -> u64 {
+        let (current, prior, legacy) = match ruling {
+            Ruling::CreatorWins => (
+                self.votes_for_creator,
+                self.prior_votes_for_creator,
+                self.legacy_votes_for_creator,
+            ),
+            Ruling::AgentWins => (
+                self.votes_for_agent,
+                self.prior_votes_for_agent,
+                self.legacy_votes_for_agent,
+            ),
+            Ruling::Split => (
+                self.votes_for_split,
+                self.prior_votes_for_split,
+                self.legacy_votes_for_split,
+            ),
+            Ruling::Pending => return 0,
+        };
+        (current + prior).saturating_sub(legacy as u64)
+    }
 }
 
 /// Individual arbitrator vote on a dispute.
@@ -136,12 +454,24 @@ pub struct ArbitratorVote {
     pub dispute: Pubkey,
     /// Voter's pubkey
     pub arbitrator: Pubkey,
-    /// Voter's ruling
+    /// Voter's ruling (Pending until revealed)
     pub ruling: Ruling,
-    /// When vote was cast
+    /// When vote was committed
     pub voted_at: i64,
     /// PDA bump
     pub bump: u8,
+    // Fields below were appended after the first deployment. Votes cast
+    // before it are reclaimed with `close_votes`.
+    /// sha256(ruling || salt || voter) committed during the commit phase
+    pub commitment: [u8; 32],
+    /// Agent's share (%) voted with a Split ruling (0 otherwise)
+    pub split_pct: u8,
+    /// Stake (lamports) locked in this account until settlement
+    pub stake_lamports: u64,
+    /// Vote weight, from the arbitrator's reputation when the vote was cast
+    pub weight: u64,
+    /// Dispute round the vote was cast in
+    pub round: u8,
 }
 
 /// Per-creator task counter to eliminate global task_count contention.
@@ -182,6 +512,269 @@ pub struct AgentProfile {
     pub skill_tags: u8,
     /// PDA bump
     pub bump: u8,
+    // Fields below were appended after the first deployment; existing
+    // accounts are grown by `migrate_agent_profile`.
+    /// Disputes this agent voted on as an arbitrator (counted on settlement)
+    pub disputes_judged: u64,
+    /// Of those, votes that matched the final ruling
+    pub majority_votes: u64,
+}
+
+/// Arbitrator registration, opted into by an agent.
+/// PDA: [b"arbitrator", authority]
+#[account]
+#[derive(InitSpace)]
+pub struct Arbitrator {
+    /// Arbitrator wallet (signer authority)
+    pub authority: Pubkey,
+    /// Bitmap of TaskCategory values qualified for (bit = category as u8)
+    pub categories: u8,
+    /// Whether the arbitrator can currently be drawn onto juries
+    pub is_available: bool,
+    /// Whether the arbitrator may vote (always true unless the platform
+    /// requires approval)
+    pub is_approved: bool,
+    /// Stake (lamports) locked in this account until deregistration
+    pub stake_lamports: u64,
+    /// Registration timestamp
+    pub registered_at: i64,
+    /// Votes cast and not yet settled or withdrawn. The stake stays locked
+    /// (no deregistration) until this is back to zero.
+    pub active_votes: u16,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Arbitrators eligible to be drawn onto dispute juries: every approved,
+/// available registration. Singleton PDA.
+/// PDA: [b"arbitrator_pool"]
+#[account]
+#[derive(InitSpace)]
+pub struct ArbitratorPool {
+    /// Eligible arbitrators and their categories
+    #[max_len(MAX_POOL_SIZE)]
+    pub members: Vec<PoolMember>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// M-of-N admin signers who change the platform through proposals.
+/// Singleton PDA.
+/// PDA: [b"governance"]
+#[account]
+#[derive(InitSpace)]
+pub struct Governance {
+    /// Admin signers
+    #[max_len(MAX_GOVERNANCE_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    /// Approvals needed to execute a proposal
+    pub threshold: u8,
+    /// Sequential proposal counter
+    pub proposal_count: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// A platform change awaiting approval by governance signers.
+/// PDA: [b"proposal", proposal_index_le_bytes]
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceProposal {
+    /// Index from `governance.proposal_count`
+    pub proposal_index: u64,
+    /// Signer who created the proposal
+    pub proposer: Pubkey,
+    /// Change applied on execution
+    pub action: GovernanceAction,
+    /// Signers who approved, the proposer first
+    #[max_len(MAX_GOVERNANCE_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    /// Current proposal status
+    pub status: ProposalStatus,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// When executed or cancelled (0 while active)
+    pub closed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Every platform setting `update_platform` can change, except the treasury.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PlatformConfig {
+    pub fee_bps: u16,
+    pub min_bounty_lamports: u64,
+    pub dispute_voting_period: i64,
+    pub dispute_min_votes: u8,
+    pub min_voter_reputation: i64,
+    pub claim_grace_period: i64,
+    pub review_period: i64,
+    pub dispute_fallback_period: i64,
+    pub arbitrator_stake_lamports: u64,
+    pub arbitrator_reward_bps: u16,
+    pub slash_bps: u16,
+    pub appeal_window: i64,
+    pub appeal_bond_lamports: u64,
+    pub max_appeal_rounds: u8,
+    pub vote_weight_mode: VoteWeightMode,
+    pub vote_weight_cap: u64,
+    pub dispute_min_weight: u64,
+    pub jury_size: u8,
+    pub min_arbitrator_stake_lamports: u64,
+    pub arbitrator_approval_required: bool,
+    pub arbitrator_rep_reward: i64,
+    pub arbitrator_rep_penalty: i64,
+    pub dispute_bond_lamports: u64,
+    pub config_timelock: i64,
+    pub emergency_withdraw_delay: i64,
+    pub emergency_agent_share_bps: u16,
+}
+
+/// Patch for `update_platform`: `None` fields keep their current value.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct PlatformConfigUpdate {
+    pub fee_bps: Option<u16>,
+    pub min_bounty_lamports: Option<u64>,
+    pub dispute_voting_period: Option<i64>,
+    pub dispute_min_votes: Option<u8>,
+    pub min_voter_reputation: Option<i64>,
+    pub claim_grace_period: Option<i64>,
+    pub review_period: Option<i64>,
+    pub dispute_fallback_period: Option<i64>,
+    pub arbitrator_stake_lamports: Option<u64>,
+    pub arbitrator_reward_bps: Option<u16>,
+    pub slash_bps: Option<u16>,
+    pub appeal_window: Option<i64>,
+    pub appeal_bond_lamports: Option<u64>,
+    pub max_appeal_rounds: Option<u8>,
+    pub vote_weight_mode: Option<VoteWeightMode>,
+    pub vote_weight_cap: Option<u64>,
+    pub dispute_min_weight: Option<u64>,
+    pub jury_size: Option<u8>,
+    pub min_arbitrator_stake_lamports: Option<u64>,
+    pub arbitrator_approval_required: Option<bool>,
+    pub arbitrator_rep_reward: Option<i64>,
+    pub arbitrator_rep_penalty: Option<i64>,
+    pub dispute_bond_lamports: Option<u64>,
+    pub config_timelock: Option<i64>,
+    pub emergency_withdraw_delay: Option<i64>,
+    pub emergency_agent_share_bps: Option<u16>,
+    pub treasury: Option<Pubkey>,
+}
+
+impl PlatformConfigUpdate {
+    /// Overwrite the fields of `c` this patch sets.
+    pub fn apply_to(&self, c: &mut PlatformConfig) {
+        if let Some(v) = self.fee_bps {
+            c.fee_bps = v;
+        }
+        if let Some(v) = self.min_bounty_lamports {
+            c.min_bounty_lamports = v;
+        }
+        if let Some(v) = self.dispute_voting_period {
+            c.dispute_voting_period = v;
+        }
+        if let Some(v) = self.dispute_min_votes {
+            c.dispute_min_votes = v;
+        }
+        if let Some(v) = self.min_voter_reputation {
+            c.min_voter_reputation = v;
+        }
+        if let Some(v) = self.claim_grace_period {
+            c.claim_grace_period = v;
+        }
+        if let Some(v) = self.review_period {
+            c.review_period = v;
+        }
+        if let Some(v) = self.dispute_fallback_period {
+            c.dispute_fallback_period = v;
+        }
+        if let Some(v) = self.arbitrator_stake_lamports {
+            c.arbitrator_stake_lamports = v;
+        }
+        if let Some(v) = self.arbitrator_reward_bps {
+            c.arbitrator_reward_bps = v;
+        }
+        if let Some(v) = self.slash_bps {
+            c.slash_bps = v;
+        }
+        if let Some(v) = self.appeal_window {
+            c.appeal_window = v;
+        }
+        if let Some(v) = self.appeal_bond_lamports {
+            c.appeal_bond_lamports = v;
+        }
+        if let Some(v) = self.max_appeal_rounds {
+            c.max_appeal_rounds = v;
+        }
+        if let Some(v) = self.vote_weight_mode {
+            c.vote_weight_mode = v;
+        }
+        if let Some(v) = self.vote_weight_cap {
+            c.vote_weight_cap = v;
+        }
+        if let Some(v) = self.dispute_min_weight {
+            c.dispute_min_weight = v;
+        }
+        if let Some(v) = self.jury_size {
+            c.jury_size = v;
+        }
+        if let Some(v) = self.min_arbitrator_stake_lamports {
+            c.min_arbitrator_stake_lamports = v;
+        }
+        if let Some(v) = self.arbitrator_approval_required {
+            c.arbitrator_approval_required = v;
+        }
+        if let Some(v) = self.arbitrator_rep_reward {
+            c.arbitrator_rep_reward = v;
+        }
+        if let Some(v) = self.arbitrator_rep_penalty {
+            c.arbitrator_rep_penalty = v;
+        }
+        if let Some(v) = self.dispute_bond_lamports {
+            c.dispute_bond_lamports = v;
+        }
+        if let Some(v) = self.config_timelock {
+            c.config_timelock = v;
+        }
+        if let Some(v) = self.emergency_withdraw_delay {
+            c.emergency_withdraw_delay = v;
+        }
+        if let Some(v) = self.emergency_agent_share_bps {
+            c.emergency_agent_share_bps = v;
+        }
+    }
+}
+
+/// A slice of a task's bounty released on approval of its deliverable.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct Milestone {
+    /// Content hash of the milestone title / scope
+    pub title_hash: [u8; 32],
+    /// Share of the bounty released when this milestone is approved
+    pub amount: u64,
+    /// Optional milestone deadline (0 = task deadline applies)
+    pub deadline: i64,
+}
+
+/// Arbitrator pool entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PoolMember {
+    /// Arbitrator wallet
+    pub arbitrator: Pubkey,
+    /// Bitmap of TaskCategory values qualified for
+    pub categories: u8,
+}
+
+/// Evidence entry added to a dispute by the creator or agent.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct Evidence {
+    /// Creator or agent who submitted it
+    pub submitter: Pubkey,
+    /// Evidence content hash
+    pub evidence_hash: [u8; 32],
+    /// Submission timestamp
+    pub submitted_at: i64,
 }
 
 // ============================================================
@@ -200,7 +793,7 @@ pub enum TaskStatus {
     Approved,
     /// Creator rejected submission
     Rejected,
-    /// Creator cancelled (pre-claim)
+    /// Creator cancelled (pre-claim, or a late submission awaiting refund)
     Cancelled,
     /// Past deadline, funds refunded
     Expired,
@@ -219,12 +812,21 @@ pub enum TaskCategory {
     Other,
 }
 
+impl TaskCategory {
+    /// This category's bit in a category bitmap.
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum DisputeReason {
     QualityIssue,
     DeadlineMissed,
     Plagiarism,
     Other,
+    /// Opened automatically when the creator hits MAX_REJECTIONS
+    RejectionLimit,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
@@ -240,3 +842,86 @@ pub enum Ruling {
     AgentWins,
     Split,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ResolutionPath {
+    /// Not yet resolved
+    Pending,
+    /// Majority of a quorum of votes
+    Quorum,
+    /// Quorum missed; plurality of the votes cast after the fallback period
+    Plurality,
+    /// Quorum missed with no votes cast; defaults to Split
+    DefaultSplit,
+    /// Ruled by the platform authority via `admin_resolve_dispute`
+    Admin,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BondOutcome {
+    /// No bond was required when the dispute opened
+    None,
+    /// Held in the dispute account until resolution
+    Pending,
+    /// Refunded to the initiator (ruling not against them)
+    Returned,
+    /// Added to the majority voters' reward pool (treasury if none)
+    Forfeited,
+}
+
+// Stored in a proposal sized for the largest variant anyway, so boxing the
+// config patch would save nothing.
+#[allow(clippy::large_enum_variant)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum GovernanceAction {
+    /// Patch the platform configuration, as `update_platform`. The patch is
+    /// merged when the proposal executes, so fields it leaves unset keep
+    /// whatever value they have by then.
+    UpdatePlatform(PlatformConfigUpdate),
+    /// Pause the `PAUSE_*` classes given, as `pause_platform`
+    PausePlatform(u8),
+    /// Resume the `PAUSE_*` classes given, as `resume_platform`
+    ResumePlatform(u8),
+    /// Send platform fees to a new treasury
+    SetTreasury(Pubkey),
+    /// Replace the governance signers and threshold
+    SetSigners {
+        #[max_len(MAX_GOVERNANCE_SIGNERS)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    /// Rule on a dispute; executed by `admin_resolve_dispute`
+    AdminResolveDispute {
+        dispute: Pubkey,
+        ruling: Ruling,
+        split_pct: u8,
+    },
+    /// Approve or revoke an arbitrator; executed by `set_arbitrator_approval`
+    SetArbitratorApproval { arbitrator: Pubkey, approved: bool },
+    /// Nominate the next platform authority, as `propose_authority`
+    ProposeAuthority(Pubkey),
+    /// Withdraw the pending nomination, as `cancel_authority_transfer`
+    CancelAuthorityTransfer,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ProposalStatus {
+    /// Collecting approvals
+    Active,
+    /// Threshold met and applied
+    Executed,
+    /// Withdrawn by its proposer
+    Cancelled,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum VoteWeightMode {
+    /// One arbitrator, one vote
+    Flat,
+    /// Weight equals reputation score
+    Linear,
+    /// Weight is the square root of reputation score
+    Sqrt,
+    /// Weight equals reputation score up to `platform.vote_weight_cap`
+    Capped,
+}
@@ -1,18 +1,8 @@
 import * as anchor from '@coral-xyz/anchor'
 import { AnchorProvider, type Program } from '@coral-xyz/anchor'
 import { LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
-import BN from 'bn.js'
 import type { TaskEscrow } from '../target/types/task_escrow.js'
-
-// Platform configuration
-const PLATFORM_CONFIG = {
-  feeBps: 250, // 2.5% platform fee
-  minBounty: 0.01 * LAMPORTS_PER_SOL, // Minimum task bounty 0.01 SOL
-  votingPeriod: 259200, // Voting period 3 days (seconds)
-  minVotes: 3, // Minimum votes
-  minVoterReputation: 100, // Minimum voter reputation
-  claimGracePeriod: 86400, // Grace period 1 day (seconds)
-}
+import { platformConfigArgs } from './platform-config.js'
 
 async function main() {
   // Setup provider
@@ -40,7 +30,7 @@ async function main() {
       feeBps: existingPlatform.feeBps,
       treasury: existingPlatform.treasury.toString(),
       taskCount: existingPlatform.taskCount.toString(),
-      isPaused: existingPlatform.isPaused,
+      pauseFlags: existingPlatform.pauseFlags,
     })
     return
   } catch (_err) {
@@ -56,14 +46,7 @@ async function main() {
   // Call initialize_platform
   try {
     const tx = await program.methods
-      .initializePlatform(
-        PLATFORM_CONFIG.feeBps,
-        new BN(PLATFORM_CONFIG.minBounty),
-        new BN(PLATFORM_CONFIG.votingPeriod),
-        PLATFORM_CONFIG.minVotes,
-        new BN(PLATFORM_CONFIG.minVoterReputation),
-        new BN(PLATFORM_CONFIG.claimGracePeriod)
-      )
+      .initializePlatform(platformConfigArgs() as any)
       .accounts({
         treasury: treasury,
         authority: provider.wallet.publicKey,
@@ -73,6 +56,13 @@ async function main() {
     console.log('✅ Platform initialized successfully!')
     console.log('Transaction signature:', tx)
 
+    // Create the (empty) arbitrator pool juries are drawn from
+    const poolTx = await program.methods
+      .initializeArbitratorPool()
+      .accounts({ payer: provider.wallet.publicKey })
+      .rpc()
+    console.log('✅ Arbitrator pool initialized:', poolTx)
+
     // Fetch and display platform config
     const platform = await program.account.platform.fetch(platformPda)
     console.log('\n📋 Platform config:')
@@ -82,10 +72,30 @@ async function main() {
     console.log('  - Min votes:', platform.disputeMinVotes)
     console.log('  - Min voter reputation:', platform.minVoterReputation.toString())
     console.log('  - Grace period:', platform.claimGracePeriod.toNumber(), 'seconds')
+    console.log('  - Review period:', platform.reviewPeriod.toNumber(), 'seconds')
+    console.log('  - Dispute fallback period:', platform.disputeFallbackPeriod.toNumber(), 'seconds')
+    console.log('  - Arbitrator stake:', platform.arbitratorStakeLamports.toNumber() / LAMPORTS_PER_SOL, 'SOL')
+    console.log('  - Arbitrator reward:', platform.arbitratorRewardBps, 'BPS of dispute fee')
+    console.log('  - Slash rate:', platform.slashBps, 'BPS of minority stake')
+    console.log('  - Appeal window:', platform.appealWindow.toNumber(), 'seconds')
+    console.log('  - Appeal bond:', platform.appealBondLamports.toNumber() / LAMPORTS_PER_SOL, 'SOL')
+    console.log('  - Max appeal rounds:', platform.maxAppealRounds)
+    console.log('  - Vote weight mode:', Object.keys(platform.voteWeightMode)[0])
+    console.log('  - Vote weight cap:', platform.voteWeightCap.toString())
+    console.log('  - Min dispute weight:', platform.disputeMinWeight.toString())
+    console.log('  - Jury size:', platform.jurySize)
+    console.log('  - Min arbitrator stake:', platform.minArbitratorStakeLamports.toNumber() / LAMPORTS_PER_SOL, 'SOL')
+    console.log('  - Arbitrator approval required:', platform.arbitratorApprovalRequired)
+    console.log('  - Arbitrator reputation reward:', platform.arbitratorRepReward.toString())
+    console.log('  - Arbitrator reputation penalty:', platform.arbitratorRepPenalty.toString())
+    console.log('  - Dispute bond:', platform.disputeBondLamports.toNumber() / LAMPORTS_PER_SOL, 'SOL')
+    console.log('  - Config timelock:', platform.configTimelock.toString(), 'seconds')
+    console.log('  - Emergency withdraw delay:', platform.emergencyWithdrawDelay.toString(), 's')
+    console.log('  - Emergency agent share:', platform.emergencyAgentShareBps, 'bps')
     console.log('  - Treasury:', platform.treasury.toString())
     console.log('  - Authority:', platform.authority.toString())
     console.log('  - Task count:', platform.taskCount.toString())
-    console.log('  - Paused:', platform.isPaused)
+    console.log('  - Pause flags:', platform.pauseFlags)
   } catch (err) {
     console.error('❌ Initialization failed:', err)
     throw err
@@ -0,0 +1,94 @@
+import { createHash } from 'node:crypto'
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, type Program } from '@coral-xyz/anchor'
+import { PublicKey } from '@solana/web3.js'
+import bs58 from 'bs58'
+import type { TaskEscrow } from '../target/types/task_escrow.js'
+import { platformConfigArgs } from './platform-config.js'
+
+// Account sizes (discriminator included) written by the first deployment
+const LEGACY_PLATFORM_SIZE = 133
+const LEGACY_TASK_SIZE = 263
+const LEGACY_AGENT_PROFILE_SIZE = 98
+const LEGACY_DISPUTE_SIZE = 128
+
+function discriminator(account: string): string {
+  return bs58.encode(createHash('sha256').update(`account:${account}`).digest().subarray(0, 8))
+}
+
+async function main() {
+  const provider = AnchorProvider.env()
+  anchor.setProvider(provider)
+
+  const program = anchor.workspace.TaskEscrow as Program<TaskEscrow>
+  const { connection } = provider
+
+  console.log('🚚 Migrating accounts to the current layout...')
+  console.log('Program ID:', program.programId.toString())
+
+  const legacyAccounts = (account: string, dataSize: number) =>
+    connection.getProgramAccounts(program.programId, {
+      filters: [{ memcmp: { offset: 0, bytes: discriminator(account) } }, { dataSize }],
+    })
+
+  // 1. Platform — authority only; everything else needs it migrated first
+  const [platformPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('platform')],
+    program.programId
+  )
+  const platformInfo = await connection.getAccountInfo(platformPda)
+  if (platformInfo?.data.length === LEGACY_PLATFORM_SIZE) {
+    const tx = await program.methods
+      .migratePlatform(platformConfigArgs() as any)
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc()
+    console.log('✅ Platform migrated:', tx)
+  } else {
+    console.log('Platform already migrated')
+  }
+
+  // 2. Tasks
+  const tasks = await legacyAccounts('Task', LEGACY_TASK_SIZE)
+  for (const { pubkey } of tasks) {
+    const tx = await program.methods
+      .migrateTask()
+      .accounts({ task: pubkey, payer: provider.wallet.publicKey })
+      .rpc()
+    console.log(`✅ Task ${pubkey.toString()} migrated:`, tx)
+  }
+
+  // 3. Agent profiles
+  const profiles = await legacyAccounts('AgentProfile', LEGACY_AGENT_PROFILE_SIZE)
+  for (const { pubkey } of profiles) {
+    const tx = await program.methods
+      .migrateAgentProfile()
+      .accounts({ agentProfile: pubkey, payer: provider.wallet.publicKey })
+      .rpc()
+    console.log(`✅ Agent profile ${pubkey.toString()} migrated:`, tx)
+  }
+
+  // 4. Disputes
+  const disputes = await legacyAccounts('Dispute', LEGACY_DISPUTE_SIZE)
+  for (const { pubkey } of disputes) {
+    const tx = await program.methods
+      .migrateDispute()
+      .accounts({ dispute: pubkey, payer: provider.wallet.publicKey })
+      .rpc()
+    console.log(`✅ Dispute ${pubkey.toString()} migrated:`, tx)
+  }
+
+  console.log(
+    `\n📋 Migrated ${tasks.length} tasks, ${profiles.length} agent profiles ` +
+      `and ${disputes.length} disputes`
+  )
+}
+
+main()
+  .then(() => {
+    console.log('\n🎉 Done!')
+    process.exit(0)
+  })
+  .catch((err) => {
+    console.error(err)
+    process.exit(1)
+  })
@@ -0,0 +1,65 @@
+import { LAMPORTS_PER_SOL } from '@solana/web3.js'
+import BN from 'bn.js'
+
+// Platform configuration
+export const PLATFORM_CONFIG = {
+  feeBps: 250, // 2.5% platform fee
+  minBounty: 0.01 * LAMPORTS_PER_SOL, // Minimum task bounty 0.01 SOL
+  votingPeriod: 259200, // Voting period 3 days (seconds)
+  minVotes: 3, // Minimum votes
+  minVoterReputation: 100, // Minimum voter reputation
+  claimGracePeriod: 86400, // Grace period 1 day (seconds)
+  reviewPeriod: 259200, // Auto-approve window 3 days (seconds)
+  disputeFallbackPeriod: 604800, // No-quorum fallback 7 days after voting (seconds)
+  arbitratorStake: 0.05 * LAMPORTS_PER_SOL, // Stake locked per arbitrator vote
+  arbitratorRewardBps: 4000, // 40% of the dispute fee goes to majority voters
+  slashBps: 5000, // Minority voters forfeit 50% of their stake
+  appealWindow: 172800, // Appeals accepted for 2 days after a round (seconds)
+  appealBond: 0.1 * LAMPORTS_PER_SOL, // Bond posted per appeal
+  maxAppealRounds: 1, // One appeal round per dispute
+  voteWeightMode: { sqrt: {} }, // Vote weight = sqrt(reputation)
+  voteWeightCap: 0, // Only used by the capped mode
+  disputeMinWeight: 30, // Revealed weight needed for quorum
+  jurySize: 5, // Jurors drawn from the arbitrator pool per dispute
+  minArbitratorStake: 0.1 * LAMPORTS_PER_SOL, // Stake locked by each registered arbitrator
+  arbitratorApprovalRequired: false, // Registered arbitrators may vote straight away
+  arbitratorRepReward: 5, // Reputation gained per vote with the final ruling
+  arbitratorRepPenalty: 10, // Reputation lost per vote against it (or left unrevealed)
+  disputeBond: 0.02 * LAMPORTS_PER_SOL, // Posted by whoever opens a dispute, lost if they lose it
+  configTimelock: 2 * 24 * 60 * 60, // 2 days notice before update_platform changes apply
+  emergencyWithdrawDelay: 14 * 24 * 60 * 60, // Withdrawals open 2 weeks into a settlement pause
+  emergencyAgentShareBps: 5000, // Agents with submitted work keep 50% of that milestone
+}
+
+/** `PLATFORM_CONFIG` as the program's `PlatformConfig` struct. */
+export function platformConfigArgs() {
+  const c = PLATFORM_CONFIG
+  return {
+    feeBps: c.feeBps,
+    minBountyLamports: new BN(c.minBounty),
+    disputeVotingPeriod: new BN(c.votingPeriod),
+    disputeMinVotes: c.minVotes,
+    minVoterReputation: new BN(c.minVoterReputation),
+    claimGracePeriod: new BN(c.claimGracePeriod),
+    reviewPeriod: new BN(c.reviewPeriod),
+    disputeFallbackPeriod: new BN(c.disputeFallbackPeriod),
+    arbitratorStakeLamports: new BN(c.arbitratorStake),
+    arbitratorRewardBps: c.arbitratorRewardBps,
+    slashBps: c.slashBps,
+    appealWindow: new BN(c.appealWindow),
+    appealBondLamports: new BN(c.appealBond),
+    maxAppealRounds: c.maxAppealRounds,
+    voteWeightMode: c.voteWeightMode,
+    voteWeightCap: new BN(c.voteWeightCap),
+    disputeMinWeight: new BN(c.disputeMinWeight),
+    jurySize: c.jurySize,
+    minArbitratorStakeLamports: new BN(c.minArbitratorStake),
+    arbitratorApprovalRequired: c.arbitratorApprovalRequired,
+    arbitratorRepReward: new BN(c.arbitratorRepReward),
+    arbitratorRepPenalty: new BN(c.arbitratorRepPenalty),
+    disputeBondLamports: new BN(c.disputeBond),
+    configTimelock: new BN(c.configTimelock),
+    emergencyWithdrawDelay: new BN(c.emergencyWithdrawDelay),
+    emergencyAgentShareBps: c.emergencyAgentShareBps,
+  }
+}
@@ -1,8 +1,31 @@
+import { createHash } from 'node:crypto'
 import { existsSync, readFileSync, writeFileSync } from 'node:fs'
 import { join } from 'node:path'
 import type { Program } from '@coral-xyz/anchor'
 import * as anchor from '@coral-xyz/anchor'
-import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from '@solana/web3.js'
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  getAccount,
+  getMint,
+  getMintLen,
+  getOrCreateAssociatedTokenAccount,
+  getTransferFeeAmount,
+  getTransferFeeConfig,
+  mintTo,
+} from '@solana/spl-token'
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+  SystemProgram,
+  Transaction,
+} from '@solana/web3.js'
 import BN from 'bn.js'
 import { expect } from 'chai'
 import type { TaskEscrow } from '../target/types/task_escrow.js'
@@ -15,6 +38,28 @@ function accs(obj: Record<string, unknown>): unknown {
   return obj
 }
 
+const RULING_INDEX: Record<string, number> = { pending: 0, creatorWins: 1, agentWins: 2, split: 3 }
+const VOTE_SALT = Buffer.alloc(32, 7)
+
+/**
+ * Commit-reveal vote commitment: sha256(ruling_u8 || split_pct_u8 || salt || voter),
+ * matching `vote_commitment` on-chain.
+ */
+function voteCommitment(
+  ruling: Record<string, unknown>,
+  voter: PublicKey,
+  splitPct = 0
+): number[] {
+  const [variant] = Object.keys(ruling)
+  return Array.from(
+    createHash('sha256')
+      .update(Buffer.from([RULING_INDEX[variant], splitPct]))
+      .update(VOTE_SALT)
+      .update(voter.toBuffer())
+      .digest()
+  )
+}
+
 function loadOrGenerateWallet(name: string): Keypair {
   const walletFile = join(process.cwd(), 'tests', 'test-wallets.json')
 
@@ -91,6 +136,226 @@ describe('task-escrow', () => {
   const MIN_VOTES = 2
   const MIN_VOTER_REPUTATION = 0 // allow any registered agent for tests
   const CLAIM_GRACE_PERIOD = 5 // 5 seconds grace period for testing
+  const REVIEW_PERIOD = 3 // 3 seconds review window for testing
+  const DISPUTE_FALLBACK_PERIOD = 5 // 5 seconds past voting before no-quorum fallback
+  const ARBITRATOR_STAKE = 0.01 * LAMPORTS_PER_SOL
+  const ARBITRATOR_REWARD_BPS = 5000 // half the dispute fee goes to majority voters
+  const SLASH_BPS = 5000 // minority voters lose half their stake
+  const APPEAL_WINDOW = 2 // 2 seconds after a round to appeal
+  const APPEAL_BOND = 0.05 * LAMPORTS_PER_SOL
+  const MAX_APPEAL_ROUNDS = 1
+  const VOTE_WEIGHT_MODE = { flat: {} } // one vote each (test voters have 0 reputation)
+  const VOTE_WEIGHT_CAP = 0
+  const DISPUTE_MIN_WEIGHT = 0
+  const JURY_SIZE = 0 // open voting, except in the jury tests
+  const JURY_DRAW_DELAY_SLOTS = 2 // slots between opening a dispute and its jury seed
+  const MIN_ARBITRATOR_STAKE = 0.01 * LAMPORTS_PER_SOL
+  const ARBITRATOR_APPROVAL_REQUIRED = false
+  const ARBITRATOR_REP_REWARD = 5
+  const ARBITRATOR_REP_PENALTY = 0 // keeps minority test voters above MIN_VOTER_REPUTATION
+  const DISPUTE_BOND = 0 // no bond, except in the dispute bond tests
+  const CONFIG_TIMELOCK = 0 // config changes apply as soon as they are cranked
+  const EMERGENCY_WITHDRAW_DELAY = 2 // 2 seconds of paused settlement before withdrawals
+  const EMERGENCY_AGENT_SHARE_BPS = 5000 // agents keep half of a submitted milestone
+  /** update_platform patch that leaves every field as it is */
+  const NO_CONFIG_CHANGES = {
+    feeBps: null,
+    minBountyLamports: null,
+    disputeVotingPeriod: null,
+    disputeMinVotes: null,
+    minVoterReputation: null,
+    claimGracePeriod: null,
+    reviewPeriod: null,
+    disputeFallbackPeriod: null,
+    arbitratorStakeLamports: null,
+    arbitratorRewardBps: null,
+    slashBps: null,
+    appealWindow: null,
+    appealBondLamports: null,
+    maxAppealRounds: null,
+    voteWeightMode: null,
+    voteWeightCap: null,
+    disputeMinWeight: null,
+    jurySize: null,
+    minArbitratorStakeLamports: null,
+    arbitratorApprovalRequired: null,
+    arbitratorRepReward: null,
+    arbitratorRepPenalty: null,
+    disputeBondLamports: null,
+    configTimelock: null,
+    emergencyWithdrawDelay: null,
+    emergencyAgentShareBps: null,
+    treasury: null,
+  }
+  const PLATFORM_CONFIG = {
+    feeBps: FEE_BPS,
+    minBountyLamports: new BN(MIN_BOUNTY),
+    disputeVotingPeriod: new BN(VOTING_PERIOD),
+    disputeMinVotes: MIN_VOTES,
+    minVoterReputation: new BN(MIN_VOTER_REPUTATION),
+    claimGracePeriod: new BN(CLAIM_GRACE_PERIOD),
+    reviewPeriod: new BN(REVIEW_PERIOD),
+    disputeFallbackPeriod: new BN(DISPUTE_FALLBACK_PERIOD),
+    arbitratorStakeLamports: new BN(ARBITRATOR_STAKE),
+    arbitratorRewardBps: ARBITRATOR_REWARD_BPS,
+    slashBps: SLASH_BPS,
+    appealWindow: new BN(APPEAL_WINDOW),
+    appealBondLamports: new BN(APPEAL_BOND),
+    maxAppealRounds: MAX_APPEAL_ROUNDS,
+    voteWeightMode: VOTE_WEIGHT_MODE as any,
+    voteWeightCap: new BN(VOTE_WEIGHT_CAP),
+    disputeMinWeight: new BN(DISPUTE_MIN_WEIGHT),
+    jurySize: JURY_SIZE,
+    minArbitratorStakeLamports: new BN(MIN_ARBITRATOR_STAKE),
+    arbitratorApprovalRequired: ARBITRATOR_APPROVAL_REQUIRED,
+    arbitratorRepReward: new BN(ARBITRATOR_REP_REWARD),
+    arbitratorRepPenalty: new BN(ARBITRATOR_REP_PENALTY),
+    disputeBondLamports: new BN(DISPUTE_BOND),
+    configTimelock: new BN(CONFIG_TIMELOCK),
+    emergencyWithdrawDelay: new BN(EMERGENCY_WITHDRAW_DELAY),
+    emergencyAgentShareBps: EMERGENCY_AGENT_SHARE_BPS,
+  }
+  const ALL_CATEGORIES = 0b1111111
+  // Platform.pause_flags bits
+  const PAUSE_TASK_CREATION = 1 << 0
+  const PAUSE_CLAIMING = 1 << 1
+  const PAUSE_SETTLEMENT = 1 << 2
+  const PAUSE_DISPUTES = 1 << 3
+  const PAUSE_ALL = 0b111111
+
+  /** Reveal a committed vote (only valid once the commit phase has ended). */
+  async function revealVote(
+    taskPda: PublicKey,
+    disputePda: PublicKey,
+    voter: Keypair,
+    ruling: Record<string, unknown>,
+    splitPct = 0
+  ) {
+    const [votePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+      program.programId
+    )
+    await program.methods
+      .revealVote(ruling as any, splitPct, Array.from(VOTE_SALT) as any)
+      .accounts(
+        accs({
+          task: taskPda,
+          dispute: disputePda,
+          vote: votePda,
+          voter: voter.publicKey,
+        })
+      )
+      .signers([voter])
+      .rpc()
+  }
+
+  /** Events emitted by a transaction sent with `rpc({ commitment: 'confirmed' })`. */
+  async function txEvents(signature: string) {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    })
+    const parser = new anchor.EventParser(program.programId, program.coder)
+    return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []))
+  }
+
+  /** The single event called `name` among `events`. */
+  function findEvent(events: { name: string; data: any }[], name: string) {
+    const matching = events.filter((e) => e.name === name)
+    expect(matching, `${name} events`).to.have.length(1)
+    return matching[0].data
+  }
+
+  /** Accounts for a dispute's token reward pool, and each voter's token account. */
+  interface RewardTokens {
+    accounts: Record<string, PublicKey>
+    tokenAccount: (voter: Keypair) => PublicKey
+  }
+
+  /** Settle a vote on a resolved dispute (returns stake ± reward/slash). */
+  async function settleVote(
+    disputePda: PublicKey,
+    voter: Keypair,
+    initiator: PublicKey,
+    rewards?: RewardTokens
+  ) {
+    const [votePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+      program.programId
+    )
+    await program.methods
+      .settleVote()
+      .accounts(
+        accs({
+          dispute: disputePda,
+          vote: votePda,
+          arbitrator: voter.publicKey,
+          initiator,
+          platform: platformPda,
+          treasury: treasury.publicKey,
+          caller: authority.publicKey,
+          ...(rewards && {
+            ...rewards.accounts,
+            arbitratorTokenAccount: rewards.tokenAccount(voter),
+          }),
+        })
+      )
+      .rpc()
+  }
+
+  /** Close several votes on one dispute in a single transaction. */
+  async function closeVotes(
+    disputePda: PublicKey,
+    voters: Keypair[],
+    initiator: PublicKey,
+    rewards?: RewardTokens
+  ) {
+    return program.methods
+      .closeVotes()
+      .accounts(
+        accs({
+          dispute: disputePda,
+          initiator,
+          platform: platformPda,
+          treasury: treasury.publicKey,
+          caller: authority.publicKey,
+          ...rewards?.accounts,
+        })
+      )
+      .remainingAccounts(
+        voters.flatMap((voter) => [
+          {
+            pubkey: PublicKey.findProgramAddressSync(
+              [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+              program.programId
+            )[0],
+            isWritable: true,
+            isSigner: false,
+          },
+          { pubkey: voter.publicKey, isWritable: true, isSigner: false },
+          {
+            pubkey: PublicKey.findProgramAddressSync(
+              [Buffer.from('agent'), voter.publicKey.toBuffer()],
+              program.programId
+            )[0],
+            isWritable: true,
+            isSigner: false,
+          },
+          {
+            pubkey: PublicKey.findProgramAddressSync(
+              [Buffer.from('arbitrator'), voter.publicKey.toBuffer()],
+              program.programId
+            )[0],
+            isWritable: true,
+            isSigner: false,
+          },
+          ...(rewards
+            ? [{ pubkey: rewards.tokenAccount(voter), isWritable: true, isSigner: false }]
+            : []),
+        ])
+      )
+      .rpc()
+  }
 
   before(async () => {
     // Derive platform PDA
@@ -153,16 +418,28 @@ describe('task-escrow', () => {
 
   // ─── Platform ──────────────────────────────────────────────
 
+  it('rejects initializing the platform with an invalid config', async () => {
+    try {
+      await program.methods
+        .initializePlatform({ ...PLATFORM_CONFIG, disputeMinVotes: 0 })
+        .accounts(
+          accs({
+            platform: platformPda,
+            treasury: treasury.publicKey,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .rpc()
+      expect.fail('Should have thrown InvalidConfig')
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('InvalidConfig')
+    }
+  })
+
   it('initializes the platform', async () => {
     await program.methods
-      .initializePlatform(
-        FEE_BPS,
-        new BN(MIN_BOUNTY),
-        new BN(VOTING_PERIOD),
-        MIN_VOTES,
-        new BN(MIN_VOTER_REPUTATION),
-        new BN(CLAIM_GRACE_PERIOD)
-      )
+      .initializePlatform(PLATFORM_CONFIG)
       .accounts(
         accs({
           platform: platformPda,
@@ -226,6 +503,56 @@ describe('task-escrow', () => {
     expect(profile.reputationScore.toNumber()).to.equal(0)
   })
 
+  it('registers voters as arbitrators', async () => {
+    const [arbitratorPoolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('arbitrator_pool')],
+      program.programId
+    )
+    await program.methods
+      .initializeArbitratorPool()
+      .accounts(
+        accs({
+          arbitratorPool: arbitratorPoolPda,
+          platform: platformPda,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+      )
+      .rpc()
+
+    for (const [voter, profilePda] of [
+      [voter1, voter1ProfilePda],
+      [voter2, voter2ProfilePda],
+      [voter3, voter3ProfilePda],
+    ] as [Keypair, PublicKey][]) {
+      await program.methods
+        .registerArbitrator(ALL_CATEGORIES, new BN(MIN_ARBITRATOR_STAKE))
+        .accounts(
+          accs({
+            arbitratorPool: arbitratorPoolPda,
+            platform: platformPda,
+            agentProfile: profilePda,
+            authority: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([voter])
+        .rpc()
+    }
+
+    const [arbitratorPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('arbitrator'), voter1.publicKey.toBuffer()],
+      program.programId
+    )
+    const arbitrator = await program.account.arbitrator.fetch(arbitratorPda)
+    expect(arbitrator.categories).to.equal(ALL_CATEGORIES)
+    expect(arbitrator.isApproved).to.equal(true)
+    expect(arbitrator.stakeLamports.toNumber()).to.equal(MIN_ARBITRATOR_STAKE)
+
+    const pool = await program.account.arbitratorPool.fetch(arbitratorPoolPda)
+    expect(pool.members).to.have.length(3)
+  })
+
   // ─── Task lifecycle: happy path ────────────────────────────
 
   describe('task lifecycle (happy path)', () => {
@@ -418,6 +745,184 @@ describe('task-escrow', () => {
     })
   })
 
+  // ─── Milestone tasks ───────────────────────────────────────
+
+  describe('milestone tasks', () => {
+    const milestoneA = 0.3 * LAMPORTS_PER_SOL
+    const milestoneB = 0.2 * LAMPORTS_PER_SOL
+    let taskPda: PublicKey
+
+    it('creates a task with two milestones', async () => {
+      const taskIndex = new BN(creatorTaskCount)
+
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+
+      const deadline = Math.floor(Date.now() / 1000) + 3600
+
+      await program.methods
+        .createMilestoneTask(
+          'Two-phase literature review',
+          Array.from(Buffer.alloc(32, 100)) as any,
+          new BN(milestoneA + milestoneB),
+          new BN(creatorTaskCount),
+          new BN(deadline),
+          new BN(0), // keep agent reputation unchanged for later assertions
+          [
+            { titleHash: Array.from(Buffer.alloc(32, 101)), amount: new BN(milestoneA), deadline: new BN(0) },
+            { titleHash: Array.from(Buffer.alloc(32, 102)), amount: new BN(milestoneB), deadline: new BN(0) },
+          ] as any
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      const task = await program.account.task.fetch(taskPda)
+      expect(task.milestones.length).to.equal(2)
+      expect(task.currentMilestone).to.equal(0)
+      expect(task.bountyLamports.toNumber()).to.equal(milestoneA + milestoneB)
+    })
+
+    it('releases only the first milestone on approval', async () => {
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 103)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+
+      await program.methods
+        .approveAndSettle()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      const fee = Math.floor((milestoneA * FEE_BPS) / 10000)
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      expect(agentAfter - agentBefore).to.equal(milestoneA - fee)
+
+      // Task stays open for the next milestone
+      const task = await program.account.task.fetch(taskPda)
+      expect(task.status).to.deep.include({ claimed: {} })
+      expect(task.currentMilestone).to.equal(1)
+      expect(task.releasedLamports.toNumber()).to.equal(milestoneA)
+    })
+
+    it('closes the task when the final milestone is approved', async () => {
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 104)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+
+      await program.methods
+        .approveAndSettle()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      const fee = Math.floor((milestoneB * FEE_BPS) / 10000)
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      expect(agentAfter - agentBefore).to.equal(milestoneB - fee)
+
+      const taskInfo = await provider.connection.getAccountInfo(taskPda)
+      expect(taskInfo).to.be.null
+    })
+
+    it('rejects milestones that do not sum to the bounty', async () => {
+      const idx = new BN(creatorTaskCount)
+      const [badTaskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), idx.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+
+      try {
+        await program.methods
+          .createMilestoneTask(
+            'Mismatched milestones',
+            Array.from(Buffer.alloc(32, 105)) as any,
+            new BN(milestoneA + milestoneB),
+            new BN(creatorTaskCount),
+            new BN(Math.floor(Date.now() / 1000) + 3600),
+            new BN(10),
+            [{ titleHash: Array.from(Buffer.alloc(32, 106)), amount: new BN(milestoneA), deadline: new BN(0) }] as any
+          )
+          .accounts(
+            accs({
+              task: badTaskPda,
+              platform: platformPda,
+              creatorCounter: creatorCounterPda,
+              creator: creator.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+          )
+          .signers([creator])
+          .rpc()
+        expect.fail('Should have thrown MilestoneSumMismatch')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('MilestoneSumMismatch')
+      }
+    })
+  })
+
   // ─── Template flow ─────────────────────────────────────────
 
   describe('task templates', () => {
@@ -596,6 +1101,24 @@ describe('task-escrow', () => {
       const dispute = await program.account.dispute.fetch(disputePda)
       expect(dispute.status).to.deep.include({ open: {} })
 
+      // Creator puts their side on-chain
+      await program.methods
+        .submitEvidence(Array.from(Buffer.alloc(32, 9)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            submitter: creator.publicKey,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      const { evidence } = await program.account.dispute.fetch(disputePda)
+      expect(evidence).to.have.length(1)
+      expect(evidence[0].submitter.toBase58()).to.equal(creator.publicKey.toBase58())
+      expect(evidence[0].submittedAt.toNumber()).to.be.greaterThan(0)
+
       // 4. Voters cast votes (2 for agent, 1 for creator)
       for (const [voter, ruling, voterProfile] of [
         [voter1, { agentWins: {} }, voter1ProfilePda],
@@ -608,7 +1131,7 @@ describe('task-escrow', () => {
         )
 
         await program.methods
-          .castVote(ruling as any)
+          .castVote(voteCommitment(ruling, (voter as Keypair).publicKey) as any)
           .accounts(
             accs({
               task: taskPda,
@@ -624,33 +1147,68 @@ describe('task-escrow', () => {
           .rpc()
       }
 
-      // 5. Wait for voting period to end
+      // 5. Wait for the commit phase to end, then reveal
       await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
 
-      // 6. Resolve dispute
-      const agentBefore = await provider.connection.getBalance(agent.publicKey)
-
-      await program.methods
-        .resolveDispute()
-        .accounts(
-          accs({
-            dispute: disputePda,
+      // Only the creator and agent can add evidence
+      try {
+        await program.methods
+          .submitEvidence(Array.from(Buffer.alloc(32, 10)) as any)
+          .accounts(
+            accs({
+              task: taskPda,
+              dispute: disputePda,
+              submitter: voter1.publicKey,
+            })
+          )
+          .signers([voter1])
+          .rpc()
+        expect.fail('Should have thrown NotTaskParty')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotTaskParty')
+      }
+
+      // A reveal that doesn't match the commitment is refused
+      try {
+        await revealVote(taskPda, disputePda, voter3, { agentWins: {} })
+        expect.fail('Should have thrown CommitmentMismatch')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('CommitmentMismatch')
+      }
+
+      await revealVote(taskPda, disputePda, voter1, { agentWins: {} })
+      await revealVote(taskPda, disputePda, voter2, { agentWins: {} })
+      await revealVote(taskPda, disputePda, voter3, { creatorWins: {} })
+
+      // Wait for the reveal phase and the unused appeal window to end
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + APPEAL_WINDOW + 1) * 1000))
+
+      // 6. Resolve dispute
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+
+      await program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
             task: taskPda,
             platform: platformPda,
             creator: creator.publicKey,
             agent: agent.publicKey,
             agentProfile: agentProfilePda,
             treasury: treasury.publicKey,
+            initiator: agent.publicKey,
             caller: authority.publicKey,
           })
         )
         .rpc()
 
-      // Both dispute and task PDAs should be closed after resolution
-      const disputeInfo = await provider.connection.getAccountInfo(disputePda)
-      expect(disputeInfo).to.be.null
+      // Task PDA is closed; the dispute stays open until votes are settled
       const taskInfo = await provider.connection.getAccountInfo(taskPda)
       expect(taskInfo).to.be.null
+      const resolved = await program.account.dispute.fetch(disputePda)
+      expect(resolved.status).to.deep.include({ resolved: {} })
+      expect(resolved.votesCommitted).to.equal(3)
 
       // Verify agent profile updated after dispute
       const profile = await program.account.agentProfile.fetch(agentProfilePda)
@@ -662,6 +1220,526 @@ describe('task-escrow', () => {
       const agentAfter = await provider.connection.getBalance(agent.publicKey)
       expect(agentAfter).to.be.greaterThan(agentBefore)
     })
+
+    it('settles arbitrator stakes: majority rewarded, minority slashed', async () => {
+      const { rewardPoolLamports } = await program.account.dispute.fetch(disputePda)
+      expect(rewardPoolLamports.toNumber()).to.be.greaterThan(0)
+
+      // Majority voter gets stake back plus half the pool (2 winners)
+      const voter1Before = await provider.connection.getBalance(voter1.publicKey)
+      await settleVote(disputePda, voter1, agent.publicKey)
+      const voter1After = await provider.connection.getBalance(voter1.publicKey)
+      expect(voter1After - voter1Before).to.be.greaterThan(
+        ARBITRATOR_STAKE + Math.floor(rewardPoolLamports.toNumber() / 2) - 1
+      )
+
+      await settleVote(disputePda, voter2, agent.publicKey)
+
+      // Minority voter forfeits SLASH_BPS of their stake to the treasury
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey)
+      await settleVote(disputePda, voter3, agent.publicKey)
+      const treasuryAfter = await provider.connection.getBalance(treasury.publicKey)
+      expect(treasuryAfter - treasuryBefore).to.equal(
+        Math.floor((ARBITRATOR_STAKE * SLASH_BPS) / 10000)
+      )
+
+      // Dispute closes once every vote is settled
+      const disputeInfo = await provider.connection.getAccountInfo(disputePda)
+      expect(disputeInfo).to.be.null
+    })
+
+    it('records arbitration stats and reputation on voter profiles', async () => {
+      // First dispute each voter judged: voter1 and voter2 with the ruling, voter3 against
+      const majority = await program.account.agentProfile.fetch(voter1ProfilePda)
+      expect(majority.disputesJudged.toNumber()).to.equal(1)
+      expect(majority.majorityVotes.toNumber()).to.equal(1)
+      expect(majority.reputationScore.toNumber()).to.equal(ARBITRATOR_REP_REWARD)
+
+      const minority = await program.account.agentProfile.fetch(voter3ProfilePda)
+      expect(minority.disputesJudged.toNumber()).to.equal(1)
+      expect(minority.majorityVotes.toNumber()).to.equal(0)
+      expect(minority.reputationScore.toNumber()).to.equal(-ARBITRATOR_REP_PENALTY)
+    })
+  })
+
+  // ─── Token bounties ────────────────────────────────────────
+
+  describe('token bounties', () => {
+    const payer = (provider.wallet as anchor.Wallet).payer
+    const DECIMALS = 6
+    const TRANSFER_FEE_BPS = 100 // 1% Token-2022 transfer fee
+    const bounty = 1_000_000
+
+    interface TokenSetup {
+      mint: PublicKey
+      tokenProgram: PublicKey
+      creatorAta: PublicKey
+      agentAta: PublicKey
+      treasuryAta: PublicKey
+    }
+    let spl: TokenSetup
+    let feeToken: TokenSetup
+
+    /** Token-2022 transfer fee on `amount`, rounded up like the token program. */
+    const transferFee = (amount: number) => Math.ceil((amount * TRANSFER_FEE_BPS) / 10000)
+    const platformFee = (amount: number) => Math.floor((amount * FEE_BPS) / 10000)
+
+    async function tokenBalance(account: PublicKey, tokenProgram: PublicKey) {
+      const { amount } = await getAccount(provider.connection, account, undefined, tokenProgram)
+      return Number(amount)
+    }
+
+    async function mintWithheld(mint: PublicKey) {
+      const info = await getMint(provider.connection, mint, undefined, TOKEN_2022_PROGRAM_ID)
+      return Number(getTransferFeeConfig(info)!.withheldAmount)
+    }
+
+    /** Token accounts for the creator, agent and treasury; funds the creator. */
+    async function setupToken(mint: PublicKey, tokenProgram: PublicKey): Promise<TokenSetup> {
+      const atas: PublicKey[] = []
+      for (const owner of [creator, agent, treasury]) {
+        const ata = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          payer,
+          mint,
+          owner.publicKey,
+          false,
+          undefined,
+          undefined,
+          tokenProgram
+        )
+        atas.push(ata.address)
+      }
+      await mintTo(
+        provider.connection,
+        payer,
+        mint,
+        atas[0],
+        payer,
+        20 * bounty,
+        [],
+        undefined,
+        tokenProgram
+      )
+      return { mint, tokenProgram, creatorAta: atas[0], agentAta: atas[1], treasuryAta: atas[2] }
+    }
+
+    async function createTokenTask(t: TokenSetup, title: string, deadline?: number) {
+      const taskIndex = new BN(creatorTaskCount)
+      const [taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+      const [vaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vault'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .createTask(
+          title,
+          Array.from(Buffer.alloc(32, 200)) as any,
+          new BN(bounty),
+          taskIndex,
+          new BN(deadline ?? Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            bountyMint: t.mint,
+            vault: vaultPda,
+            creatorTokenAccount: t.creatorAta,
+            tokenProgram: t.tokenProgram,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+      return { taskPda, vaultPda }
+    }
+
+    async function claimAndSubmit(taskPda: PublicKey) {
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 201)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+    }
+
+    async function approve(t: TokenSetup, taskPda: PublicKey, vaultPda: PublicKey) {
+      await program.methods
+        .approveAndSettle()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            bountyMint: t.mint,
+            vault: vaultPda,
+            agentTokenAccount: t.agentAta,
+            treasuryTokenAccount: t.treasuryAta,
+            tokenProgram: t.tokenProgram,
+          })
+        )
+        .signers([creator])
+        .rpc()
+    }
+
+    before(async () => {
+      const splMint = await createMint(
+        provider.connection,
+        payer,
+        payer.publicKey,
+        null,
+        DECIMALS,
+        undefined,
+        undefined,
+        TOKEN_PROGRAM_ID
+      )
+      spl = await setupToken(splMint, TOKEN_PROGRAM_ID)
+
+      // Token-2022 mint charging a transfer fee on every transfer
+      const feeMint = Keypair.generate()
+      const space = getMintLen([ExtensionType.TransferFeeConfig])
+      const lamports = await provider.connection.getMinimumBalanceForRentExemption(space)
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: feeMint.publicKey,
+            space,
+            lamports,
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeTransferFeeConfigInstruction(
+            feeMint.publicKey,
+            payer.publicKey,
+            payer.publicKey,
+            TRANSFER_FEE_BPS,
+            BigInt(bounty),
+            TOKEN_2022_PROGRAM_ID
+          ),
+          createInitializeMintInstruction(
+            feeMint.publicKey,
+            DECIMALS,
+            payer.publicKey,
+            null,
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [feeMint]
+      )
+      feeToken = await setupToken(feeMint.publicKey, TOKEN_2022_PROGRAM_ID)
+    })
+
+    it('escrows an SPL bounty in the task vault and settles it to the agent', async () => {
+      const { taskPda, vaultPda } = await createTokenTask(spl, 'SPL bounty task')
+
+      const task = await program.account.task.fetch(taskPda)
+      expect(task.bountyMint.toBase58()).to.equal(spl.mint.toBase58())
+      expect(task.bountyLamports.toNumber()).to.equal(bounty)
+      expect(await tokenBalance(vaultPda, TOKEN_PROGRAM_ID)).to.equal(bounty)
+
+      await claimAndSubmit(taskPda)
+
+      const agentBefore = await tokenBalance(spl.agentAta, TOKEN_PROGRAM_ID)
+      const treasuryBefore = await tokenBalance(spl.treasuryAta, TOKEN_PROGRAM_ID)
+      await approve(spl, taskPda, vaultPda)
+
+      const fee = platformFee(bounty)
+      expect((await tokenBalance(spl.agentAta, TOKEN_PROGRAM_ID)) - agentBefore).to.equal(
+        bounty - fee
+      )
+      expect((await tokenBalance(spl.treasuryAta, TOKEN_PROGRAM_ID)) - treasuryBefore).to.equal(
+        fee
+      )
+      expect(await provider.connection.getAccountInfo(vaultPda)).to.be.null
+      expect(await provider.connection.getAccountInfo(taskPda)).to.be.null
+    })
+
+    it('refunds a cancelled SPL bounty along with tokens sent to the vault', async () => {
+      const { taskPda, vaultPda } = await createTokenTask(spl, 'SPL cancel task')
+
+      // Anyone can top up the vault; it must not block closing it
+      const donation = 1
+      await mintTo(
+        provider.connection,
+        payer,
+        spl.mint,
+        vaultPda,
+        payer,
+        donation,
+        [],
+        undefined,
+        TOKEN_PROGRAM_ID
+      )
+
+      const creatorBefore = await tokenBalance(spl.creatorAta, TOKEN_PROGRAM_ID)
+      await program.methods
+        .cancelTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            creator: creator.publicKey,
+            bountyMint: spl.mint,
+            vault: vaultPda,
+            creatorTokenAccount: spl.creatorAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      expect((await tokenBalance(spl.creatorAta, TOKEN_PROGRAM_ID)) - creatorBefore).to.equal(
+        bounty + donation
+      )
+      expect(await provider.connection.getAccountInfo(vaultPda)).to.be.null
+      expect(await provider.connection.getAccountInfo(taskPda)).to.be.null
+    })
+
+    it('expires an SPL bounty back to the creator', async () => {
+      const { taskPda, vaultPda } = await createTokenTask(
+        spl,
+        'SPL expiring task',
+        Math.floor(Date.now() / 1000) + 3
+      )
+      await new Promise((resolve) => setTimeout(resolve, 5000))
+
+      const creatorBefore = await tokenBalance(spl.creatorAta, TOKEN_PROGRAM_ID)
+      await program.methods
+        .expireTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            creator: creator.publicKey,
+            platform: platformPda,
+            caller: authority.publicKey,
+            bountyMint: spl.mint,
+            vault: vaultPda,
+            creatorTokenAccount: spl.creatorAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+        )
+        .rpc()
+
+      expect((await tokenBalance(spl.creatorAta, TOKEN_PROGRAM_ID)) - creatorBefore).to.equal(
+        bounty
+      )
+      expect(await provider.connection.getAccountInfo(vaultPda)).to.be.null
+      expect(await provider.connection.getAccountInfo(taskPda)).to.be.null
+    })
+
+    it('settles a Token-2022 bounty net of transfer fees', async () => {
+      const { taskPda, vaultPda } = await createTokenTask(feeToken, 'Token-2022 bounty task')
+
+      // The bounty is what the vault actually received
+      const depositFee = transferFee(bounty)
+      const escrowed = bounty - depositFee
+      const task = await program.account.task.fetch(taskPda)
+      expect(task.bountyLamports.toNumber()).to.equal(escrowed)
+      const vault = await getAccount(
+        provider.connection,
+        vaultPda,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      )
+      expect(Number(vault.amount)).to.equal(escrowed)
+      expect(Number(getTransferFeeAmount(vault)!.withheldAmount)).to.equal(depositFee)
+
+      await claimAndSubmit(taskPda)
+
+      const agentBefore = await tokenBalance(feeToken.agentAta, TOKEN_2022_PROGRAM_ID)
+      const treasuryBefore = await tokenBalance(feeToken.treasuryAta, TOKEN_2022_PROGRAM_ID)
+      const withheldBefore = await mintWithheld(feeToken.mint)
+      await approve(feeToken, taskPda, vaultPda)
+
+      const fee = platformFee(escrowed)
+      const payout = escrowed - fee
+      expect(
+        (await tokenBalance(feeToken.agentAta, TOKEN_2022_PROGRAM_ID)) - agentBefore
+      ).to.equal(payout - transferFee(payout))
+      expect(
+        (await tokenBalance(feeToken.treasuryAta, TOKEN_2022_PROGRAM_ID)) - treasuryBefore
+      ).to.equal(fee - transferFee(fee))
+      // The vault's withheld deposit fee moves to the mint so the vault can close
+      expect((await mintWithheld(feeToken.mint)) - withheldBefore).to.equal(depositFee)
+      expect(await provider.connection.getAccountInfo(vaultPda)).to.be.null
+      expect(await provider.connection.getAccountInfo(taskPda)).to.be.null
+    })
+
+    it('resolves a disputed Token-2022 bounty in tokens', async () => {
+      const { taskPda, vaultPda } = await createTokenTask(feeToken, 'Token-2022 disputed task')
+      const [disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+      await claimAndSubmit(taskPda)
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 202)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 203)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      for (const [voter, voterProfile] of [
+        [voter1, voter1ProfilePda],
+        [voter2, voter2ProfilePda],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+          program.programId
+        )
+        await program.methods
+          .castVote(voteCommitment({ agentWins: {} }, voter.publicKey) as any)
+          .accounts(
+            accs({
+              task: taskPda,
+              dispute: disputePda,
+              platform: platformPda,
+              vote: votePda,
+              voterProfile,
+              voter: voter.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+          )
+          .signers([voter])
+          .rpc()
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+      await revealVote(taskPda, disputePda, voter1, { agentWins: {} })
+      await revealVote(taskPda, disputePda, voter2, { agentWins: {} })
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + APPEAL_WINDOW + 1) * 1000))
+
+      const escrowed = bounty - transferFee(bounty)
+      const agentBefore = await tokenBalance(feeToken.agentAta, TOKEN_2022_PROGRAM_ID)
+      const treasuryBefore = await tokenBalance(feeToken.treasuryAta, TOKEN_2022_PROGRAM_ID)
+      const withheldBefore = await mintWithheld(feeToken.mint)
+      const [rewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('reward_vault'), disputePda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: agent.publicKey,
+            caller: authority.publicKey,
+            bountyMint: feeToken.mint,
+            vault: vaultPda,
+            creatorTokenAccount: feeToken.creatorAta,
+            agentTokenAccount: feeToken.agentAta,
+            treasuryTokenAccount: feeToken.treasuryAta,
+            rewardVault: rewardVaultPda,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .rpc()
+
+      const fee = platformFee(escrowed)
+      const payout = escrowed - fee
+      // The majority's share of the fee is held in the dispute's reward vault
+      const rewardCut = Math.floor((fee * ARBITRATOR_REWARD_BPS) / 10000)
+      const treasuryShare = fee - rewardCut
+      expect(
+        (await tokenBalance(feeToken.agentAta, TOKEN_2022_PROGRAM_ID)) - agentBefore
+      ).to.equal(payout - transferFee(payout))
+      expect(
+        (await tokenBalance(feeToken.treasuryAta, TOKEN_2022_PROGRAM_ID)) - treasuryBefore
+      ).to.equal(treasuryShare - transferFee(treasuryShare))
+      expect((await mintWithheld(feeToken.mint)) - withheldBefore).to.equal(transferFee(bounty))
+      expect(await provider.connection.getAccountInfo(vaultPda)).to.be.null
+      expect(await provider.connection.getAccountInfo(taskPda)).to.be.null
+
+      const pool = rewardCut - transferFee(rewardCut)
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.rewardPoolLamports.toNumber()).to.equal(0)
+      expect(dispute.rewardPoolTokens.toNumber()).to.equal(pool)
+      expect(await tokenBalance(rewardVaultPda, TOKEN_2022_PROGRAM_ID)).to.equal(pool)
+
+      const voterAtas = new Map<string, PublicKey>()
+      for (const voter of [voter1, voter2]) {
+        const ata = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          payer,
+          feeToken.mint,
+          voter.publicKey,
+          false,
+          undefined,
+          undefined,
+          TOKEN_2022_PROGRAM_ID
+        )
+        voterAtas.set(voter.publicKey.toBase58(), ata.address)
+      }
+      const rewards = {
+        accounts: {
+          bountyMint: feeToken.mint,
+          rewardVault: rewardVaultPda,
+          treasuryTokenAccount: feeToken.treasuryAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        },
+        tokenAccount: (voter: Keypair) => voterAtas.get(voter.publicKey.toBase58())!,
+      }
+
+      // Equal weights: each majority voter gets half the pool, net of the transfer fee
+      const share = Math.floor(pool / 2)
+      const balanceBefore = (voter: Keypair) =>
+        tokenBalance(rewards.tokenAccount(voter), TOKEN_2022_PROGRAM_ID)
+      const voter1Before = await balanceBefore(voter1)
+      const voter2Before = await balanceBefore(voter2)
+      await settleVote(disputePda, voter1, agent.publicKey, rewards)
+      expect((await balanceBefore(voter1)) - voter1Before).to.equal(share - transferFee(share))
+
+      // The last vote closes the reward vault along with the dispute
+      await closeVotes(disputePda, [voter2], agent.publicKey, rewards)
+      expect((await balanceBefore(voter2)) - voter2Before).to.equal(share - transferFee(share))
+      expect(await provider.connection.getAccountInfo(rewardVaultPda)).to.be.null
+      expect(await provider.connection.getAccountInfo(disputePda)).to.be.null
+    })
   })
 
   // ─── Negative tests (error paths) ─────────────────────────
@@ -932,7 +2010,7 @@ describe('task-escrow', () => {
       }
     })
 
-    it('rejects cancel on claimed task (not open)', async () => {
+    it('rejects cancel on claimed task before its deadline', async () => {
       try {
         await program.methods
           .cancelTask()
@@ -944,9 +2022,9 @@ describe('task-escrow', () => {
           )
           .signers([creator])
           .rpc()
-        expect.fail('Should have thrown TaskNotOpen')
+        expect.fail('Should have thrown DeadlineNotReached')
       } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal('TaskNotOpen')
+        expect(err.error.errorCode.code).to.equal('DeadlineNotReached')
       }
     })
 
@@ -969,9 +2047,64 @@ describe('task-escrow', () => {
         expect(err.error.errorCode.code).to.equal('DeadlineNotReached')
       }
     })
-  })
-
-  // ─── Platform admin negative tests ─────────────────────────
+
+    it('rejects migrating accounts that already use the current layout', async () => {
+      try {
+        await program.methods
+          .migrateTask()
+          .accounts(
+            accs({
+              task: taskPda,
+              platform: platformPda,
+              payer: randomUser.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+          )
+          .signers([randomUser])
+          .rpc()
+        expect.fail('Should have thrown AccountAlreadyMigrated')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('AccountAlreadyMigrated')
+      }
+
+      try {
+        await program.methods
+          .migrateAgentProfile()
+          .accounts(
+            accs({
+              agentProfile: taskPda, // a task is not an agent profile
+              payer: randomUser.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+          )
+          .signers([randomUser])
+          .rpc()
+        expect.fail('Should have thrown InvalidLegacyAccount')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('InvalidLegacyAccount')
+      }
+
+      try {
+        await program.methods
+          .migrateDispute()
+          .accounts(
+            accs({
+              dispute: taskPda, // a task is not a dispute
+              platform: platformPda,
+              payer: randomUser.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+          )
+          .signers([randomUser])
+          .rpc()
+        expect.fail('Should have thrown InvalidLegacyAccount')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('InvalidLegacyAccount')
+      }
+    })
+  })
+
+  // ─── Platform admin negative tests ─────────────────────────
 
   describe('platform admin negative tests', () => {
     const nonAuthority = Keypair.generate()
@@ -990,7 +2123,7 @@ describe('task-escrow', () => {
     it('rejects pause by non-authority', async () => {
       try {
         await program.methods
-          .pausePlatform()
+          .pausePlatform(PAUSE_ALL)
           .accounts({
             platform: platformPda,
             authority: nonAuthority.publicKey,
@@ -1006,7 +2139,7 @@ describe('task-escrow', () => {
     it('rejects resume when not paused', async () => {
       try {
         await program.methods
-          .resumePlatform()
+          .resumePlatform(PAUSE_ALL)
           .accounts({
             platform: platformPda,
             authority: authority.publicKey,
@@ -1018,10 +2151,9 @@ describe('task-escrow', () => {
       }
     })
 
-    it('rejects task creation when platform is paused', async () => {
-      // Pause the platform
+    it('rejects task creation when task creation is paused', async () => {
       await program.methods
-        .pausePlatform()
+        .pausePlatform(PAUSE_TASK_CREATION)
         .accounts({
           platform: platformPda,
           authority: authority.publicKey,
@@ -1029,7 +2161,7 @@ describe('task-escrow', () => {
         .rpc()
 
       const platform = await program.account.platform.fetch(platformPda)
-      expect(platform.isPaused).to.be.true
+      expect(platform.pauseFlags).to.equal(PAUSE_TASK_CREATION)
 
       const idx = new BN(creatorTaskCount)
       const [taskPda] = PublicKey.findProgramAddressSync(
@@ -1065,7 +2197,7 @@ describe('task-escrow', () => {
 
       // Resume the platform for subsequent tests
       await program.methods
-        .resumePlatform()
+        .resumePlatform(PAUSE_TASK_CREATION)
         .accounts({
           platform: platformPda,
           authority: authority.publicKey,
@@ -1075,7 +2207,7 @@ describe('task-escrow', () => {
 
     it('rejects double-pause', async () => {
       await program.methods
-        .pausePlatform()
+        .pausePlatform(PAUSE_ALL)
         .accounts({
           platform: platformPda,
           authority: authority.publicKey,
@@ -1084,7 +2216,7 @@ describe('task-escrow', () => {
 
       try {
         await program.methods
-          .pausePlatform()
+          .pausePlatform(PAUSE_CLAIMING)
           .accounts({
             platform: platformPda,
             authority: authority.publicKey,
@@ -1097,7 +2229,7 @@ describe('task-escrow', () => {
 
       // Clean up: resume
       await program.methods
-        .resumePlatform()
+        .resumePlatform(PAUSE_ALL)
         .accounts({
           platform: platformPda,
           authority: authority.publicKey,
@@ -1105,18 +2237,118 @@ describe('task-escrow', () => {
         .rpc()
     })
 
-    it('rejects update_platform with invalid fee (> 50%)', async () => {
+    it('emits PlatformPaused and PlatformResumed with the resulting flags', async () => {
+      let sig = await program.methods
+        .pausePlatform(PAUSE_CLAIMING)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc({ commitment: 'confirmed' })
+      const paused = findEvent(await txEvents(sig), 'platformPaused')
+      expect(paused.pausedBy.toBase58()).to.equal(authority.publicKey.toBase58())
+      expect(paused.flags).to.equal(PAUSE_CLAIMING)
+      expect(paused.pauseFlags).to.equal(PAUSE_CLAIMING)
+
+      sig = await program.methods
+        .resumePlatform(PAUSE_CLAIMING)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc({ commitment: 'confirmed' })
+      const resumed = findEvent(await txEvents(sig), 'platformResumed')
+      expect(resumed.resumedBy.toBase58()).to.equal(authority.publicKey.toBase58())
+      expect(resumed.flags).to.equal(PAUSE_CLAIMING)
+      expect(resumed.pauseFlags).to.equal(0)
+    })
+
+    it('rejects empty or unknown pause flags', async () => {
+      for (const flags of [0, 1 << 6]) {
+        try {
+          await program.methods
+            .pausePlatform(flags)
+            .accounts({ platform: platformPda, authority: authority.publicKey })
+            .rpc()
+          expect.fail('Should have thrown InvalidPauseFlags')
+        } catch (err: any) {
+          expect(err.error.errorCode.code).to.equal('InvalidPauseFlags')
+        }
+      }
+    })
+
+    it('freezes only the paused class', async () => {
+      await program.methods
+        .pausePlatform(PAUSE_CLAIMING)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+
+      // Task creation still works...
+      const [taskPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('task'),
+          creator.publicKey.toBuffer(),
+          new BN(creatorTaskCount).toArrayLike(Buffer, 'le', 8),
+        ],
+        program.programId
+      )
+      await program.methods
+        .createTask(
+          'Claiming paused',
+          Array.from(Buffer.alloc(32, 21)) as any,
+          new BN(0.1 * LAMPORTS_PER_SOL),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+      creatorTaskCount++
+
+      // ...but claiming does not
       try {
         await program.methods
-          .updatePlatform(
-            6000, // > 3001 (50%)
-            new BN(MIN_BOUNTY),
-            new BN(VOTING_PERIOD),
-            MIN_VOTES,
-            new BN(MIN_VOTER_REPUTATION),
-            new BN(CLAIM_GRACE_PERIOD),
-            treasury.publicKey
+          .claimTask()
+          .accounts(
+            accs({
+              task: taskPda,
+              platform: platformPda,
+              agentProfile: agentProfilePda,
+              agent: agent.publicKey,
+            })
           )
+          .signers([agent])
+          .rpc()
+        expect.fail('Should have thrown PlatformPaused')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('PlatformPaused')
+      }
+
+      // The creator can still take the bounty back
+      await program.methods
+        .cancelTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            creator: creator.publicKey,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      await program.methods
+        .resumePlatform(PAUSE_CLAIMING)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+    })
+
+    it('rejects update_platform with invalid fee (> 50%)', async () => {
+      try {
+        await program.methods
+          .updatePlatform({ ...NO_CONFIG_CHANGES, feeBps: 6000 } as any) // > 3001 (50%)
           .accounts({
             platform: platformPda,
             authority: authority.publicKey,
@@ -1127,6 +2359,45 @@ describe('task-escrow', () => {
         expect(err.error.errorCode.code).to.equal('InvalidFee')
       }
     })
+
+    it('rejects capped vote weighting without a cap', async () => {
+      try {
+        await program.methods
+          .updatePlatform({
+            ...NO_CONFIG_CHANGES,
+            voteWeightMode: { capped: {} },
+            voteWeightCap: new BN(0),
+          } as any)
+          .accounts({
+            platform: platformPda,
+            authority: authority.publicKey,
+          })
+          .rpc()
+        expect.fail('Should have thrown InvalidConfig')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('InvalidConfig')
+      }
+    })
+
+    it('rejects a jury that outgrows the juror seats over its appeal rounds', async () => {
+      // 16 jurors, then 32 on appeal: 48 seats against MAX_JURORS = 32
+      try {
+        await program.methods
+          .updatePlatform({
+            ...NO_CONFIG_CHANGES,
+            jurySize: 16,
+            maxAppealRounds: MAX_APPEAL_ROUNDS,
+          } as any)
+          .accounts({
+            platform: platformPda,
+            authority: authority.publicKey,
+          })
+          .rpc()
+        expect.fail('Should have thrown InvalidConfig')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('InvalidConfig')
+      }
+    })
   })
 
   // ─── Dispute negative tests ────────────────────────────────
@@ -1314,30 +2585,30 @@ describe('task-escrow', () => {
     })
   })
 
-  // ─── MAX_REJECTIONS auto-dispute ───────────────────────────
+  // ─── Missed deadlines ──────────────────────────────────────
 
-  describe('auto-dispute after MAX_REJECTIONS (3)', () => {
-    let taskPda: PublicKey
+  describe('missed deadlines on claimed tasks', () => {
+    const bounty = 0.2 * LAMPORTS_PER_SOL
+    let silentTaskPda: PublicKey
+    let reclaimTaskPda: PublicKey
+    let lateTaskPda: PublicKey
 
-    it('auto-transitions to Disputed after 3 rejections', async () => {
+    /** Create a task with a 6-second deadline and have the agent claim it. */
+    async function createClaimedTask(title: string, seed: number): Promise<PublicKey> {
       const taskIndex = new BN(creatorTaskCount)
-      const bounty = 0.5 * LAMPORTS_PER_SOL
-
-      ;[taskPda] = PublicKey.findProgramAddressSync(
+      const [taskPda] = PublicKey.findProgramAddressSync(
         [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
         program.programId
       )
 
-      const deadline = Math.floor(Date.now() / 1000) + 3600
-
       await program.methods
         .createTask(
-          'Multi-reject task',
-          Array.from(Buffer.alloc(32, 50)) as any,
+          title,
+          Array.from(Buffer.alloc(32, seed)) as any,
           new BN(bounty),
           new BN(creatorTaskCount),
-          new BN(deadline),
-          new BN(20)
+          new BN(Math.floor(Date.now() / 1000) + 6),
+          new BN(10)
         )
         .accounts(
           accs({
@@ -1353,7 +2624,6 @@ describe('task-escrow', () => {
 
       creatorTaskCount++
 
-      // Agent claims
       await program.methods
         .claimTask()
         .accounts(
@@ -1367,94 +2637,77 @@ describe('task-escrow', () => {
         .signers([agent])
         .rpc()
 
-      // Rejection cycle: submit → reject × 3
-      for (let i = 0; i < 3; i++) {
-        // Agent submits
-        await program.methods
-          .submitDeliverable(Array.from(Buffer.alloc(32, 51 + i)) as any)
-          .accounts(
-            accs({
-              task: taskPda,
-              platform: platformPda,
-              agent: agent.publicKey,
-            })
-          )
-          .signers([agent])
-          .rpc()
+      return taskPda
+    }
 
-        // Creator rejects
-        await program.methods
-          .rejectSubmission(Array.from(Buffer.alloc(32, 61 + i)) as any)
-          .accounts({ task: taskPda, creator: creator.publicKey })
-          .signers([creator])
-          .rpc()
-      }
+    before(async () => {
+      silentTaskPda = await createClaimedTask('Silent agent task', 120)
+      lateTaskPda = await createClaimedTask('Late submission task', 122)
+      reclaimTaskPda = await createClaimedTask('Reclaimed task', 121)
 
-      // After 3 rejections, status should be Disputed
-      const task = await program.account.task.fetch(taskPda)
-      expect(task.status).to.deep.include({ disputed: {} })
-      expect(task.rejectionCount).to.equal(3)
+      // Let every deadline pass, but not the reclaimed task's grace period
+      await new Promise((resolve) => setTimeout(resolve, 8000))
     })
-  })
-
-  // ─── Dispute: CreatorWins & Split rulings ──────────────────
-
-  describe('dispute rulings: CreatorWins and Split', () => {
-    // ── CreatorWins ──
-
-    it('resolves dispute with CreatorWins ruling (full refund)', async () => {
-      const taskIndex = new BN(creatorTaskCount)
-      const bounty = 1 * LAMPORTS_PER_SOL
 
-      const [taskPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+    it('lets the creator dispute a claimed task past its deadline', async () => {
+      const [disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), silentTaskPda.toBuffer()],
         program.programId
       )
 
-      const deadline = Math.floor(Date.now() / 1000) + 3600
-
-      // Create → Claim → Submit → Reject → OpenDispute
       await program.methods
-        .createTask(
-          'CreatorWins dispute task',
-          Array.from(Buffer.alloc(32, 70)) as any,
-          new BN(bounty),
-          new BN(creatorTaskCount),
-          new BN(deadline),
-          new BN(50)
-        )
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 123)) as any)
         .accounts(
           accs({
-            task: taskPda,
-            platform: platformPda,
-            creatorCounter: creatorCounterPda,
-            creator: creator.publicKey,
+            task: silentTaskPda,
+            dispute: disputePda,
+            initiator: creator.publicKey,
             systemProgram: SystemProgram.programId,
           })
         )
         .signers([creator])
         .rpc()
 
-      creatorTaskCount++
+      const task = await program.account.task.fetch(silentTaskPda)
+      expect(task.status).to.deep.include({ disputed: {} })
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.reason).to.deep.include({ deadlineMissed: {} })
+    })
+
+    it('lets the creator reclaim a claimed task once its grace period is over', async () => {
+      try {
+        await program.methods
+          .cancelTask()
+          .accounts(accs({ task: reclaimTaskPda, creator: creator.publicKey }))
+          .signers([creator])
+          .rpc()
+        expect.fail('Should have thrown DeadlineNotReached')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('DeadlineNotReached')
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, CLAIM_GRACE_PERIOD * 1000))
+      const creatorBefore = await provider.connection.getBalance(creator.publicKey)
 
       await program.methods
-        .claimTask()
-        .accounts(
-          accs({
-            task: taskPda,
-            platform: platformPda,
-            agentProfile: agentProfilePda,
-            agent: agent.publicKey,
-          })
-        )
-        .signers([agent])
+        .cancelTask()
+        .accounts(accs({ task: reclaimTaskPda, creator: creator.publicKey }))
+        .signers([creator])
         .rpc()
 
+      const taskInfo = await provider.connection.getAccountInfo(reclaimTaskPda)
+      expect(taskInfo).to.be.null
+
+      const creatorAfter = await provider.connection.getBalance(creator.publicKey)
+      expect(creatorAfter - creatorBefore).to.be.greaterThanOrEqual(bounty)
+    })
+
+    it('lets the agent contest the cancellation of a late submission', async () => {
       await program.methods
-        .submitDeliverable(Array.from(Buffer.alloc(32, 71)) as any)
+        .submitDeliverable(Array.from(Buffer.alloc(32, 124)) as any)
         .accounts(
           accs({
-            task: taskPda,
+            task: lateTaskPda,
             platform: platformPda,
             agent: agent.publicKey,
           })
@@ -1462,110 +2715,80 @@ describe('task-escrow', () => {
         .signers([agent])
         .rpc()
 
+      // Creator cancels instead of reviewing; the bounty stays in escrow
       await program.methods
-        .rejectSubmission(Array.from(Buffer.alloc(32, 72)) as any)
-        .accounts({ task: taskPda, creator: creator.publicKey })
+        .cancelTask()
+        .accounts(accs({ task: lateTaskPda, creator: creator.publicKey }))
         .signers([creator])
         .rpc()
 
-      const [disputePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('dispute'), taskPda.toBuffer()],
-        program.programId
-      )
-
-      await program.methods
-        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 73)) as any)
-        .accounts(
-          accs({
-            task: taskPda,
-            dispute: disputePda,
-            initiator: creator.publicKey,
-            systemProgram: SystemProgram.programId,
-          })
-        )
-        .signers([creator])
-        .rpc()
-
-      // Both voters vote CreatorWins
-      for (const [voter, voterProfile] of [
-        [voter1, voter1ProfilePda],
-        [voter2, voter2ProfilePda],
-      ] as const) {
-        const [votePda] = PublicKey.findProgramAddressSync(
-          [Buffer.from('vote'), disputePda.toBuffer(), (voter as Keypair).publicKey.toBuffer()],
-          program.programId
-        )
+      let task = await program.account.task.fetch(lateTaskPda)
+      expect(task.status).to.deep.include({ cancelled: {} })
 
+      // Refund waits for the agent's window to contest
+      try {
         await program.methods
-          .castVote({ creatorWins: {} } as any)
+          .expireTask()
           .accounts(
             accs({
-              task: taskPda,
-              dispute: disputePda,
+              task: lateTaskPda,
+              creator: creator.publicKey,
               platform: platformPda,
-              vote: votePda,
-              voterProfile: voterProfile as PublicKey,
-              voter: (voter as Keypair).publicKey,
-              systemProgram: SystemProgram.programId,
+              caller: authority.publicKey,
             })
           )
-          .signers([voter as Keypair])
           .rpc()
+        expect.fail('Should have thrown DeadlineNotReached')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('DeadlineNotReached')
       }
 
-      // Wait for voting period
-      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
-
-      const creatorBefore = await provider.connection.getBalance(creator.publicKey)
+      const [disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), lateTaskPda.toBuffer()],
+        program.programId
+      )
 
       await program.methods
-        .resolveDispute()
+        .openDispute({ other: {} } as any, Array.from(Buffer.alloc(32, 125)) as any)
         .accounts(
           accs({
+            task: lateTaskPda,
             dispute: disputePda,
-            task: taskPda,
-            platform: platformPda,
-            creator: creator.publicKey,
-            agent: agent.publicKey,
-            agentProfile: agentProfilePda,
-            treasury: treasury.publicKey,
-            caller: authority.publicKey,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
           })
         )
+        .signers([agent])
         .rpc()
 
-      // Both PDAs should be closed
-      const taskInfo = await provider.connection.getAccountInfo(taskPda)
-      expect(taskInfo).to.be.null
-      const disputeInfo = await provider.connection.getAccountInfo(disputePda)
-      expect(disputeInfo).to.be.null
-
-      // Creator should receive full bounty back (no fee for CreatorWins)
-      const creatorAfter = await provider.connection.getBalance(creator.publicKey)
-      expect(creatorAfter - creatorBefore).to.be.greaterThanOrEqual(bounty)
+      task = await program.account.task.fetch(lateTaskPda)
+      expect(task.status).to.deep.include({ disputed: {} })
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.reason).to.deep.include({ deadlineMissed: {} })
     })
+  })
 
-    // ── Split ──
+  // ─── Auto-approval after review window ─────────────────────
 
-    it('resolves dispute with Split ruling (50/50 minus fee)', async () => {
-      const taskIndex = new BN(creatorTaskCount)
-      const bounty = 2 * LAMPORTS_PER_SOL
+  describe('auto-approve after review period', () => {
+    let taskPda: PublicKey
+    const bounty = 0.2 * LAMPORTS_PER_SOL
 
-      const [taskPda] = PublicKey.findProgramAddressSync(
+    it('rejects auto-approval before the review period ends', async () => {
+      const taskIndex = new BN(creatorTaskCount)
+      ;[taskPda] = PublicKey.findProgramAddressSync(
         [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
         program.programId
       )
 
-      const deadline = Math.floor(Date.now() / 1000) + 3600
-
       await program.methods
         .createTask(
-          'Split dispute task',
-          Array.from(Buffer.alloc(32, 80)) as any,
+          'Ghosted review task',
+          Array.from(Buffer.alloc(32, 110)) as any,
           new BN(bounty),
           new BN(creatorTaskCount),
-          new BN(deadline),
-          new BN(50)
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(0)
         )
         .accounts(
           accs({
@@ -1595,7 +2818,7 @@ describe('task-escrow', () => {
         .rpc()
 
       await program.methods
-        .submitDeliverable(Array.from(Buffer.alloc(32, 81)) as any)
+        .submitDeliverable(Array.from(Buffer.alloc(32, 111)) as any)
         .accounts(
           accs({
             task: taskPda,
@@ -1606,123 +2829,187 @@ describe('task-escrow', () => {
         .signers([agent])
         .rpc()
 
-      await program.methods
-        .rejectSubmission(Array.from(Buffer.alloc(32, 82)) as any)
-        .accounts({ task: taskPda, creator: creator.publicKey })
-        .signers([creator])
-        .rpc()
+      try {
+        await program.methods
+          .autoApproveTask()
+          .accounts(
+            accs({
+              task: taskPda,
+              platform: platformPda,
+              creator: creator.publicKey,
+              agent: agent.publicKey,
+              agentProfile: agentProfilePda,
+              treasury: treasury.publicKey,
+              caller: authority.publicKey,
+            })
+          )
+          .rpc()
+        expect.fail('Should have thrown ReviewPeriodNotEnded')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('ReviewPeriodNotEnded')
+      }
+    })
 
-      const [disputePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('dispute'), taskPda.toBuffer()],
-        program.programId
-      )
+    it('settles to the agent once the review period has passed', async () => {
+      // Wait for the review window to lapse
+      await new Promise((resolve) => setTimeout(resolve, (REVIEW_PERIOD + 2) * 1000))
 
-      await program.methods
-        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 83)) as any)
-        .accounts(
-          accs({
-            task: taskPda,
-            dispute: disputePda,
-            initiator: agent.publicKey,
-            systemProgram: SystemProgram.programId,
-          })
-        )
-        .signers([agent])
-        .rpc()
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
 
-      // 1 vote CreatorWins + 1 vote AgentWins → tie → Split
-      const [vote1Pda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('vote'), disputePda.toBuffer(), voter1.publicKey.toBuffer()],
-        program.programId
-      )
+      // Anyone can trigger auto-approval
       await program.methods
-        .castVote({ creatorWins: {} } as any)
+        .autoApproveTask()
         .accounts(
           accs({
             task: taskPda,
-            dispute: disputePda,
             platform: platformPda,
-            vote: vote1Pda,
-            voterProfile: voter1ProfilePda,
-            voter: voter1.publicKey,
-            systemProgram: SystemProgram.programId,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            caller: authority.publicKey,
           })
         )
-        .signers([voter1])
         .rpc()
 
-      const [vote2Pda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('vote'), disputePda.toBuffer(), voter2.publicKey.toBuffer()],
+      const fee = Math.floor((bounty * FEE_BPS) / 10000)
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      expect(agentAfter - agentBefore).to.equal(bounty - fee)
+
+      const taskInfo = await provider.connection.getAccountInfo(taskPda)
+      expect(taskInfo).to.be.null
+    })
+  })
+
+  // ─── MAX_REJECTIONS auto-dispute ───────────────────────────
+
+  describe('auto-dispute after MAX_REJECTIONS (3)', () => {
+    let taskPda: PublicKey
+    let disputePda: PublicKey
+
+    it('auto-transitions to Disputed after 3 rejections', async () => {
+      const taskIndex = new BN(creatorTaskCount)
+      const bounty = 0.5 * LAMPORTS_PER_SOL
+
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+      ;[disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
         program.programId
       )
+
+      const deadline = Math.floor(Date.now() / 1000) + 3600
+
       await program.methods
-        .castVote({ agentWins: {} } as any)
+        .createTask(
+          'Multi-reject task',
+          Array.from(Buffer.alloc(32, 50)) as any,
+          new BN(bounty),
+          new BN(creatorTaskCount),
+          new BN(deadline),
+          new BN(20)
+        )
         .accounts(
           accs({
             task: taskPda,
-            dispute: disputePda,
             platform: platformPda,
-            vote: vote2Pda,
-            voterProfile: voter2ProfilePda,
-            voter: voter2.publicKey,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
             systemProgram: SystemProgram.programId,
           })
         )
-        .signers([voter2])
+        .signers([creator])
         .rpc()
 
-      // Wait for voting period
-      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
-
-      const creatorBefore = await provider.connection.getBalance(creator.publicKey)
-      const agentBefore = await provider.connection.getBalance(agent.publicKey)
-      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey)
+      creatorTaskCount++
 
+      // Agent claims
       await program.methods
-        .resolveDispute()
+        .claimTask()
         .accounts(
           accs({
-            dispute: disputePda,
             task: taskPda,
             platform: platformPda,
-            creator: creator.publicKey,
-            agent: agent.publicKey,
             agentProfile: agentProfilePda,
-            treasury: treasury.publicKey,
-            caller: authority.publicKey,
+            agent: agent.publicKey,
           })
         )
+        .signers([agent])
         .rpc()
 
-      // Both PDAs should be closed
-      const taskInfo = await provider.connection.getAccountInfo(taskPda)
-      expect(taskInfo).to.be.null
+      // Rejection cycle: submit → reject × 3
+      for (let i = 0; i < 3; i++) {
+        // Agent submits
+        await program.methods
+          .submitDeliverable(Array.from(Buffer.alloc(32, 51 + i)) as any)
+          .accounts(
+            accs({
+              task: taskPda,
+              platform: platformPda,
+              agent: agent.publicKey,
+            })
+          )
+          .signers([agent])
+          .rpc()
 
-      // Verify split distribution
-      const fee = Math.floor((bounty * FEE_BPS) / 10000)
-      const afterFee = bounty - fee
-      const halfAgent = Math.floor(afterFee / 2)
-      const halfCreator = afterFee - halfAgent // creator gets ceiling
+        // Creator rejects; the third rejection opens the dispute PDA
+        const reject = () =>
+          program.methods
+            .rejectSubmission(Array.from(Buffer.alloc(32, 61 + i)) as any)
+            .accounts(
+              accs({
+                task: taskPda,
+                dispute: i === 2 ? disputePda : null,
+                creator: creator.publicKey,
+              })
+            )
+            .signers([creator])
+            .rpc()
+
+        if (i === 2) {
+          // ...which counts as opening a dispute, so it waits out a dispute pause
+          await program.methods
+            .pausePlatform(PAUSE_DISPUTES)
+            .accounts({ platform: platformPda, authority: authority.publicKey })
+            .rpc()
+          try {
+            await reject()
+            expect.fail('Should have thrown PlatformPaused')
+          } catch (err: any) {
+            expect(err.error.errorCode.code).to.equal('PlatformPaused')
+          }
+          await program.methods
+            .resumePlatform(PAUSE_DISPUTES)
+            .accounts({ platform: platformPda, authority: authority.publicKey })
+            .rpc()
+        }
+        await reject()
+      }
 
-      const creatorAfter = await provider.connection.getBalance(creator.publicKey)
-      const agentAfter = await provider.connection.getBalance(agent.publicKey)
-      const treasuryAfter = await provider.connection.getBalance(treasury.publicKey)
+      // After 3 rejections, status should be Disputed
+      const task = await program.account.task.fetch(taskPda)
+      expect(task.status).to.deep.include({ disputed: {} })
+      expect(task.rejectionCount).to.equal(3)
 
-      // Creator gets half + rent from closed accounts
-      expect(creatorAfter - creatorBefore).to.be.greaterThanOrEqual(halfCreator)
-      // Agent gets the other half
-      expect(agentAfter - agentBefore).to.equal(halfAgent)
-      // Treasury gets the fee
-      expect(treasuryAfter - treasuryBefore).to.equal(fee)
+      // ...with a dispute record ready for arbitration
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.task.toBase58()).to.equal(taskPda.toBase58())
+      expect(dispute.initiator.toBase58()).to.equal(creator.publicKey.toBase58())
+      expect(dispute.reason).to.deep.include({ rejectionLimit: {} })
+      expect(dispute.status).to.deep.include({ open: {} })
     })
   })
 
-  // ─── InsufficientVotes error path ──────────────────────────
+  // ─── Dispute: CreatorWins & Split rulings ──────────────────
 
-  describe('InsufficientVotes error path', () => {
-    it('rejects resolve_dispute when votes < min_votes', async () => {
+  describe('dispute rulings: CreatorWins and Split', () => {
+    // ── CreatorWins ──
+
+    it('resolves dispute with CreatorWins ruling (full refund)', async () => {
       const taskIndex = new BN(creatorTaskCount)
-      const bounty = 0.5 * LAMPORTS_PER_SOL
+      const bounty = 1 * LAMPORTS_PER_SOL
 
       const [taskPda] = PublicKey.findProgramAddressSync(
         [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
@@ -1734,12 +3021,12 @@ describe('task-escrow', () => {
       // Create → Claim → Submit → Reject → OpenDispute
       await program.methods
         .createTask(
-          'InsufficientVotes task',
-          Array.from(Buffer.alloc(32, 90)) as any,
+          'CreatorWins dispute task',
+          Array.from(Buffer.alloc(32, 70)) as any,
           new BN(bounty),
           new BN(creatorTaskCount),
           new BN(deadline),
-          new BN(10)
+          new BN(50)
         )
         .accounts(
           accs({
@@ -1769,7 +3056,7 @@ describe('task-escrow', () => {
         .rpc()
 
       await program.methods
-        .submitDeliverable(Array.from(Buffer.alloc(32, 91)) as any)
+        .submitDeliverable(Array.from(Buffer.alloc(32, 71)) as any)
         .accounts(
           accs({
             task: taskPda,
@@ -1781,7 +3068,7 @@ describe('task-escrow', () => {
         .rpc()
 
       await program.methods
-        .rejectSubmission(Array.from(Buffer.alloc(32, 92)) as any)
+        .rejectSubmission(Array.from(Buffer.alloc(32, 72)) as any)
         .accounts({ task: taskPda, creator: creator.publicKey })
         .signers([creator])
         .rpc()
@@ -1792,63 +3079,2916 @@ describe('task-escrow', () => {
       )
 
       await program.methods
-        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 93)) as any)
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 73)) as any)
         .accounts(
           accs({
             task: taskPda,
             dispute: disputePda,
-            initiator: agent.publicKey,
+            initiator: creator.publicKey,
             systemProgram: SystemProgram.programId,
           })
         )
-        .signers([agent])
+        .signers([creator])
         .rpc()
 
-      // Cast only 1 vote (MIN_VOTES = 2)
-      const [votePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('vote'), disputePda.toBuffer(), voter1.publicKey.toBuffer()],
-        program.programId
+      // Both voters vote CreatorWins
+      for (const [voter, voterProfile] of [
+        [voter1, voter1ProfilePda],
+        [voter2, voter2ProfilePda],
+      ] as const) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [Buffer.from('vote'), disputePda.toBuffer(), (voter as Keypair).publicKey.toBuffer()],
+          program.programId
+        )
+
+        await program.methods
+          .castVote(voteCommitment({ creatorWins: {} }, (voter as Keypair).publicKey) as any)
+          .accounts(
+            accs({
+              task: taskPda,
+              dispute: disputePda,
+              platform: platformPda,
+              vote: votePda,
+              voterProfile: voterProfile as PublicKey,
+              voter: (voter as Keypair).publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+          )
+          .signers([voter as Keypair])
+          .rpc()
+      }
+
+      // Wait for the commit phase, reveal, then wait out the reveal phase
+      // and the unused appeal window
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+      await revealVote(taskPda, disputePda, voter1, { creatorWins: {} })
+      await revealVote(taskPda, disputePda, voter2, { creatorWins: {} })
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + APPEAL_WINDOW + 1) * 1000))
+
+      const creatorBefore = await provider.connection.getBalance(creator.publicKey)
+
+      await program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: creator.publicKey,
+            caller: authority.publicKey,
+          })
+        )
+        .rpc()
+
+      // Task PDA should be closed
+      const taskInfo = await provider.connection.getAccountInfo(taskPda)
+      expect(taskInfo).to.be.null
+
+      // Creator should receive full bounty back (no fee for CreatorWins)
+      const creatorAfter = await provider.connection.getBalance(creator.publicKey)
+      expect(creatorAfter - creatorBefore).to.be.greaterThanOrEqual(bounty)
+
+      // Dispute closes once both votes are settled
+      await settleVote(disputePda, voter1, creator.publicKey)
+      await settleVote(disputePda, voter2, creator.publicKey)
+      const disputeInfo = await provider.connection.getAccountInfo(disputePda)
+      expect(disputeInfo).to.be.null
+    })
+
+    // ── Split ──
+
+    it('resolves dispute with Split ruling (50/50 minus fee)', async () => {
+      const taskIndex = new BN(creatorTaskCount)
+      const bounty = 2 * LAMPORTS_PER_SOL
+
+      const [taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+
+      const deadline = Math.floor(Date.now() / 1000) + 3600
+
+      await program.methods
+        .createTask(
+          'Split dispute task',
+          Array.from(Buffer.alloc(32, 80)) as any,
+          new BN(bounty),
+          new BN(creatorTaskCount),
+          new BN(deadline),
+          new BN(50)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 81)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 82)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      const [disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 83)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      // 1 vote CreatorWins + 1 vote AgentWins → tie → Split
+      const [vote1Pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter1.publicKey.toBuffer()],
+        program.programId
+      )
+      await program.methods
+        .castVote(voteCommitment({ creatorWins: {} }, voter1.publicKey) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            platform: platformPda,
+            vote: vote1Pda,
+            voterProfile: voter1ProfilePda,
+            voter: voter1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([voter1])
+        .rpc()
+
+      const [vote2Pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter2.publicKey.toBuffer()],
+        program.programId
+      )
+      await program.methods
+        .castVote(voteCommitment({ agentWins: {} }, voter2.publicKey) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            platform: platformPda,
+            vote: vote2Pda,
+            voterProfile: voter2ProfilePda,
+            voter: voter2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([voter2])
+        .rpc()
+
+      // Wait for the commit phase, reveal, then wait out the reveal phase
+      // and the unused appeal window
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+      await revealVote(taskPda, disputePda, voter1, { creatorWins: {} })
+      await revealVote(taskPda, disputePda, voter2, { agentWins: {} })
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + APPEAL_WINDOW + 1) * 1000))
+
+      const creatorBefore = await provider.connection.getBalance(creator.publicKey)
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey)
+
+      await program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: agent.publicKey,
+            caller: authority.publicKey,
+          })
+        )
+        .rpc()
+
+      // Both PDAs should be closed
+      const taskInfo = await provider.connection.getAccountInfo(taskPda)
+      expect(taskInfo).to.be.null
+
+      // Verify split distribution
+      const fee = Math.floor((bounty * FEE_BPS) / 10000)
+      const afterFee = bounty - fee
+      const halfAgent = Math.floor(afterFee / 2)
+      const halfCreator = afterFee - halfAgent // creator gets ceiling
+
+      const creatorAfter = await provider.connection.getBalance(creator.publicKey)
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      const treasuryAfter = await provider.connection.getBalance(treasury.publicKey)
+
+      // Creator gets half + rent from closed accounts
+      expect(creatorAfter - creatorBefore).to.be.greaterThanOrEqual(halfCreator)
+      // Agent gets the other half
+      expect(agentAfter - agentBefore).to.equal(halfAgent)
+      // Treasury gets the fee (no majority voters to reward on a tie)
+      expect(treasuryAfter - treasuryBefore).to.equal(fee)
+
+      // Both tied voters sided against the Split ruling and are slashed
+      await settleVote(disputePda, voter1, agent.publicKey)
+      await settleVote(disputePda, voter2, agent.publicKey)
+      const disputeInfo = await provider.connection.getAccountInfo(disputePda)
+      expect(disputeInfo).to.be.null
+    })
+
+    it('splits at the median percentage the arbitrators voted', async () => {
+      const taskIndex = new BN(creatorTaskCount)
+      const bounty = 2 * LAMPORTS_PER_SOL
+
+      const [taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+
+      await program.methods
+        .createTask(
+          'Custom split task',
+          Array.from(Buffer.alloc(32, 84)) as any,
+          new BN(bounty),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(50)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 85)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 86)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      const [disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 87)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      // Two Split votes giving the agent 70%
+      for (const [voter, profile] of [
+        [voter1, voter1ProfilePda],
+        [voter2, voter2ProfilePda],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+          program.programId
+        )
+        await program.methods
+          .castVote(voteCommitment({ split: {} }, voter.publicKey, 70) as any)
+          .accounts(
+            accs({
+              task: taskPda,
+              dispute: disputePda,
+              platform: platformPda,
+              vote: votePda,
+              voterProfile: profile,
+              voter: voter.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+          )
+          .signers([voter])
+          .rpc()
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+
+      // Percentages must be a multiple of 10
+      try {
+        await revealVote(taskPda, disputePda, voter1, { split: {} }, 75)
+        expect.fail('Should have thrown InvalidSplitPercentage')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('InvalidSplitPercentage')
+      }
+
+      await revealVote(taskPda, disputePda, voter1, { split: {} }, 70)
+      await revealVote(taskPda, disputePda, voter2, { split: {} }, 70)
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + APPEAL_WINDOW + 1) * 1000))
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+
+      await program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: agent.publicKey,
+            caller: authority.publicKey,
+          })
+        )
+        .rpc()
+
+      const fee = Math.floor((bounty * FEE_BPS) / 10000)
+      const agentShare = Math.floor(((bounty - fee) * 70) / 100)
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      expect(agentAfter - agentBefore).to.equal(agentShare)
+
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.agentSharePct).to.equal(70)
+
+      await settleVote(disputePda, voter1, agent.publicKey)
+      await settleVote(disputePda, voter2, agent.publicKey)
+    })
+  })
+
+  // ─── InsufficientVotes error path ──────────────────────────
+
+  describe('InsufficientVotes error path', () => {
+    const bounty = 0.5 * LAMPORTS_PER_SOL
+    let taskPda: PublicKey
+    let disputePda: PublicKey
+
+    it('rejects resolve_dispute when votes < min_votes', async () => {
+      const taskIndex = new BN(creatorTaskCount)
+
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+
+      const deadline = Math.floor(Date.now() / 1000) + 3600
+
+      // Create → Claim → Submit → Reject → OpenDispute
+      await program.methods
+        .createTask(
+          'InsufficientVotes task',
+          Array.from(Buffer.alloc(32, 90)) as any,
+          new BN(bounty),
+          new BN(creatorTaskCount),
+          new BN(deadline),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 91)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 92)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      ;[disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 93)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      // Cast only 1 vote (MIN_VOTES = 2)
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter1.publicKey.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .castVote(voteCommitment({ agentWins: {} }, voter1.publicKey) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            platform: platformPda,
+            vote: votePda,
+            voterProfile: voter1ProfilePda,
+            voter: voter1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([voter1])
+        .rpc()
+
+      // Wait for the commit phase, reveal, then wait for the reveal phase
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+      await revealVote(taskPda, disputePda, voter1, { agentWins: {} })
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+
+      // Try to resolve — should fail with InsufficientVotes
+      try {
+        await program.methods
+          .resolveDispute()
+          .accounts(
+            accs({
+              dispute: disputePda,
+              task: taskPda,
+              platform: platformPda,
+              creator: creator.publicKey,
+              agent: agent.publicKey,
+              agentProfile: agentProfilePda,
+              treasury: treasury.publicKey,
+              initiator: agent.publicKey,
+              caller: authority.publicKey,
+            })
+          )
+          .rpc()
+        expect.fail('Should have thrown InsufficientVotes')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('InsufficientVotes')
+      }
+    })
+
+    it('resolves on the plurality of votes once the fallback period passes', async () => {
+      // Wait out the no-quorum fallback period and the unused appeal window
+      await new Promise((resolve) =>
+        setTimeout(resolve, (DISPUTE_FALLBACK_PERIOD + APPEAL_WINDOW + 1) * 1000)
+      )
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+
+      await program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: agent.publicKey,
+            caller: authority.publicKey,
+          })
+        )
+        .rpc()
+
+      // The single AgentWins vote carries the ruling
+      const fee = Math.floor((bounty * FEE_BPS) / 10000)
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      expect(agentAfter - agentBefore).to.equal(bounty - fee)
+
+      const taskInfo = await provider.connection.getAccountInfo(taskPda)
+      expect(taskInfo).to.be.null
+
+      await settleVote(disputePda, voter1, agent.publicKey)
+    })
+  })
+
+  // ─── Vote weight modes ─────────────────────────────────────
+
+  describe('vote weight modes', () => {
+    const bounty = 0.2 * LAMPORTS_PER_SOL
+    const WEIGHT_CAP = 1000
+    const REP_PENALTY = 50 // only voter1, far above it, is outvoted under this
+    const voters: [Keypair, PublicKey, Record<string, unknown>][] = []
+    const disputes: Record<
+      string,
+      { taskPda: PublicKey; disputePda: PublicKey; revealDeadline: number }
+    > = {}
+    // Reputation of voter1..3 while the votes below are cast
+    let reputations: number[] = []
+
+    async function setWeighting(mode: Record<string, unknown>, cap: number, minWeight: number) {
+      await program.methods
+        .updatePlatform({
+          ...NO_CONFIG_CHANGES,
+          voteWeightMode: mode,
+          voteWeightCap: new BN(cap),
+          disputeMinWeight: new BN(minWeight),
+        } as any)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+      await program.methods
+        .applyPlatformConfig()
+        .accounts({ platform: platformPda, caller: authority.publicKey })
+        .rpc()
+    }
+
+    async function setRepPenalty(penalty: number) {
+      await program.methods
+        .updatePlatform({ ...NO_CONFIG_CHANGES, arbitratorRepPenalty: new BN(penalty) } as any)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+      await program.methods
+        .applyPlatformConfig()
+        .accounts({ platform: platformPda, caller: authority.publicKey })
+        .rpc()
+    }
+
+    /** Sleep until `unixTime`, plus a second of slack for the validator clock. */
+    function sleepUntil(unixTime: number) {
+      return new Promise((resolve) =>
+        setTimeout(resolve, Math.max(0, (unixTime + 1) * 1000 - Date.now()))
+      )
+    }
+
+    function weight(mode: string, reputation: number) {
+      const rep = Math.max(reputation, 1)
+      switch (mode) {
+        case 'linear':
+          return rep
+        case 'sqrt':
+          return Math.floor(Math.sqrt(rep))
+        case 'capped':
+          return Math.max(Math.min(rep, WEIGHT_CAP), 1)
+        default:
+          return 1
+      }
+    }
+
+    async function createTask(seed: number, reputationReward: number) {
+      const [taskPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('task'),
+          creator.publicKey.toBuffer(),
+          new BN(creatorTaskCount).toArrayLike(Buffer, 'le', 8),
+        ],
+        program.programId
+      )
+      await program.methods
+        .createTask(
+          'Vote weight task',
+          Array.from(Buffer.alloc(32, seed)) as any,
+          new BN(bounty),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(reputationReward)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+      creatorTaskCount++
+      return taskPda
+    }
+
+    async function claimAndSubmit(taskPda: PublicKey, worker: Keypair, profile: PublicKey) {
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: profile,
+            agent: worker.publicKey,
+          })
+        )
+        .signers([worker])
+        .rpc()
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 171)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: worker.publicKey }))
+        .signers([worker])
+        .rpc()
+    }
+
+    /**
+     * Open a dispute with the platform weighted per `mode`, then have voter1
+     * vote AgentWins against voter2 and voter3 (or just voter1 and voter2
+     * vote CreatorWins when `pair` is set), revealing once the commit phase ends.
+     */
+    async function voteOnDispute(seed: number, mode: string, pair = false) {
+      const taskPda = await createTask(seed, 10)
+      const [disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+      await claimAndSubmit(taskPda, agent, agentProfilePda)
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 172)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 173)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      const ballots = voters
+        .slice(0, pair ? 2 : 3)
+        .map(([voter, profile, ruling]) => [voter, profile, pair ? { creatorWins: {} } : ruling])
+      for (const [voter, profile, ruling] of ballots as [Keypair, PublicKey, any][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+          program.programId
+        )
+        await program.methods
+          .castVote(voteCommitment(ruling, voter.publicKey) as any)
+          .accounts(
+            accs({
+              task: taskPda,
+              dispute: disputePda,
+              platform: platformPda,
+              vote: votePda,
+              voterProfile: profile,
+              voter: voter.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+          )
+          .signers([voter])
+          .rpc()
+      }
+      const dispute = await program.account.dispute.fetch(disputePda)
+      await sleepUntil(dispute.commitDeadline.toNumber())
+      for (const [voter, , ruling] of ballots as [Keypair, PublicKey, any][]) {
+        await revealVote(taskPda, disputePda, voter, ruling)
+      }
+      disputes[mode] = { taskPda, disputePda, revealDeadline: dispute.revealDeadline.toNumber() }
+    }
+
+    function resolve(mode: string) {
+      const { taskPda, disputePda } = disputes[mode]
+      return program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: agent.publicKey,
+            caller: authority.publicKey,
+          })
+        )
+        .rpc()
+    }
+
+    async function expectWeightedRuling(mode: string, ruling: Record<string, unknown>) {
+      const { disputePda } = disputes[mode]
+      let dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.votesForAgent.toNumber()).to.equal(weight(mode, reputations[0]))
+      expect(dispute.votesForCreator.toNumber()).to.equal(
+        weight(mode, reputations[1]) + weight(mode, reputations[2])
+      )
+
+      await resolve(mode)
+      dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.ruling).to.deep.equal(ruling)
+      expect(dispute.resolution).to.deep.equal({ quorum: {} })
+    }
+
+    before(async () => {
+      voters.push(
+        [voter1, voter1ProfilePda, { agentWins: {} }],
+        [voter2, voter2ProfilePda, { creatorWins: {} }],
+        [voter3, voter3ProfilePda, { creatorWins: {} }]
+      )
+
+      // Give voter1 far more reputation than the other two by completing work
+      for (const seed of [174, 175]) {
+        const taskPda = await createTask(seed, 1000)
+        await claimAndSubmit(taskPda, voter1, voter1ProfilePda)
+        await program.methods
+          .approveAndSettle()
+          .accounts(
+            accs({
+              task: taskPda,
+              platform: platformPda,
+              creator: creator.publicKey,
+              agent: voter1.publicKey,
+              agentProfile: voter1ProfilePda,
+              treasury: treasury.publicKey,
+            })
+          )
+          .signers([creator])
+          .rpc()
+      }
+      reputations = []
+      for (const [, profile] of voters) {
+        const account = await program.account.agentProfile.fetch(profile)
+        reputations.push(account.reputationScore.toNumber())
+      }
+      // voter1 outweighs the others in every mode but Flat
+      expect(reputations[0]).to.be.greaterThan(WEIGHT_CAP)
+      expect(reputations[1] + reputations[2]).to.be.lessThan(WEIGHT_CAP)
+
+      // Vote weights are fixed when a vote is cast, so each dispute is voted
+      // on under its own mode
+      for (const [seed, mode] of [
+        [176, 'flat'],
+        [177, 'linear'],
+        [178, 'sqrt'],
+        [179, 'capped'],
+      ] as [number, string][]) {
+        await setWeighting({ [mode]: {} }, WEIGHT_CAP, 0)
+        await voteOnDispute(seed, mode)
+      }
+      // Two Flat votes of weight 1 each, against a minimum weight of 3
+      await setWeighting({ flat: {} }, 0, 3)
+      await voteOnDispute(180, 'minWeight', true)
+      await setWeighting(VOTE_WEIGHT_MODE, VOTE_WEIGHT_CAP, DISPUTE_MIN_WEIGHT)
+
+      // Every round has ended (the earlier ones' appeal windows too), but the
+      // min-weight dispute's fallback period is still running
+      await sleepUntil(disputes.minWeight.revealDeadline)
+    })
+
+    after(async () => {
+      for (const [mode, { disputePda }] of Object.entries(disputes)) {
+        if (!(await provider.connection.getAccountInfo(disputePda))) continue
+        for (const [voter] of mode === 'minWeight' ? voters.slice(0, 2) : voters) {
+          await settleVote(disputePda, voter, agent.publicKey)
+        }
+      }
+    })
+
+    it('blocks quorum on enough votes with too little weight', async () => {
+      try {
+        await resolve('minWeight')
+        expect.fail('Should have thrown InsufficientVotes')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('InsufficientVotes')
+      }
+    })
+
+    it('counts one vote per arbitrator in Flat mode', async () => {
+      // The penalty is snapshotted at resolution; voter1 is outvoted here
+      await setRepPenalty(REP_PENALTY)
+      await expectWeightedRuling('flat', { creatorWins: {} })
+      await setRepPenalty(ARBITRATOR_REP_PENALTY)
+    })
+
+    it('docks the outvoted arbitrator the configured reputation penalty', async () => {
+      const { disputePda } = disputes.flat
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.arbitratorRepPenalty.toNumber()).to.equal(REP_PENALTY)
+
+      const before: number[] = []
+      for (const [, profile] of voters) {
+        const account = await program.account.agentProfile.fetch(profile)
+        before.push(account.reputationScore.toNumber())
+      }
+      for (const [voter] of voters) {
+        await settleVote(disputePda, voter, agent.publicKey)
+      }
+      const after: number[] = []
+      for (const [, profile] of voters) {
+        const account = await program.account.agentProfile.fetch(profile)
+        after.push(account.reputationScore.toNumber())
+      }
+
+      expect(after[0]).to.equal(before[0] - REP_PENALTY)
+      expect(after[1]).to.equal(before[1] + ARBITRATOR_REP_REWARD)
+      expect(after[2]).to.equal(before[2] + ARBITRATOR_REP_REWARD)
+    })
+
+    it('weights votes by reputation in Linear mode', async () => {
+      await expectWeightedRuling('linear', { agentWins: {} })
+    })
+
+    it('weights votes by the square root of reputation in Sqrt mode', async () => {
+      await expectWeightedRuling('sqrt', { agentWins: {} })
+    })
+
+    it('caps each vote at vote_weight_cap in Capped mode', async () => {
+      await expectWeightedRuling('capped', { agentWins: {} })
+      const dispute = await program.account.dispute.fetch(disputes.capped.disputePda)
+      expect(dispute.votesForAgent.toNumber()).to.equal(WEIGHT_CAP)
+    })
+
+    it('falls back to plurality once the min-weight dispute times out', async () => {
+      await sleepUntil(disputes.minWeight.revealDeadline + DISPUTE_FALLBACK_PERIOD + APPEAL_WINDOW)
+      await resolve('minWeight')
+      const dispute = await program.account.dispute.fetch(disputes.minWeight.disputePda)
+      expect(dispute.ruling).to.deep.equal({ creatorWins: {} })
+      expect(dispute.resolution).to.deep.equal({ plurality: {} })
+    })
+  })
+
+  // ─── Appeals ───────────────────────────────────────────────
+
+  describe('dispute appeals', () => {
+    const bounty = 0.5 * LAMPORTS_PER_SOL
+    let taskPda: PublicKey
+    let disputePda: PublicKey
+
+    async function appeal(appellant: Keypair, counterparty: PublicKey) {
+      await program.methods
+        .appealDispute()
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            platform: platformPda,
+            appellant: appellant.publicKey,
+            counterparty,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([appellant])
+        .rpc()
+    }
+
+    async function castVote(
+      voter: Keypair,
+      voterProfile: PublicKey,
+      ruling: Record<string, unknown>
+    ) {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+        program.programId
+      )
+      await program.methods
+        .castVote(voteCommitment(ruling, voter.publicKey) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            platform: platformPda,
+            vote: votePda,
+            voterProfile,
+            voter: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([voter])
+        .rpc()
+    }
+
+    it('rejects an appeal from the party the ruling favors', async () => {
+      const taskIndex = new BN(creatorTaskCount)
+
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+
+      const deadline = Math.floor(Date.now() / 1000) + 3600
+
+      // Create → Claim → Submit → Reject → OpenDispute
+      await program.methods
+        .createTask(
+          'Appealed dispute task',
+          Array.from(Buffer.alloc(32, 100)) as any,
+          new BN(bounty),
+          new BN(creatorTaskCount),
+          new BN(deadline),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 101)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 102)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      ;[disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 103)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      // First round: both voters side with the creator
+      await castVote(voter1, voter1ProfilePda, { creatorWins: {} })
+      await castVote(voter2, voter2ProfilePda, { creatorWins: {} })
+
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+      await revealVote(taskPda, disputePda, voter1, { creatorWins: {} })
+      await revealVote(taskPda, disputePda, voter2, { creatorWins: {} })
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+
+      try {
+        await appeal(creator, agent.publicKey)
+        expect.fail('Should have thrown CannotAppealRuling')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('CannotAppealRuling')
+      }
+    })
+
+    it('reopens the dispute with a larger jury when the losing party appeals', async () => {
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+
+      await appeal(agent, creator.publicKey)
+
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.round).to.equal(1)
+      expect(dispute.minVotes).to.equal(MIN_VOTES * 2)
+      expect(dispute.appellant.toBase58()).to.equal(agent.publicKey.toBase58())
+      expect(dispute.appealBondLamports.toNumber()).to.equal(APPEAL_BOND)
+      expect(dispute.votesForCreator).to.equal(0)
+      expect(dispute.priorVotesForCreator).to.equal(2)
+      expect('pending' in dispute.ruling).to.be.true
+
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      expect(agentBefore - agentAfter).to.be.greaterThanOrEqual(APPEAL_BOND)
+
+      // The appeal round has to run its course before resolution
+      try {
+        await program.methods
+          .resolveDispute()
+          .accounts(
+            accs({
+              dispute: disputePda,
+              task: taskPda,
+              platform: platformPda,
+              creator: creator.publicKey,
+              agent: agent.publicKey,
+              agentProfile: agentProfilePda,
+              treasury: treasury.publicKey,
+              initiator: creator.publicKey,
+              caller: authority.publicKey,
+            })
+          )
+          .rpc()
+        expect.fail('Should have thrown VotingPeriodNotEnded')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('VotingPeriodNotEnded')
+      }
+    })
+
+    it('finalizes on the appeal ruling and refunds the successful bond', async () => {
+      // Appeal round: voting periods are doubled; only a fresh arbitrator votes
+      await castVote(voter3, voter3ProfilePda, { agentWins: {} })
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD * 2 + 1) * 1000))
+      await revealVote(taskPda, disputePda, voter3, { agentWins: {} })
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD * 2 + 1) * 1000))
+
+      // Quorum is now 4, so wait out the fallback; no appeal rounds remain
+      await new Promise((resolve) => setTimeout(resolve, (DISPUTE_FALLBACK_PERIOD + 1) * 1000))
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+
+      await program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: creator.publicKey,
+            caller: authority.publicKey,
+          })
+        )
+        .rpc()
+
+      // Agent is paid and gets the bond back, since the ruling was overturned
+      const fee = Math.floor((bounty * FEE_BPS) / 10000)
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      expect(agentAfter - agentBefore).to.equal(bounty - fee + APPEAL_BOND)
+
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect('agentWins' in dispute.ruling).to.be.true
+      expect(dispute.appealBondLamports.toNumber()).to.equal(0)
+
+      // First-round voters are settled against the final ruling
+      await settleVote(disputePda, voter1, creator.publicKey)
+      await settleVote(disputePda, voter2, creator.publicKey)
+      await settleVote(disputePda, voter3, creator.publicKey)
+      const disputeInfo = await provider.connection.getAccountInfo(disputePda)
+      expect(disputeInfo).to.be.null
+    })
+  })
+
+  // ─── Jury selection ────────────────────────────────────────
+
+  describe('random jury selection', () => {
+    const [arbitratorPoolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('arbitrator_pool')],
+      program.programId
+    )
+    let taskPda: PublicKey
+    let disputePda: PublicKey
+    let openSignature: string
+
+    async function setJurySize(jurySize: number) {
+      await program.methods
+        .updatePlatform({ ...NO_CONFIG_CHANGES, jurySize } as any)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+      await program.methods
+        .applyPlatformConfig()
+        .accounts({ platform: platformPda, caller: authority.publicKey })
+        .rpc()
+    }
+
+    async function castVote(voter: Keypair, voterProfile: PublicKey) {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+        program.programId
+      )
+      await program.methods
+        .castVote(voteCommitment({ agentWins: {} }, voter.publicKey) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            platform: platformPda,
+            vote: votePda,
+            voterProfile,
+            voter: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([voter])
+        .rpc()
+    }
+
+    async function drawJury() {
+      // The draw is seeded by a block after the committed slot
+      const { drawSlot } = await program.account.dispute.fetch(disputePda)
+      while ((await provider.connection.getSlot('processed')) <= drawSlot.toNumber()) {
+        await new Promise((resolve) => setTimeout(resolve, 200))
+      }
+      await program.methods
+        .drawJury()
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            arbitratorPool: arbitratorPoolPda,
+            slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          })
+        )
+        .rpc()
+    }
+
+    before(async () => {
+      await setJurySize(MIN_VOTES)
+
+      const taskIndex = new BN(creatorTaskCount)
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+      ;[disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .createTask(
+          'Jury dispute task',
+          Array.from(Buffer.alloc(32, 110)) as any,
+          new BN(0.5 * LAMPORTS_PER_SOL),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 111)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 112)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      openSignature = await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 113)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc({ commitment: 'confirmed' })
+    })
+
+    after(async () => {
+      await setJurySize(JURY_SIZE)
+    })
+
+    it('commits the draw to a slot after the dispute opened', async () => {
+      const tx = await provider.connection.getTransaction(openSignature, {
+        commitment: 'confirmed',
+        maxSupportedTransactionVersion: 0,
+      })
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.drawSlot.toNumber()).to.equal(tx!.slot + JURY_DRAW_DELAY_SLOTS)
+    })
+
+    it('rejects votes before the jury is drawn', async () => {
+      try {
+        await castVote(voter1, voter1ProfilePda)
+        expect.fail('Should have thrown JuryNotDrawn')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('JuryNotDrawn')
+      }
+    })
+
+    it('draws a jury from the pool and only lets jurors vote', async () => {
+      await drawJury()
+
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.jurors).to.have.length(MIN_VOTES)
+
+      const jurors = dispute.jurors.map((j: PublicKey) => j.toBase58())
+      const voters: [Keypair, PublicKey][] = [
+        [voter1, voter1ProfilePda],
+        [voter2, voter2ProfilePda],
+        [voter3, voter3ProfilePda],
+      ]
+      const outsider = voters.find(([v]) => !jurors.includes(v.publicKey.toBase58()))
+      expect(outsider).to.not.be.undefined
+
+      try {
+        await castVote(outsider![0], outsider![1])
+        expect.fail('Should have thrown NotOnJury')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotOnJury')
+      }
+
+      const [juror, jurorProfile] = voters.find(([v]) => jurors.includes(v.publicKey.toBase58()))!
+      await castVote(juror, jurorProfile)
+
+      // One draw per round
+      try {
+        await drawJury()
+        expect.fail('Should have thrown JuryAlreadyDrawn')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('JuryAlreadyDrawn')
+      }
+    })
+  })
+
+  // ─── Arbitrator registry ───────────────────────────────────
+
+  describe('arbitrator registry', () => {
+    const [arbitratorPoolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('arbitrator_pool')],
+      program.programId
+    )
+    let taskPda: PublicKey
+    let disputePda: PublicKey
+
+    async function updateArbitrator(voter: Keypair, categories: number, isAvailable: boolean) {
+      await program.methods
+        .updateArbitrator(categories, isAvailable)
+        .accounts(accs({ arbitratorPool: arbitratorPoolPda, authority: voter.publicKey }))
+        .signers([voter])
+        .rpc()
+    }
+
+    before(async () => {
+      const taskIndex = new BN(creatorTaskCount)
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+      ;[disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .createTask(
+          'Registry dispute task',
+          Array.from(Buffer.alloc(32, 120)) as any,
+          new BN(0.5 * LAMPORTS_PER_SOL),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 121)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 122)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 123)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+    })
+
+    it('rejects an empty category set', async () => {
+      try {
+        await updateArbitrator(voter1, 0, true)
+        expect.fail('Should have thrown InvalidCategories')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('InvalidCategories')
+      }
+    })
+
+    it('rejects votes outside the arbitrator categories', async () => {
+      // Tasks created directly fall under Other; keep voter1 to DataLabeling only
+      await updateArbitrator(voter1, 0b0000001, true)
+
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter1.publicKey.toBuffer()],
+        program.programId
+      )
+      try {
+        await program.methods
+          .castVote(voteCommitment({ agentWins: {} }, voter1.publicKey) as any)
+          .accounts(
+            accs({
+              task: taskPda,
+              dispute: disputePda,
+              platform: platformPda,
+              vote: votePda,
+              voterProfile: voter1ProfilePda,
+              voter: voter1.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+          )
+          .signers([voter1])
+          .rpc()
+        expect.fail('Should have thrown CategoryNotQualified')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('CategoryNotQualified')
+      } finally {
+        await updateArbitrator(voter1, ALL_CATEGORIES, true)
+      }
+    })
+
+    it('drops unavailable arbitrators from the jury pool', async () => {
+      const isListed = async () => {
+        const pool = await program.account.arbitratorPool.fetch(arbitratorPoolPda)
+        return pool.members.some((m: any) => m.arbitrator.equals(voter3.publicKey))
+      }
+
+      await updateArbitrator(voter3, ALL_CATEGORIES, false)
+      expect(await isListed()).to.equal(false)
+
+      await updateArbitrator(voter3, ALL_CATEGORIES, true)
+      expect(await isListed()).to.equal(true)
+    })
+  })
+
+  // ─── Dispute bonds ─────────────────────────────────────────
+
+  describe('dispute bonds', () => {
+    const bond = 0.02 * LAMPORTS_PER_SOL
+    let taskPda: PublicKey
+    let disputePda: PublicKey
+
+    async function setDisputeBond(disputeBond: number) {
+      await program.methods
+        .updatePlatform({ ...NO_CONFIG_CHANGES, disputeBondLamports: new BN(disputeBond) } as any)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+      await program.methods
+        .applyPlatformConfig()
+        .accounts({ platform: platformPda, caller: authority.publicKey })
+        .rpc()
+    }
+
+    async function castVote(voter: Keypair, voterProfile: PublicKey) {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+        program.programId
+      )
+      await program.methods
+        .castVote(voteCommitment({ creatorWins: {} }, voter.publicKey) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            platform: platformPda,
+            vote: votePda,
+            voterProfile,
+            voter: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([voter])
+        .rpc()
+    }
+
+    before(async () => {
+      await setDisputeBond(bond)
+
+      const taskIndex = new BN(creatorTaskCount)
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+      ;[disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .createTask(
+          'Bonded dispute task',
+          Array.from(Buffer.alloc(32, 130)) as any,
+          new BN(0.5 * LAMPORTS_PER_SOL),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 131)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 132)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+    })
+
+    after(async () => {
+      await setDisputeBond(DISPUTE_BOND)
+    })
+
+    it('takes the bond from the initiator when the dispute opens', async () => {
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 133)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.bondLamports.toNumber()).to.equal(bond)
+      expect(dispute.bondOutcome).to.deep.include({ pending: {} })
+
+      const disputeInfo = await provider.connection.getAccountInfo(disputePda)
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(
+        disputeInfo!.data.length
+      )
+      expect(disputeInfo!.lamports).to.equal(rent + bond)
+    })
+
+    it('forfeits the bond to majority voters when the initiator loses', async () => {
+      await castVote(voter1, voter1ProfilePda)
+      await castVote(voter2, voter2ProfilePda)
+
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+      await revealVote(taskPda, disputePda, voter1, { creatorWins: {} })
+      await revealVote(taskPda, disputePda, voter2, { creatorWins: {} })
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + APPEAL_WINDOW + 1) * 1000))
+
+      await program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: agent.publicKey,
+            caller: authority.publicKey,
+          })
+        )
+        .rpc()
+
+      // CreatorWins charges no fee, so the pool is exactly the forfeited bond
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.bondOutcome).to.deep.include({ forfeited: {} })
+      expect(dispute.rewardPoolLamports.toNumber()).to.equal(bond)
+
+      await settleVote(disputePda, voter1, agent.publicKey)
+      await settleVote(disputePda, voter2, agent.publicKey)
+    })
+  })
+
+  describe('closing votes in batches', () => {
+    let taskPda: PublicKey
+    let disputePda: PublicKey
+
+    async function castVote(voter: Keypair, voterProfile: PublicKey) {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+        program.programId
+      )
+      await program.methods
+        .castVote(voteCommitment({ creatorWins: {} }, voter.publicKey) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            platform: platformPda,
+            vote: votePda,
+            voterProfile,
+            voter: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([voter])
+        .rpc()
+    }
+
+    before(async () => {
+      const taskIndex = new BN(creatorTaskCount)
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+      ;[disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .createTask(
+          'Batch close task',
+          Array.from(Buffer.alloc(32, 140)) as any,
+          new BN(0.5 * LAMPORTS_PER_SOL),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 141)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 142)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 143)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await castVote(voter1, voter1ProfilePda)
+      await castVote(voter2, voter2ProfilePda)
+
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+      await revealVote(taskPda, disputePda, voter1, { creatorWins: {} })
+      await revealVote(taskPda, disputePda, voter2, { creatorWins: {} })
+      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + APPEAL_WINDOW + 1) * 1000))
+
+      await program.methods
+        .resolveDispute()
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: agent.publicKey,
+            caller: authority.publicKey,
+          })
+        )
+        .rpc()
+    })
+
+    it('rejects a batch that is not made of whole vote groups', async () => {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter1.publicKey.toBuffer()],
+        program.programId
+      )
+      try {
+        await program.methods
+          .closeVotes()
+          .accounts(
+            accs({
+              dispute: disputePda,
+              initiator: agent.publicKey,
+              platform: platformPda,
+              treasury: treasury.publicKey,
+              caller: authority.publicKey,
+            })
+          )
+          .remainingAccounts([
+            { pubkey: votePda, isWritable: true, isSigner: false },
+            { pubkey: voter1.publicKey, isWritable: true, isSigner: false },
+          ])
+          .rpc()
+        expect.fail('Should have thrown InvalidVoteBatch')
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal('InvalidVoteBatch')
+      }
+    })
+
+    it('settles and closes every vote in one transaction', async () => {
+      const voter1Before = await provider.connection.getBalance(voter1.publicKey)
+
+      await closeVotes(disputePda, [voter1, voter2], agent.publicKey)
+
+      for (const voter of [voter1, voter2]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [Buffer.from('vote'), disputePda.toBuffer(), voter.publicKey.toBuffer()],
+          program.programId
+        )
+        expect(await provider.connection.getAccountInfo(votePda)).to.be.null
+      }
+
+      // Stake and vote rent come back to the arbitrator
+      const voter1After = await provider.connection.getBalance(voter1.publicKey)
+      expect(voter1After - voter1Before).to.be.greaterThanOrEqual(ARBITRATOR_STAKE)
+
+      // Last vote settled: the dispute closes as well
+      expect(await provider.connection.getAccountInfo(disputePda)).to.be.null
+    })
+  })
+
+  describe('changing and withdrawing votes', () => {
+    let taskPda: PublicKey
+    let disputePda: PublicKey
+    let votePda: PublicKey
+    const [arbitratorPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('arbitrator'), voter1.publicKey.toBuffer()],
+      program.programId
+    )
+
+    before(async () => {
+      const taskIndex = new BN(creatorTaskCount)
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+      ;[disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+      ;[votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vote'), disputePda.toBuffer(), voter1.publicKey.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .createTask(
+          'Vote change task',
+          Array.from(Buffer.alloc(32, 150)) as any,
+          new BN(0.5 * LAMPORTS_PER_SOL),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 151)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 152)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 153)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .castVote(voteCommitment({ creatorWins: {} }, voter1.publicKey) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            platform: platformPda,
+            vote: votePda,
+            voterProfile: voter1ProfilePda,
+            voter: voter1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([voter1])
+        .rpc()
+    })
+
+    it('replaces the commitment during the commit phase', async () => {
+      const commitment = voteCommitment({ agentWins: {} }, voter1.publicKey)
+      await program.methods
+        .changeVote(commitment as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            vote: votePda,
+            voter: voter1.publicKey,
+          })
+        )
+        .signers([voter1])
+        .rpc()
+
+      const vote = await program.account.arbitratorVote.fetch(votePda)
+      expect(Buffer.from(vote.commitment)).to.deep.equal(Buffer.from(commitment))
+    })
+
+    it('keeps the arbitrator registered while the vote is active', async () => {
+      try {
+        await program.methods
+          .deregisterArbitrator()
+          .accounts(
+            accs({
+              arbitrator: arbitratorPda,
+              arbitratorPool: PublicKey.findProgramAddressSync(
+                [Buffer.from('arbitrator_pool')],
+                program.programId
+              )[0],
+              authority: voter1.publicKey,
+            })
+          )
+          .signers([voter1])
+          .rpc()
+        expect.fail('Should have thrown ArbitratorHasActiveVotes')
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal('ArbitratorHasActiveVotes')
+      }
+    })
+
+    it('withdraws the vote and returns the stake', async () => {
+      const before = await program.account.dispute.fetch(disputePda)
+      const activeBefore = (await program.account.arbitrator.fetch(arbitratorPda)).activeVotes
+      const voterBefore = await provider.connection.getBalance(voter1.publicKey)
+
+      await program.methods
+        .withdrawVote()
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            vote: votePda,
+            voter: voter1.publicKey,
+          })
+        )
+        .signers([voter1])
+        .rpc()
+
+      expect(await provider.connection.getAccountInfo(votePda)).to.be.null
+      const after = await program.account.dispute.fetch(disputePda)
+      expect(after.votesCommitted).to.equal(before.votesCommitted - 1)
+      const arbitrator = await program.account.arbitrator.fetch(arbitratorPda)
+      expect(arbitrator.activeVotes).to.equal(activeBefore - 1)
+
+      // Stake and rent come back, less the transaction fee
+      const voterAfter = await provider.connection.getBalance(voter1.publicKey)
+      expect(voterAfter - voterBefore).to.be.greaterThan(ARBITRATOR_STAKE - 10_000)
+    })
+  })
+
+  describe('admin dispute resolution', () => {
+    const bounty = 0.5 * LAMPORTS_PER_SOL
+    let taskPda: PublicKey
+    let disputePda: PublicKey
+
+    function adminResolve(
+      caller: PublicKey,
+      ruling: Record<string, unknown>,
+      signers: Keypair[] = []
+    ) {
+      return program.methods
+        .adminResolveDispute(ruling as any, 0)
+        .accounts(
+          accs({
+            dispute: disputePda,
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+            initiator: agent.publicKey,
+            caller,
+          })
+        )
+        .signers(signers)
+        .rpc()
+    }
+
+    before(async () => {
+      const taskIndex = new BN(creatorTaskCount)
+      ;[taskPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('task'), creator.publicKey.toBuffer(), taskIndex.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )
+      ;[disputePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('dispute'), taskPda.toBuffer()],
+        program.programId
+      )
+
+      await program.methods
+        .createTask(
+          'Admin ruling task',
+          Array.from(Buffer.alloc(32, 160)) as any,
+          new BN(bounty),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 161)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      await program.methods
+        .rejectSubmission(Array.from(Buffer.alloc(32, 162)) as any)
+        .accounts({ task: taskPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc()
+
+      await program.methods
+        .openDispute({ qualityIssue: {} } as any, Array.from(Buffer.alloc(32, 163)) as any)
+        .accounts(
+          accs({
+            task: taskPda,
+            dispute: disputePda,
+            initiator: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([agent])
+        .rpc()
+    })
+
+    it('rejects an admin ruling on an unflagged dispute while running', async () => {
+      try {
+        await adminResolve(authority.publicKey, { agentWins: {} })
+        expect.fail('Should have thrown AdminResolutionNotAllowed')
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal('AdminResolutionNotAllowed')
+      }
+    })
+
+    it('lets a task party flag the dispute', async () => {
+      await program.methods
+        .flagDispute()
+        .accounts(accs({ task: taskPda, dispute: disputePda, party: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      const dispute = await program.account.dispute.fetch(disputePda)
+      expect(dispute.flagged).to.equal(true)
+    })
+
+    it('rejects an admin ruling from anyone but the authority', async () => {
+      try {
+        await adminResolve(voter1.publicKey, { agentWins: {} }, [voter1])
+        expect.fail('Should have thrown NotPlatformAuthority')
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal('NotPlatformAuthority')
+      }
+    })
+
+    it('pays out the admin ruling on a flagged dispute', async () => {
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+      // Bond plus rent, all returned to the agent as initiator
+      const disputeLamports = await provider.connection.getBalance(disputePda)
+
+      await adminResolve(authority.publicKey, { agentWins: {} })
+
+      const fee = (bounty * FEE_BPS) / 10000
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      expect(agentAfter - agentBefore).to.equal(bounty - fee + disputeLamports)
+
+      // No votes were cast, so task and dispute both close
+      expect(await provider.connection.getAccountInfo(taskPda)).to.be.null
+      expect(await provider.connection.getAccountInfo(disputePda)).to.be.null
+    })
+  })
+
+  describe('authority transfer', () => {
+    const newAuthority = Keypair.generate()
+
+    function propose(nominee: PublicKey, current = authority.publicKey, signers: Keypair[] = []) {
+      return program.methods
+        .proposeAuthority(nominee)
+        .accounts({ platform: platformPda, authority: current })
+        .signers(signers)
+        .rpc()
+    }
+
+    function accept(nominee: PublicKey, signers: Keypair[] = []) {
+      return program.methods
+        .acceptAuthority()
+        .accounts({ platform: platformPda, newAuthority: nominee })
+        .signers(signers)
+        .rpc()
+    }
+
+    it('rejects cancelling when no transfer is pending', async () => {
+      try {
+        await program.methods
+          .cancelAuthorityTransfer()
+          .accounts({ platform: platformPda, authority: authority.publicKey })
+          .rpc()
+        expect.fail('Should have thrown NoPendingAuthority')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NoPendingAuthority')
+      }
+    })
+
+    it('rejects a proposal from anyone but the authority', async () => {
+      try {
+        await propose(newAuthority.publicKey, newAuthority.publicKey, [newAuthority])
+        expect.fail('Should have thrown NotPlatformAuthority')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotPlatformAuthority')
+      }
+    })
+
+    it('only lets the nominee accept, and the authority cancel', async () => {
+      await propose(newAuthority.publicKey)
+      let platform = await program.account.platform.fetch(platformPda)
+      expect(platform.pendingAuthority.toBase58()).to.equal(newAuthority.publicKey.toBase58())
+
+      try {
+        await accept(voter1.publicKey, [voter1])
+        expect.fail('Should have thrown NotPendingAuthority')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotPendingAuthority')
+      }
+
+      await program.methods
+        .cancelAuthorityTransfer()
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+      platform = await program.account.platform.fetch(platformPda)
+      expect(platform.pendingAuthority.toBase58()).to.equal(PublicKey.default.toBase58())
+    })
+
+    it('hands over the authority once the nominee accepts', async () => {
+      await propose(newAuthority.publicKey)
+      await accept(newAuthority.publicKey, [newAuthority])
+
+      let platform = await program.account.platform.fetch(platformPda)
+      expect(platform.authority.toBase58()).to.equal(newAuthority.publicKey.toBase58())
+      expect(platform.pendingAuthority.toBase58()).to.equal(PublicKey.default.toBase58())
+
+      // Hand it back for any later tests
+      await propose(authority.publicKey, newAuthority.publicKey, [newAuthority])
+      await accept(authority.publicKey)
+      platform = await program.account.platform.fetch(platformPda)
+      expect(platform.authority.toBase58()).to.equal(authority.publicKey.toBase58())
+    })
+  })
+
+  describe('timelocked config changes', () => {
+    function queueConfig(feeBps: number, configTimelock: number) {
+      return program.methods
+        .updatePlatform({
+          ...NO_CONFIG_CHANGES,
+          feeBps,
+          configTimelock: new BN(configTimelock),
+        } as any)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc({ commitment: 'confirmed' })
+    }
+
+    function applyConfig() {
+      return program.methods
+        .applyPlatformConfig()
+        .accounts({ platform: platformPda, caller: authority.publicKey })
+        .rpc({ commitment: 'confirmed' })
+    }
+
+    /** The platform's settings, as comparable strings keyed like the patch. */
+    async function configSnapshot() {
+      const platform: Record<string, any> = await program.account.platform.fetch(platformPda)
+      return Object.fromEntries(
+        Object.keys(NO_CONFIG_CHANGES).map((k) => [k, JSON.stringify(platform[k])])
+      )
+    }
+
+    it('rejects the crank when nothing is queued', async () => {
+      try {
+        await applyConfig()
+        expect.fail('Should have thrown NoPendingConfig')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NoPendingConfig')
+      }
+    })
+
+    it('holds a queued change until its timelock passes', async () => {
+      await queueConfig(FEE_BPS, 2)
+      await applyConfig()
+      let platform = await program.account.platform.fetch(platformPda)
+      expect(platform.configTimelock.toNumber()).to.equal(2)
+      // Fields left out of the patch keep their values
+      expect(platform.jurySize).to.equal(JURY_SIZE)
+      expect(platform.treasury.toBase58()).to.equal(treasury.publicKey.toBase58())
+
+      await queueConfig(FEE_BPS, CONFIG_TIMELOCK)
+      platform = await program.account.platform.fetch(platformPda)
+      expect(platform.configEffectiveAt.toNumber()).to.be.greaterThan(0)
+      try {
+        await applyConfig()
+        expect.fail('Should have thrown ConfigTimelockActive')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('ConfigTimelockActive')
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 3000))
+      await applyConfig()
+      platform = await program.account.platform.fetch(platformPda)
+      expect(platform.configTimelock.toNumber()).to.equal(CONFIG_TIMELOCK)
+      expect(platform.configEffectiveAt.toNumber()).to.equal(0)
+    })
+
+    it('keeps every field a patch leaves unset', async () => {
+      const before = await configSnapshot()
+      await program.methods
+        .updatePlatform({ ...NO_CONFIG_CHANGES, slashBps: 1234 } as any)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+      await applyConfig()
+      const after = await configSnapshot()
+
+      expect(after.slashBps).to.equal('1234')
+      expect({ ...after, slashBps: before.slashBps }).to.deep.equal(before)
+
+      await program.methods
+        .updatePlatform({ ...NO_CONFIG_CHANGES, slashBps: Number(before.slashBps) } as any)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+      await applyConfig()
+      expect(await configSnapshot()).to.deep.equal(before)
+    })
+
+    it('reports the full old and new config when queuing and applying', async () => {
+      const queued = findEvent(
+        await txEvents(await queueConfig(FEE_BPS * 2, CONFIG_TIMELOCK)),
+        'platformConfigQueued'
+      )
+      expect(queued.oldConfig.feeBps).to.equal(FEE_BPS)
+      expect(queued.newConfig.feeBps).to.equal(FEE_BPS * 2)
+      expect(queued.newConfig.jurySize).to.equal(JURY_SIZE)
+      expect(queued.oldTreasury.toBase58()).to.equal(treasury.publicKey.toBase58())
+      expect(queued.newTreasury.toBase58()).to.equal(treasury.publicKey.toBase58())
+
+      const updated = findEvent(await txEvents(await applyConfig()), 'platformUpdated')
+      expect(updated.oldConfig.feeBps).to.equal(FEE_BPS)
+      expect(updated.newConfig.feeBps).to.equal(FEE_BPS * 2)
+      expect(updated.newConfig.slashBps).to.equal(updated.oldConfig.slashBps)
+      expect(updated.newTreasury.toBase58()).to.equal(treasury.publicKey.toBase58())
+
+      await queueConfig(FEE_BPS, CONFIG_TIMELOCK)
+      await applyConfig()
+    })
+
+    it('settles a task at the fee in effect when it was created', async () => {
+      const bounty = 0.2 * LAMPORTS_PER_SOL
+      const [taskPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('task'),
+          creator.publicKey.toBuffer(),
+          new BN(creatorTaskCount).toArrayLike(Buffer, 'le', 8),
+        ],
+        program.programId
       )
 
       await program.methods
-        .castVote({ agentWins: {} } as any)
+        .createTask(
+          'Fee snapshot task',
+          Array.from(Buffer.alloc(32, 61)) as any,
+          new BN(bounty),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+        )
+        .signers([creator])
+        .rpc()
+      creatorTaskCount++
+
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 62)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      // Raise the fee while the task is in flight
+      await queueConfig(FEE_BPS * 4, CONFIG_TIMELOCK)
+      await applyConfig()
+      const task = await program.account.task.fetch(taskPda)
+      expect(task.feeBps).to.equal(FEE_BPS)
+
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey)
+      await program.methods
+        .approveAndSettle()
         .accounts(
           accs({
             task: taskPda,
-            dispute: disputePda,
             platform: platformPda,
-            vote: votePda,
-            voterProfile: voter1ProfilePda,
-            voter: voter1.publicKey,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            agentProfile: agentProfilePda,
+            treasury: treasury.publicKey,
+          })
+        )
+        .signers([creator])
+        .rpc()
+      const treasuryAfter = await provider.connection.getBalance(treasury.publicKey)
+      expect(treasuryAfter - treasuryBefore).to.equal(Math.floor((bounty * FEE_BPS) / 10000))
+
+      await queueConfig(FEE_BPS, CONFIG_TIMELOCK)
+      await applyConfig()
+    })
+  })
+
+  describe('emergency withdrawal', () => {
+    const bounty = 0.2 * LAMPORTS_PER_SOL
+
+    async function createClaimedTask(seed: number) {
+      const [taskPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('task'),
+          creator.publicKey.toBuffer(),
+          new BN(creatorTaskCount).toArrayLike(Buffer, 'le', 8),
+        ],
+        program.programId
+      )
+      await program.methods
+        .createTask(
+          'Emergency withdrawal task',
+          Array.from(Buffer.alloc(32, seed)) as any,
+          new BN(bounty),
+          new BN(creatorTaskCount),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          new BN(10)
+        )
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creatorCounter: creatorCounterPda,
+            creator: creator.publicKey,
             systemProgram: SystemProgram.programId,
           })
         )
-        .signers([voter1])
+        .signers([creator])
         .rpc()
+      creatorTaskCount++
 
-      // Wait for voting period to end
-      await new Promise((resolve) => setTimeout(resolve, (VOTING_PERIOD + 1) * 1000))
+      await program.methods
+        .claimTask()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            agentProfile: agentProfilePda,
+            agent: agent.publicKey,
+          })
+        )
+        .signers([agent])
+        .rpc()
+      return taskPda
+    }
 
-      // Try to resolve — should fail with InsufficientVotes
+    function emergencyWithdraw(taskPda: PublicKey, caller: Keypair) {
+      return program.methods
+        .emergencyWithdraw()
+        .accounts(
+          accs({
+            task: taskPda,
+            platform: platformPda,
+            creator: creator.publicKey,
+            agent: agent.publicKey,
+            caller: caller.publicKey,
+          })
+        )
+        .signers([caller])
+        .rpc()
+    }
+
+    it('stays closed until settlement has been paused for the delay', async () => {
+      const taskPda = await createClaimedTask(71)
+
+      try {
+        await emergencyWithdraw(taskPda, creator)
+        expect.fail('Should have thrown EmergencyWithdrawNotAvailable')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('EmergencyWithdrawNotAvailable')
+      }
+
+      await program.methods
+        .pausePlatform(PAUSE_SETTLEMENT)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+      const platform = await program.account.platform.fetch(platformPda)
+      expect(platform.settlementPausedAt.toNumber()).to.be.greaterThan(0)
+
+      try {
+        await emergencyWithdraw(taskPda, creator)
+        expect.fail('Should have thrown EmergencyWithdrawNotAvailable')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('EmergencyWithdrawNotAvailable')
+      }
+    })
+
+    it('refunds the creator of a Claimed task in full', async () => {
+      const taskPda = await createClaimedTask(72)
+      await new Promise((resolve) => setTimeout(resolve, (EMERGENCY_WITHDRAW_DELAY + 1) * 1000))
+
+      // The agent has nothing submitted, so only the creator may withdraw
+      try {
+        await emergencyWithdraw(taskPda, agent)
+        expect.fail('Should have thrown NotTaskCreator')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotTaskCreator')
+      }
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+      const creatorBefore = await provider.connection.getBalance(creator.publicKey)
+      await emergencyWithdraw(taskPda, creator)
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      const creatorAfter = await provider.connection.getBalance(creator.publicKey)
+
+      expect(agentAfter).to.equal(agentBefore)
+      // Bounty plus the task's rent
+      expect(creatorAfter - creatorBefore).to.be.greaterThan(bounty)
+      expect(await provider.connection.getAccountInfo(taskPda)).to.be.null
+    })
+
+    it('pays the agent its share of submitted work', async () => {
+      const taskPda = await createClaimedTask(73)
+      await program.methods
+        .submitDeliverable(Array.from(Buffer.alloc(32, 74)) as any)
+        .accounts(accs({ task: taskPda, platform: platformPda, agent: agent.publicKey }))
+        .signers([agent])
+        .rpc()
+
+      const agentShare = Math.floor((bounty * EMERGENCY_AGENT_SHARE_BPS) / 10000)
+      const agentBefore = await provider.connection.getBalance(agent.publicKey)
+      const creatorBefore = await provider.connection.getBalance(creator.publicKey)
+      await emergencyWithdraw(taskPda, agent)
+      const agentAfter = await provider.connection.getBalance(agent.publicKey)
+      const creatorAfter = await provider.connection.getBalance(creator.publicKey)
+
+      expect(agentAfter - agentBefore).to.equal(agentShare)
+      expect(creatorAfter - creatorBefore).to.be.greaterThan(bounty - agentShare)
+      expect(await provider.connection.getAccountInfo(taskPda)).to.be.null
+    })
+
+    it('closes again once settlement resumes', async () => {
+      await program.methods
+        .resumePlatform(PAUSE_SETTLEMENT)
+        .accounts({ platform: platformPda, authority: authority.publicKey })
+        .rpc()
+      const platform = await program.account.platform.fetch(platformPda)
+      expect(platform.settlementPausedAt.toNumber()).to.equal(0)
+
+      const taskPda = await createClaimedTask(75)
+      try {
+        await emergencyWithdraw(taskPda, creator)
+        expect.fail('Should have thrown EmergencyWithdrawNotAvailable')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('EmergencyWithdrawNotAvailable')
+      }
+    })
+  })
+
+  describe('governance', () => {
+    const [governancePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('governance')],
+      program.programId
+    )
+
+    function proposalPda(index: number): PublicKey {
+      const buf = Buffer.alloc(8)
+      buf.writeBigUInt64LE(BigInt(index))
+      const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('proposal'), buf],
+        program.programId
+      )
+      return pda
+    }
+
+    async function propose(action: any, proposer = authority.publicKey, signers: Keypair[] = []) {
+      const governance = await program.account.governance.fetch(governancePda)
+      const proposal = proposalPda(governance.proposalCount.toNumber())
+      await program.methods
+        .createProposal(action)
+        .accounts({ governance: governancePda, proposal, proposer })
+        .signers(signers)
+        .rpc()
+      return proposal
+    }
+
+    function approve(proposal: PublicKey, signer: Keypair) {
+      return program.methods
+        .approveProposal()
+        .accounts({ governance: governancePda, proposal, signer: signer.publicKey })
+        .signers([signer])
+        .rpc()
+    }
+
+    function execute(proposal: PublicKey) {
+      return program.methods
+        .executeProposal()
+        .accounts({
+          governance: governancePda,
+          proposal,
+          platform: platformPda,
+          executor: authority.publicKey,
+        })
+        .rpc()
+    }
+
+    it('rejects a threshold above the signer count', async () => {
       try {
         await program.methods
-          .resolveDispute()
+          .initializeGovernance([authority.publicKey, voter1.publicKey], 3)
+          .accounts({
+            governance: governancePda,
+            platform: platformPda,
+            authority: authority.publicKey,
+          })
+          .rpc()
+        expect.fail('Should have thrown InvalidGovernanceConfig')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('InvalidGovernanceConfig')
+      }
+    })
+
+    it('initializes a 2-of-3 signer set', async () => {
+      await program.methods
+        .initializeGovernance([authority.publicKey, voter1.publicKey, voter2.publicKey], 2)
+        .accounts({
+          governance: governancePda,
+          platform: platformPda,
+          authority: authority.publicKey,
+        })
+        .rpc()
+
+      const governance = await program.account.governance.fetch(governancePda)
+      expect(governance.signers.length).to.equal(3)
+      expect(governance.threshold).to.equal(2)
+      const platform = await program.account.platform.fetch(platformPda)
+      expect(platform.authority.toBase58()).to.equal(governancePda.toBase58())
+    })
+
+    it('rejects direct admin calls from the previous authority', async () => {
+      try {
+        await program.methods
+          .pausePlatform(PAUSE_ALL)
+          .accounts({ platform: platformPda, authority: authority.publicKey })
+          .rpc()
+        expect.fail('Should have thrown NotPlatformAuthority')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotPlatformAuthority')
+      }
+
+      try {
+        await program.methods
+          .updatePlatform({ ...NO_CONFIG_CHANGES, feeBps: 100 })
+          .accounts(accs({ platform: platformPda, authority: authority.publicKey }))
+          .rpc()
+        expect.fail('Should have thrown NotPlatformAuthority')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotPlatformAuthority')
+      }
+    })
+
+    it('rejects proposals from non-signers', async () => {
+      try {
+        await propose({ pausePlatform: [PAUSE_ALL] }, agent.publicKey, [agent])
+        expect.fail('Should have thrown NotGovernanceSigner')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotGovernanceSigner')
+      }
+    })
+
+    it('executes a proposal only once the threshold is met', async () => {
+      const proposal = await propose({ pausePlatform: [PAUSE_ALL] })
+
+      try {
+        await execute(proposal)
+        expect.fail('Should have thrown ThresholdNotMet')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('ThresholdNotMet')
+      }
+
+      try {
+        await program.methods
+          .approveProposal()
+          .accounts({ governance: governancePda, proposal, signer: authority.publicKey })
+          .rpc()
+        expect.fail('Should have thrown ProposalAlreadyApproved')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('ProposalAlreadyApproved')
+      }
+
+      await approve(proposal, voter1)
+      await execute(proposal)
+
+      const account = await program.account.governanceProposal.fetch(proposal)
+      expect(account.status).to.deep.equal({ executed: {} })
+      expect(account.approvals.length).to.equal(2)
+      let platform = await program.account.platform.fetch(platformPda)
+      expect(platform.pauseFlags).to.equal(PAUSE_ALL)
+
+      // Resume through governance as well
+      const resume = await propose({ resumePlatform: [PAUSE_ALL] })
+      await approve(resume, voter2)
+      await execute(resume)
+      platform = await program.account.platform.fetch(platformPda)
+      expect(platform.pauseFlags).to.equal(0)
+    })
+
+    it('lets only the proposer cancel, and blocks approving after', async () => {
+      const proposal = await propose({ setTreasury: [treasury.publicKey] }, voter1.publicKey, [
+        voter1,
+      ])
+
+      try {
+        await program.methods
+          .cancelProposal()
+          .accounts({ governance: governancePda, proposal, signer: authority.publicKey })
+          .rpc()
+        expect.fail('Should have thrown NotProposer')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotProposer')
+      }
+
+      await program.methods
+        .cancelProposal()
+        .accounts({ governance: governancePda, proposal, signer: voter1.publicKey })
+        .signers([voter1])
+        .rpc()
+
+      try {
+        await approve(proposal, voter2)
+        expect.fail('Should have thrown ProposalNotActive')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('ProposalNotActive')
+      }
+    })
+
+    it('approves arbitrators only through a matching proposal', async () => {
+      const [arbitratorPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('arbitrator'), voter3.publicKey.toBuffer()],
+        program.programId
+      )
+      const [arbitratorPoolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('arbitrator_pool')],
+        program.programId
+      )
+      const setApproval = (approved: boolean, proposal: PublicKey) =>
+        program.methods
+          .setArbitratorApproval(approved)
           .accounts(
             accs({
-              dispute: disputePda,
-              task: taskPda,
+              arbitrator: arbitratorPda,
+              arbitratorPool: arbitratorPoolPda,
               platform: platformPda,
-              creator: creator.publicKey,
-              agent: agent.publicKey,
-              agentProfile: agentProfilePda,
-              treasury: treasury.publicKey,
-              caller: authority.publicKey,
+              authority: authority.publicKey,
+              governance: governancePda,
+              proposal,
             })
           )
           .rpc()
-        expect.fail('Should have thrown InsufficientVotes')
+
+      const revoke = await propose({
+        setArbitratorApproval: { arbitrator: voter3.publicKey, approved: false },
+      })
+      await approve(revoke, voter1)
+
+      // Targeted actions run through their own instruction, not execute_proposal
+      try {
+        await execute(revoke)
+        expect.fail('Should have thrown WrongProposalAction')
       } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal('InsufficientVotes')
+        expect(err.error.errorCode.code).to.equal('WrongProposalAction')
+      }
+      try {
+        await setApproval(true, revoke)
+        expect.fail('Should have thrown WrongProposalAction')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('WrongProposalAction')
+      }
+
+      await setApproval(false, revoke)
+      let arbitrator = await program.account.arbitrator.fetch(arbitratorPda)
+      expect(arbitrator.isApproved).to.equal(false)
+      const account = await program.account.governanceProposal.fetch(revoke)
+      expect(account.status).to.deep.equal({ executed: {} })
+
+      const restore = await propose({
+        setArbitratorApproval: { arbitrator: voter3.publicKey, approved: true },
+      })
+      await approve(restore, voter2)
+      await setApproval(true, restore)
+      arbitrator = await program.account.arbitrator.fetch(arbitratorPda)
+      expect(arbitrator.isApproved).to.equal(true)
+    })
+
+    it('merges an UpdatePlatform patch when the proposal executes', async () => {
+      const applyConfig = () =>
+        program.methods
+          .applyPlatformConfig()
+          .accounts({ platform: platformPda, caller: authority.publicKey })
+          .rpc()
+
+      const raiseFee = await propose({ updatePlatform: [{ ...NO_CONFIG_CHANGES, feeBps: 300 }] })
+      const extendGrace = await propose({
+        updatePlatform: [
+          { ...NO_CONFIG_CHANGES, claimGracePeriod: new BN(CLAIM_GRACE_PERIOD + 1) },
+        ],
+      })
+      await approve(raiseFee, voter1)
+      await approve(extendGrace, voter1)
+
+      // Executed out of order: the fee patch must not undo the grace period change
+      await execute(extendGrace)
+      await applyConfig()
+      await execute(raiseFee)
+      await applyConfig()
+
+      const platform = await program.account.platform.fetch(platformPda)
+      expect(platform.feeBps).to.equal(300)
+      expect(platform.claimGracePeriod.toNumber()).to.equal(CLAIM_GRACE_PERIOD + 1)
+      expect(platform.jurySize).to.equal(JURY_SIZE)
+    })
+
+    it('replaces the signer set and drops stale approvals', async () => {
+      // Approved by voter2, who is about to be removed
+      const stale = await propose({ pausePlatform: [PAUSE_ALL] })
+      await approve(stale, voter2)
+
+      const rotate = await propose({
+        setSigners: {
+          signers: [authority.publicKey, voter1.publicKey, voter3.publicKey],
+          threshold: 2,
+        },
+      })
+      await approve(rotate, voter1)
+      await execute(rotate)
+
+      const governance = await program.account.governance.fetch(governancePda)
+      expect(governance.signers.map((s) => s.toBase58())).to.deep.equal([
+        authority.publicKey.toBase58(),
+        voter1.publicKey.toBase58(),
+        voter3.publicKey.toBase58(),
+      ])
+
+      try {
+        await execute(stale)
+        expect.fail('Should have thrown ThresholdNotMet')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('ThresholdNotMet')
+      }
+
+      try {
+        await propose({ pausePlatform: [PAUSE_ALL] }, voter2.publicKey, [voter2])
+        expect.fail('Should have thrown NotGovernanceSigner')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotGovernanceSigner')
+      }
+    })
+
+    it('hands the authority on through proposals', async () => {
+      // Nominate, then withdraw the nomination
+      const nominate = await propose({ proposeAuthority: [agent.publicKey] })
+      await approve(nominate, voter1)
+      await execute(nominate)
+      let platform = await program.account.platform.fetch(platformPda)
+      expect(platform.pendingAuthority.toBase58()).to.equal(agent.publicKey.toBase58())
+
+      const withdraw = await propose({ cancelAuthorityTransfer: {} })
+      await approve(withdraw, voter1)
+      await execute(withdraw)
+      platform = await program.account.platform.fetch(platformPda)
+      expect(platform.pendingAuthority.toBase58()).to.equal(PublicKey.default.toBase58())
+
+      // Approved while governance still holds the authority, executed after
+      const leftover = await propose({ pausePlatform: [PAUSE_ALL] })
+      await approve(leftover, voter1)
+
+      const handBack = await propose({ proposeAuthority: [authority.publicKey] })
+      await approve(handBack, voter1)
+      await execute(handBack)
+      await program.methods
+        .acceptAuthority()
+        .accounts({ platform: platformPda, newAuthority: authority.publicKey })
+        .rpc()
+      platform = await program.account.platform.fetch(platformPda)
+      expect(platform.authority.toBase58()).to.equal(authority.publicKey.toBase58())
+
+      try {
+        await execute(leftover)
+        expect.fail('Should have thrown NotPlatformAuthority')
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotPlatformAuthority')
       }
     })
   })